@@ -0,0 +1,115 @@
+//! Access control for the avn-service http API.
+//!
+//! Every request must present, in the `EXTERNAL_SERVICE_AUTH_HEADER` header, the secret the node generates at startup
+//! and shares with its offchain workers through the offchain storage.
+//! Transactions sent with `/eth/send` must also be allowed by the node's `SendPolicy`.
+use sp_avn_common::{EthTransaction, EXTERNAL_SERVICE_AUTH_HEADER, external_service_errors::ExternalServiceErrorCode};
+use sp_core::H160;
+use std::sync::{Arc, RwLock};
+use tide::{Error as TideError, Middleware, Next, Request};
+use crate::errors::service_error;
+use async_trait::async_trait;
+
+const FUNCTION_SELECTOR_LENGTH: usize = 4;
+
+/// Rejects any request that does not carry the node's secret
+pub struct AuthMiddleware {
+    secret: String,
+}
+
+impl AuthMiddleware {
+    pub fn new(secret: String) -> Self {
+        return AuthMiddleware { secret };
+    }
+
+    pub(crate) fn is_valid(&self, presented_secret: &str) -> bool {
+        if presented_secret.len() != self.secret.len() {
+            return false;
+        }
+
+        // Compare every byte so the time taken does not depend on where the secrets differ
+        return presented_secret.bytes()
+            .zip(self.secret.bytes())
+            .fold(0u8, |difference, (a, b)| difference | (a ^ b)) == 0;
+    }
+}
+
+#[async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for AuthMiddleware {
+    async fn handle(&self, request: Request<State>, next: Next<'_, State>) -> tide::Result {
+        let is_authorised = request.header(EXTERNAL_SERVICE_AUTH_HEADER)
+            .map(|values| self.is_valid(values.last().as_str()))
+            .unwrap_or(false);
+
+        if !is_authorised {
+            return Err(service_error(
+                ExternalServiceErrorCode::Unauthorised,
+                format!("Request to {} does not have a valid secret", request.url().path())
+            ));
+        }
+
+        return Ok(next.run(request).await);
+    }
+}
+
+/// The contracts and functions this node is allowed to call with `/eth/send`.
+/// Unless other contracts are explicitly allowed, only the T1 contracts the runtime sends transactions to can be called,
+/// so with no contract configured at all every transaction is refused.
+/// An empty list of function selectors does not restrict the function called.
+#[derive(Debug, Clone, Default)]
+pub struct SendPolicy {
+    /// The contracts the runtime sends transactions to, kept up to date by the node as new blocks are imported
+    pub t1_contracts: Arc<RwLock<Vec<H160>>>,
+    /// Replaces `t1_contracts` when it is not empty
+    pub allowed_contracts: Vec<H160>,
+    pub allowed_function_selectors: Vec<[u8; 4]>,
+}
+
+impl SendPolicy {
+    pub fn permitted_contracts(&self) -> Vec<H160> {
+        if self.allowed_contracts.is_empty() {
+            return self.t1_contracts.read().map(|contracts| contracts.clone()).unwrap_or_default();
+        }
+
+        return self.allowed_contracts.clone();
+    }
+
+    pub fn check(&self, transaction: &EthTransaction) -> Result<(), TideError> {
+        if !self.permitted_contracts().contains(&transaction.to) {
+            return Err(service_error(
+                ExternalServiceErrorCode::Forbidden,
+                format!("Contract {:?} is not allowed", transaction.to)
+            ));
+        }
+
+        if !self.allowed_function_selectors.is_empty() {
+            let is_allowed = transaction.data.len() >= FUNCTION_SELECTOR_LENGTH &&
+                self.allowed_function_selectors.iter().any(|selector| selector[..] == transaction.data[0..FUNCTION_SELECTOR_LENGTH]);
+
+            if !is_allowed {
+                return Err(service_error(
+                    ExternalServiceErrorCode::Forbidden,
+                    format!("Function 0x{} is not allowed", hex::encode(transaction.data.iter().take(FUNCTION_SELECTOR_LENGTH).cloned().collect::<Vec<u8>>()))
+                ));
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+pub fn parse_eth_address(address: &str) -> Result<H160, String> {
+    let mut bytes: [u8; 20] = [0; 20];
+    hex::decode_to_slice(address.trim_start_matches("0x"), &mut bytes)
+        .map_err(|e| format!("Invalid Ethereum address {:?}: {:?}", address, e))?;
+
+    return Ok(H160::from(bytes));
+}
+
+pub fn parse_function_selector(selector: &str) -> Result<[u8; 4], String> {
+    let mut bytes: [u8; 4] = [0; 4];
+    hex::decode_to_slice(selector.trim_start_matches("0x"), &mut bytes)
+        .map_err(|e| format!("Invalid function selector {:?}: {:?}", selector, e))?;
+
+    return Ok(bytes);
+}
@@ -0,0 +1,48 @@
+use structopt::StructOpt;
+use sp_core::H160;
+use crate::auth::{parse_eth_address, parse_function_selector};
+
+/// Avn-service specific command line options
+#[derive(Debug, StructOpt, Clone, Default)]
+pub struct AvnCliConfiguration {
+    /// Build and send legacy (pre EIP-1559) transactions to Ethereum.
+    /// Use this option if the Ethereum chain does not support type-2 transactions.
+    #[structopt(long = "eth-legacy-transactions")]
+    pub eth_legacy_transactions: bool,
+
+    /// Number of Ethereum blocks after which a transaction sent by this node that is still not mined
+    /// is replaced by a copy with higher fees. Defaults to 20.
+    #[structopt(long = "eth-stuck-tx-blocks", value_name = "BLOCKS")]
+    pub eth_stuck_tx_blocks: Option<u64>,
+
+    /// Additional Ethereum node URLs. They are used, in the order given, when the `--ethereum-node-url`
+    /// endpoint is unhealthy. Can be passed multiple times.
+    #[structopt(long = "eth-fallback-node-url", value_name = "URL")]
+    pub eth_fallback_node_urls: Vec<String>,
+
+    /// Number of Ethereum endpoints that must return an identical receipt before an event is checked.
+    /// Defaults to 1, meaning the receipt of the first healthy endpoint is used.
+    #[structopt(long = "eth-events-quorum", value_name = "COUNT")]
+    pub eth_events_quorum: Option<u32>,
+
+    /// Minimum balance, in wei, the Ethereum (`ethk`) wallet must keep. Transactions that could drop the balance
+    /// below it are refused, and the node hands its summary slots to the next validator, without being reported for them,
+    /// until the wallet is funded. Defaults to 0.
+    #[structopt(long = "eth-wallet-reserve", value_name = "WEI")]
+    pub eth_wallet_reserve: Option<u128>,
+
+    /// URL of an external signing service holding the Ethereum (`ethk`) key.
+    /// If not set, the key is read from the local keystore.
+    #[structopt(long = "eth-remote-signer-url", value_name = "URL")]
+    pub eth_remote_signer_url: Option<String>,
+
+    /// Contract address that transactions sent to Ethereum are allowed to call.
+    /// Can be passed multiple times. If not set, only the T1 contracts the runtime sends transactions to can be called.
+    #[structopt(long = "eth-send-allowed-contract", value_name = "ADDRESS", parse(try_from_str = parse_eth_address))]
+    pub eth_send_allowed_contracts: Vec<H160>,
+
+    /// 4 byte function selector (for example 0xa9059cbb) that transactions sent to Ethereum are allowed to call.
+    /// Can be passed multiple times. If not set, any function can be called.
+    #[structopt(long = "eth-send-allowed-function", value_name = "SELECTOR", parse(try_from_str = parse_function_selector))]
+    pub eth_send_allowed_function_selectors: Vec<[u8; 4]>,
+}
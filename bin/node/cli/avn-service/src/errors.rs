@@ -0,0 +1,75 @@
+//! Failed requests are answered with a versioned json envelope carrying a stable error code,
+//! so the offchain workers can tell the different failures apart.
+//! See `sp_avn_common::external_service_errors` for the format.
+use sp_avn_common::external_service_errors::{ExternalServiceErrorCode, EXTERNAL_SERVICE_ERROR_VERSION};
+use tide::{http::StatusCode, Body, Error as TideError, Response};
+use std::{convert::TryFrom, fmt};
+
+#[derive(Debug)]
+pub struct ServiceError {
+    pub code: ExternalServiceErrorCode,
+    pub message: String,
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code.as_str(), self.message)
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+#[derive(serde::Serialize)]
+struct ErrorEnvelope {
+    version: u32,
+    error: ErrorDetails,
+}
+
+#[derive(serde::Serialize)]
+struct ErrorDetails {
+    code: &'static str,
+    message: String,
+}
+
+pub fn service_error(code: ExternalServiceErrorCode, message: String) -> TideError {
+    let status = StatusCode::try_from(code.status_code()).unwrap_or(StatusCode::InternalServerError);
+    return TideError::new(status, ServiceError { code, message });
+}
+
+/// The error code of an error. Errors raised by tide itself are classified by their status code.
+pub fn error_code(error: &TideError) -> ExternalServiceErrorCode {
+    if let Some(service_error) = error.downcast_ref::<ServiceError>() {
+        return service_error.code;
+    }
+
+    return match error.status() {
+        StatusCode::BadRequest | StatusCode::UnprocessableEntity => ExternalServiceErrorCode::InvalidRequest,
+        StatusCode::Unauthorized => ExternalServiceErrorCode::Unauthorised,
+        StatusCode::Forbidden => ExternalServiceErrorCode::Forbidden,
+        StatusCode::FailedDependency | StatusCode::ServiceUnavailable => ExternalServiceErrorCode::EthereumNodeUnavailable,
+        _ => ExternalServiceErrorCode::Internal,
+    };
+}
+
+fn error_message(error: &TideError) -> String {
+    return match error.downcast_ref::<ServiceError>() {
+        Some(service_error) => service_error.message.clone(),
+        None => error.to_string(),
+    };
+}
+
+/// Replaces the body of every failed response with the error envelope
+pub async fn to_error_envelope(mut response: Response) -> tide::Result {
+    let envelope = match response.error() {
+        Some(error) => ErrorEnvelope {
+            version: EXTERNAL_SERVICE_ERROR_VERSION,
+            error: ErrorDetails { code: error_code(error).as_str(), message: error_message(error) },
+        },
+        None => return Ok(response),
+    };
+
+    log::error!("💔 avn-service request failed with {}: {}", envelope.error.code, envelope.error.message);
+    response.set_body(Body::from_json(&envelope)?);
+
+    return Ok(response);
+}
@@ -1,11 +1,15 @@
 use sp_avn_common::ETHEREUM_SIGNING_KEY;
 use sc_keystore::LocalKeystore;
 use tide::Error as TideError;
-use web3::{Web3, transports::Http};
-use crate::{server_error, web3_utils::get_nonce_from_ethereum};
-use std::{str::FromStr, path::PathBuf, fs::{File, OpenOptions}, io::{Read, Write, Seek, SeekFrom}};
+use web3::Web3;
+use crate::{
+    server_error, nonce_manager::NonceManager, signer::EthereumSigner, tx_monitor::TransactionMonitor,
+    web3_utils::{EthTransport, get_pending_nonce_from_ethereum}
+};
+use std::{path::PathBuf, sync::Mutex};
 
 const NONCE_FILE_NAME: &str = "eth_wallet_nonce";
+const TX_REPLACEMENTS_FILE_NAME: &str = "eth_tx_replacements";
 
 ///For this function to work, the name of the keystore file must be a valid Ethereum address
 pub fn get_eth_address_bytes_from_keystore(keystore: &LocalKeystore) -> Result<Vec<u8>, TideError> {
@@ -30,77 +34,45 @@ pub fn get_priv_key(keystore: &LocalKeystore, eth_address: &Vec<u8>) -> Result<[
     return Ok(key);
 }
 
-pub async fn setup_nonce_storage(web3: &Option<&Web3<Http>>, keystore: &LocalKeystore) -> Result<(), TideError> {
-    let nonce_file = get_nonce_file(keystore)?;
+pub async fn setup_nonce_manager(
+    web3: &Web3<EthTransport>,
+    keystore: &LocalKeystore,
+    signer: &dyn EthereumSigner,
+    nonce_manager: &Mutex<NonceManager>) -> Result<(), TideError>
+{
+    let path = nonce_file_path(keystore)?;
 
-    let eth_address = get_eth_address_bytes_from_keystore(keystore)?;
-    let ethereum_nonce: u64 = get_nonce_from_ethereum(web3.unwrap(), &eth_address).await?.low_u64();
+    let eth_address = signer.address().await?;
+    let pending_nonce: u64 = get_pending_nonce_from_ethereum(web3, &eth_address).await?.low_u64();
 
-    write_nonce_to_file(nonce_file, ethereum_nonce)?;
-
-    return Ok(());
-}
-
-pub fn get_nonce(keystore: &LocalKeystore) -> Result<u64, TideError> {
-    let nonce_file = get_nonce_file(keystore)?;
-    return read_nonce_from_file(&nonce_file);
-}
-
-pub fn set_nonce(keystore: &LocalKeystore, nonce: u64) -> Result<(), TideError> {
-    let nonce_file = get_nonce_file(keystore)?;
-    write_nonce_to_file(nonce_file, nonce)?;
-
-    Ok(())
-}
-
-pub fn increment_nonce(keystore: &LocalKeystore) -> Result<(), TideError> {
-    let nonce_file = get_nonce_file(keystore)?;
-    let current_nonce = read_nonce_from_file(&nonce_file)?;
-
-    write_nonce_to_file(nonce_file, current_nonce + 1)?;
-
-    Ok(())
+    nonce_manager.lock()
+        .map_err(|_| server_error("Failed to get the nonce manager".to_string()))?
+        .initialise(path, pending_nonce)
 }
 
-fn get_nonce_file(keystore: &LocalKeystore) -> Result<File, TideError> {
-    let path = nonce_file_path(keystore).expect("Already checked");
-
-    let nonce_file = OpenOptions::new().read(true).write(true).create(true).open(path);
-    if let Err(e) = nonce_file {
-        return Err(server_error(format!("Error opening nonce database: {:?}", e)));
-    }
+pub fn setup_tx_monitor(keystore: &LocalKeystore, tx_monitor: &Mutex<TransactionMonitor>) -> Result<(), TideError> {
+    let path = tx_replacements_file_path(keystore)?;
 
-    return Ok(nonce_file.expect("Already checked"));
+    tx_monitor.lock()
+        .map_err(|_| server_error("Failed to get the transaction monitor".to_string()))?
+        .initialise(path)
 }
 
-fn read_nonce_from_file(mut nonce_file: &File) -> Result<u64, TideError> {
-    let mut nonce = String::new();
-    nonce_file.read_to_string(&mut nonce)
-        .map_err(|e| server_error(format!("Error reading nonce from db: {:?}", e)))?;
-
-    nonce = nonce.replace("\n", "");
-    if nonce.is_empty() {
-        nonce = "0".to_string();
-    }
-
-    return Ok(u64::from_str(&nonce).map_err(|_| server_error(format!("Invalid nonce value in database: {:?}", nonce)))?);
+pub fn nonce_file_path(keystore: &LocalKeystore) -> Result<PathBuf, TideError> {
+    return keystore_file_path(keystore, NONCE_FILE_NAME);
 }
 
-fn write_nonce_to_file(mut nonce_file: File, nonce: u64) -> Result<(), TideError> {
-    nonce_file.seek(SeekFrom::Start(0)).unwrap();
-    serde_json::to_writer(&nonce_file, &nonce)
-        .map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
-    nonce_file.flush().map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
-
-    Ok(())
+/// The replacements are stored next to the nonce file, because they belong to the same wallet
+pub fn tx_replacements_file_path(keystore: &LocalKeystore) -> Result<PathBuf, TideError> {
+    return keystore_file_path(keystore, TX_REPLACEMENTS_FILE_NAME);
 }
 
-fn nonce_file_path(keystore: &LocalKeystore) -> Result<PathBuf, TideError> {
+fn keystore_file_path(keystore: &LocalKeystore, file_name: &str) -> Result<PathBuf, TideError> {
     if keystore.path().is_none() {
         return Err(server_error("Keystore not setup correctly".to_string()));
     }
 
     let mut buf: PathBuf = keystore.path().clone().expect("Already checked");
-    buf.push(NONCE_FILE_NAME.to_string());
+    buf.push(file_name.to_string());
     Ok(buf)
 }
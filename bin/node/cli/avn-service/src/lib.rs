@@ -3,36 +3,101 @@ use std::marker::PhantomData;
 use codec::{Encode, Decode};
 use hex::FromHex;
 use sp_core::{ecdsa::Signature, hashing::keccak_256};
-use sp_avn_common::{EthTransaction, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER};
+use sp_avn_common::{
+    EthTransaction, EthTransactionStatus, EthWalletStatus, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER, external_service_errors::ExternalServiceErrorCode,
+    event_types::{EthLogsQuery, EthLogsScanResult, DiscoveredEthLog},
+};
 use sp_runtime::{traits::{Block as BlockT}};
 use sc_keystore::LocalKeystore;
 
 use sc_client_api::{UsageProvider, client::BlockBackend};
-use std::time::Instant;
+use std::{cmp, time::Instant};
 
 pub use std::sync::{Arc, Mutex};
 
-use secp256k1::SecretKey;
-use tide::{http::StatusCode, Error as TideError};
-use web3::{Web3, types::{Bytes, TransactionReceipt}, transports};
+use tide::Error as TideError;
+use web3::{Web3, types::{Bytes, Log, TransactionReceipt, U256}};
 use ethereum_types::H256;
 use node_rpc::extrinsic_utils::get_latest_finalised_block;
 
 pub mod web3_utils;
 pub mod keystore_utils;
 pub mod summary_utils;
+pub mod transaction_utils;
+pub mod signer;
+pub mod tx_monitor;
+pub mod nonce_manager;
+pub mod web3_pool;
+pub mod cli;
+pub mod auth;
+pub mod errors;
+pub mod metrics;
+
+#[cfg(test)]
+#[path = "tests/test_remote_signer.rs"]
+mod test_remote_signer;
+
+#[cfg(test)]
+#[path = "tests/test_errors.rs"]
+mod test_errors;
+
+#[cfg(test)]
+#[path = "tests/test_transaction_utils.rs"]
+mod test_transaction_utils;
+
+#[cfg(test)]
+#[path = "tests/test_nonce_manager.rs"]
+mod test_nonce_manager;
+
+#[cfg(test)]
+#[path = "tests/test_tx_monitor.rs"]
+mod test_tx_monitor;
+
+#[cfg(test)]
+#[path = "tests/test_web3_pool.rs"]
+mod test_web3_pool;
+
+#[cfg(test)]
+#[path = "tests/test_auth.rs"]
+mod test_auth;
+
+#[cfg(test)]
+#[path = "tests/test_web3_utils.rs"]
+mod test_web3_utils;
 
 use crate::{web3_utils::*};
 use crate::{keystore_utils::*};
 use crate::{summary_utils::*};
+use crate::{tx_monitor::*};
+use crate::{nonce_manager::NonceManager};
+use crate::{web3_pool::*};
+use crate::{signer::EthereumSigner};
+use crate::{auth::{AuthMiddleware, SendPolicy}};
+use crate::{errors::{service_error, to_error_envelope}};
+use crate::{metrics::{Metrics, MetricsMiddleware}};
 
 
 #[derive(Clone)]
 pub struct Config<Block: BlockT, ClientT: BlockBackend<Block> + UsageProvider<Block>> {
     pub keystore: Arc<LocalKeystore>,
+    pub signer: Arc<dyn EthereumSigner>,
     pub avn_port: Option<String>,
-    pub eth_node_url: String,
-    pub web3_mutex: Arc<Mutex<Option<Web3<transports::Http>>>>,
+    /// Secret every request must present. It is shared with the offchain workers via the offchain storage.
+    pub auth_secret: String,
+    pub send_policy: SendPolicy,
+    /// Ethereum endpoints, in order of preference
+    pub eth_node_urls: Vec<String>,
+    /// Number of endpoints that must return the same receipt for an event check. A value of 1 disables quorum reads.
+    pub eth_events_quorum: u32,
+    pub eth_legacy_transactions: bool,
+    pub eth_stuck_tx_blocks: u64,
+    /// Minimum balance, in wei, the Ethereum wallet must keep. Sends that could drop the balance below it are refused.
+    pub eth_wallet_reserve: U256,
+    pub web3_pool: Arc<Mutex<Web3Pool>>,
+    pub tx_monitor: Arc<Mutex<TransactionMonitor>>,
+    pub nonce_manager: Arc<Mutex<NonceManager>>,
+    /// None if the node does not expose Prometheus metrics
+    pub metrics: Option<Metrics>,
     pub client: Arc<ClientT>,
     pub _block: PhantomData<Block>,
 }
@@ -43,8 +108,11 @@ struct Response {
     pub num_confirmations: u64
 }
 
+/// Gas we expect a transaction to a T1 contract to use at most, when working out if the wallet can pay for one
+const T1_TRANSACTION_GAS_ALLOWANCE: u64 = 500_000;
+
 pub fn server_error(message: String) -> TideError {
-    return TideError::from_str(StatusCode::InternalServerError, format!("ℹ️  {:?}", message));
+    return service_error(ExternalServiceErrorCode::Internal, message);
 }
 
 pub fn hash_with_ethereum_prefix(data_to_sign: Vec<u8>) -> [u8; 32] {
@@ -63,7 +131,7 @@ pub fn to_bytes32(data: String) -> Result<[u8; 32], TideError> {
 
     return <[u8; 32]>::from_hex(data.clone())
         .map_or_else(
-            |_| Err(server_error(format!("Error converting to bytes32: {:?}", data))),
+            |_| Err(service_error(ExternalServiceErrorCode::InvalidRequest, format!("Error converting to bytes32: {:?}", data))),
             |bytes32| Ok(bytes32)
         );
 }
@@ -71,8 +139,8 @@ pub fn to_bytes32(data: String) -> Result<[u8; 32], TideError> {
 fn get_tx_receipt_json(receipt: TransactionReceipt, current_block_number: u64) -> Result<String, TideError> {
     let response = Response {
         result: serde_json::to_value(&receipt)
-            .map_err(|_| TideError::from_str(StatusCode::Ok, "❗Eth response is not a valid JSON".to_string()))?,
-        num_confirmations:  current_block_number - receipt.block_number.unwrap_or(Default::default()).as_u64()
+            .map_err(|_| server_error("Eth response is not a valid JSON".to_string()))?,
+        num_confirmations:  current_block_number.saturating_sub(receipt.block_number.unwrap_or(Default::default()).as_u64())
     };
 
     let json_response = serde_json::to_string(&response)
@@ -81,19 +149,80 @@ fn get_tx_receipt_json(receipt: TransactionReceipt, current_block_number: u64) -
     return Ok(json_response);
 }
 
-async fn send_tx(web3: &Web3<transports::Http>,
-    keystore: &LocalKeystore,
+fn lock_nonce_manager(nonce_manager: &Mutex<NonceManager>) -> Result<MutexGuard<NonceManager>, TideError> {
+    return nonce_manager.lock().map_err(|_| server_error("Failed to get the nonce manager".to_string()));
+}
+
+async fn send_tx(web3: &Web3<EthTransport>,
+    nonce_manager: &Mutex<NonceManager>,
     send_request: &EthTransaction,
     sender_eth_address: &Vec<u8>,
-    priv_key: [u8; 32]) -> Result<web3::types::H256, TideError>
+    signer: &dyn EthereumSigner,
+    use_legacy_transaction: bool,
+    wallet_reserve: U256) -> Result<DispatchedTransaction, TideError>
 {
-    let tx = build_raw_transaction(web3, &keystore, send_request, &sender_eth_address).await.map_err(|e| {
+    let chain_id = get_chain_id(web3).await?;
+    let fees = get_transaction_fees(web3, use_legacy_transaction).await?;
+    let gas = estimate_gas_for_transaction(web3, send_request, sender_eth_address, &fees).await?;
+    ensure_wallet_can_pay(web3, sender_eth_address, fees.max_cost(gas), wallet_reserve).await?;
+    let current_block_number = get_current_block_number(web3).await?;
+
+    let nonce = lock_nonce_manager(nonce_manager)?.reserve()?;
+
+    let signed_tx = build_transaction(send_request, nonce, gas, &fees, chain_id).sign(signer).await;
+    if let Err(e) = signed_tx {
         log::error!("💔 Error building raw transaction: {:?}", e);
-        server_error(format!("Error building raw transaction: {:?}", e))})?;
+        lock_nonce_manager(nonce_manager)?.release(nonce)?;
+        return Err(server_error(format!("Error building raw transaction: {:?}", e)));
+    }
 
-    let signed_tx = tx.sign(&H256::from_slice(&priv_key), &get_chain_id(web3).await?);
+    match send_raw_transaction(web3, Bytes::from(signed_tx.expect("Checked for errors"))).await {
+        Ok(tx_hash) => {
+            lock_nonce_manager(nonce_manager)?.commit(nonce);
+
+            Ok(DispatchedTransaction {
+                send_request: send_request.clone(),
+                nonce,
+                gas,
+                fees,
+                tx_hashes: vec![H256::from_slice(tx_hash.as_bytes())],
+                last_sent_at_eth_block: current_block_number,
+            })
+        },
+        Err(e) => {
+            if let Err(settle_error) = settle_failed_nonce(web3, nonce_manager, sender_eth_address, nonce).await {
+                log::error!("💔 Error settling nonce {:?} of a failed transaction: {:?}", nonce, settle_error);
+            }
+            Err(e)
+        }
+    }
+}
 
-    Ok(send_raw_transaction(web3, Bytes::from(signed_tx)).await?)
+/// A failed send could still have reached the mempool, so we check the pending nonce on Ethereum
+/// before making the nonce available again.
+async fn settle_failed_nonce(web3: &Web3<EthTransport>,
+    nonce_manager: &Mutex<NonceManager>,
+    sender_eth_address: &Vec<u8>,
+    nonce: u64) -> Result<(), TideError>
+{
+    let pending_nonce = get_pending_nonce_from_ethereum(web3, sender_eth_address).await.map(|n| n.low_u64());
+    let mut nonce_manager = lock_nonce_manager(nonce_manager)?;
+
+    match pending_nonce {
+        Ok(pending_nonce) if pending_nonce > nonce => {
+            nonce_manager.commit(nonce);
+            nonce_manager.reconcile(pending_nonce)
+        },
+        Ok(pending_nonce) => {
+            nonce_manager.release(nonce)?;
+            nonce_manager.reconcile(pending_nonce)
+        },
+        Err(e) => {
+            // Assume the nonce has been used. If it wasn't, the gap is closed the next time we reconcile.
+            nonce_manager.commit(nonce);
+            Err(e)
+        }
+    }
 }
 
 #[tokio::main]
@@ -102,40 +231,106 @@ async fn send_main<Block: BlockT, ClientT>(mut req: tide::Request<Arc<Config<Blo
 {
     log::info!("ℹ️ avn-service send Request");
     let post_body = req.body_bytes().await?;
-    let send_request = &EthTransaction::decode(&mut &post_body[..])?;
-    // TODO wrap this in a getter function that does the check and returns the mutex
-    if let Ok(mutex_web3) = &req.state().web3_mutex.lock() {
-        if mutex_web3.is_none() {
-            return Err(server_error("Web3 connection not setup".to_string()));
-        }
-        let web3 = mutex_web3.as_ref().expect("Already checked");
-        let keystore = &req.state().keystore;
+    let send_request = &EthTransaction::decode(&mut &post_body[..])
+        .map_err(|e| service_error(ExternalServiceErrorCode::InvalidRequest, format!("Invalid transaction: {:?}", e)))?;
+    req.state().send_policy.check(send_request)?;
 
-        let my_eth_address = get_eth_address_bytes_from_keystore(&keystore)?;
-        let my_priv_key = get_priv_key(&keystore, &my_eth_address)?;
+    let web3 = get_active_web3(req.state())?.web3;
+    let nonce_manager = &req.state().nonce_manager;
+    let use_legacy_transaction = req.state().eth_legacy_transactions;
+    let wallet_reserve = req.state().eth_wallet_reserve;
 
-        let mut tx_hash = send_tx(&web3, keystore, send_request, &my_eth_address, my_priv_key).await;
+    let signer = req.state().signer.as_ref();
+    let my_eth_address = signer.address().await?;
 
-        if let Err(e) = tx_hash {
-            if e.to_string().find("the tx doesn't have the correct nonce").is_some() {
-                let ethereum_nonce: u64 = get_nonce_from_ethereum(web3, &my_eth_address).await?.low_u64();
-                set_nonce(&keystore, ethereum_nonce)?;
+    let mut dispatched_tx = send_tx(&web3, nonce_manager, send_request, &my_eth_address, signer, use_legacy_transaction, wallet_reserve).await;
 
-                tx_hash = send_tx(&web3, keystore, send_request, &my_eth_address, my_priv_key).await;
-            } else {
-                return Err(server_error(format!("Error sending transaction to ethereum: {:?}", e)));
-            }
+    if dispatched_tx.as_ref().err().map_or(false, is_nonce_error) {
+        // The nonce manager has been reconciled with Ethereum, so try once more with a new nonce
+        dispatched_tx = send_tx(&web3, nonce_manager, send_request, &my_eth_address, signer, use_legacy_transaction, wallet_reserve).await;
+    }
+
+    let dispatched_tx = dispatched_tx.map_err(|e| {
+        log::error!("💔 Error sending transaction to ethereum: {:?}", e);
+        e
+    })?;
+
+    let tx_hash = dispatched_tx.original_tx_hash();
+    if let Ok(mut tx_monitor) = req.state().tx_monitor.lock() {
+        if let Err(e) = tx_monitor.track(dispatched_tx) {
+            log::error!("💔 Error storing the transaction replacements: {:?}", e);
         }
+    }
 
-        let tx_hash = tx_hash.map_err(|e| server_error(format!("Error sending transaction to ethereum: {:?}", e)))?;
+    Ok(hex::encode(tx_hash))
+}
 
-        increment_nonce(&keystore)?;
+fn tx_hashes_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service tx hashes");
+    let tx_hash: H256 = H256::from_slice(&to_bytes32(req.param("txHash")?.to_string())?);
 
-        Ok(hex::encode(tx_hash))
-    }
-    else {
-        Err(TideError::from_str(StatusCode::FailedDependency, "Failed to get web3"))
+    let tx_hashes: Vec<sp_core::H256> = req.state().tx_monitor.lock()
+        .map_err(|_| server_error("Failed to get the transaction monitor".to_string()))?
+        .tx_hashes(&tx_hash)
+        .iter()
+        .map(|tx_hash| sp_core::H256::from_slice(tx_hash.as_bytes()))
+        .collect();
+
+    Ok(hex::encode(tx_hashes.encode()))
+}
+
+fn stop_monitoring_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service stop monitoring");
+    let tx_hash: H256 = H256::from_slice(&to_bytes32(req.param("txHash")?.to_string())?);
+
+    req.state().tx_monitor.lock()
+        .map_err(|_| server_error("Failed to get the transaction monitor".to_string()))?
+        .stop_tracking_tx_hash(&tx_hash)?;
+
+    Ok(hex::encode(tx_hash))
+}
+
+#[tokio::main]
+async fn wallet_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service wallet Request");
+    let web3 = get_active_web3(req.state())?.web3;
+    let my_eth_address = req.state().signer.address().await?;
+    let balance = get_balance(&web3, &my_eth_address).await?;
+    let fees = get_transaction_fees(&web3, req.state().eth_legacy_transactions).await?;
+
+    let wallet_status = EthWalletStatus::new(
+        sp_core::H160::from_slice(&my_eth_address),
+        sp_core::U256(balance.0),
+        sp_core::U256(req.state().eth_wallet_reserve.0),
+        sp_core::U256(fees.max_cost(U256::from(T1_TRANSACTION_GAS_ALLOWANCE)).0),
+    );
+
+    if !wallet_status.can_pay_for_transaction() {
+        log::warn!("⚠️ Ethereum wallet {:?} balance of {} wei cannot pay {} wei for a transaction and keep the reserve of {} wei",
+            wallet_status.address, wallet_status.balance, wallet_status.transaction_cost, wallet_status.reserve);
     }
+
+    Ok(hex::encode(wallet_status.encode()))
+}
+
+#[tokio::main]
+async fn sign_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service sign Request");
+    let data_to_sign: Vec<u8> = hex::decode(req.param("data_to_sign")?.trim_start_matches("0x"))
+        .map_err(|e| server_error(format!("Error converting data_to_sign into hex string {:?}", e)))?;
+
+    let hashed_message = hash_with_ethereum_prefix(data_to_sign);
+    let signature: Signature = req.state().signer.sign_hash(hashed_message).await?;
+
+    Ok(hex::encode(signature.encode()))
 }
 
 #[tokio::main]
@@ -145,69 +340,249 @@ async fn root_hash_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Bl
     log::info!("ℹ️ avn-service eth events");
     let tx_hash: H256 = H256::from_slice(
         &to_bytes32(
-            req.param("txHash").map_err(|_| TideError::from_str(
-                StatusCode::BadRequest,
-                "💔 txHash is not a valid transaction hash".to_string()))?.to_string()
+            req.param("txHash").map_err(|_| service_error(
+                ExternalServiceErrorCode::InvalidRequest,
+                "txHash is not a valid transaction hash".to_string()))?.to_string()
         )?
     );
 
-    // TODO wrap this in a getter function that does the check and returns the mutex
-    if let Ok(mutex_web3) = &req.state().web3_mutex.lock() {
-        if mutex_web3.is_none() {
-            return Err(server_error("Web3 connection not setup".to_string()));
+    let (maybe_receipt, current_block_number) = match req.state().eth_events_quorum {
+        quorum if quorum > 1 => get_tx_receipt_with_quorum(req.state(), tx_hash, quorum).await?,
+        _ => get_tx_receipt_with_failover(req.state(), tx_hash).await?
+    };
+
+    match maybe_receipt {
+        None => Err(service_error(ExternalServiceErrorCode::ReceiptNotFound, "Transaction receipt is empty".to_string())),
+        Some(receipt) => Ok(get_tx_receipt_json(receipt, current_block_number)?)
+    }
+}
+
+#[tokio::main]
+async fn tx_status_main<Block: BlockT, ClientT>(req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service tx status");
+    let tx_hash: H256 = H256::from_slice(&to_bytes32(req.param("txHash")?.to_string())?);
+    // Only set for a transaction sent as part of a multicall batch
+    let batch_call_index: Option<u64> = match req.param("callIndex") {
+        Ok(call_index) => Some(call_index.parse()?),
+        Err(_) => None,
+    };
+
+    // Only the status of this hash is reported: every node must agree on the status of a hash, and only the sender knows
+    // its replacements. The sender records them on chain, from `/eth/tx_hashes`, so the other nodes query them too.
+    let (maybe_receipt, current_block_number) = get_tx_receipt_with_failover(req.state(), tx_hash).await?;
+
+    let tx_status = match maybe_receipt {
+        Some(receipt) if receipt.block_number.is_some() => {
+            let block_number = receipt.block_number.unwrap_or_default().as_u64();
+            let mut succeeded = receipt.status.map_or(false, |status| status.as_u64() == 1);
+            if let (true, Some(call_index)) = (succeeded, batch_call_index) {
+                succeeded = web3_utils::batch_call_succeeded(&receipt, call_index)?;
+            }
+
+            EthTransactionStatus::Mined {
+                tx_hash: sp_core::H256::from_slice(tx_hash.as_bytes()),
+                block_number,
+                succeeded,
+                num_confirmations: current_block_number.saturating_sub(block_number),
+            }
+        },
+        _ => EthTransactionStatus::NotMined,
+    };
+
+    Ok(hex::encode(tx_status.encode()))
+}
+
+async fn get_tx_receipt_and_block_number(web3: &Web3<EthTransport>, tx_hash: H256)
+    -> Result<(Option<TransactionReceipt>, u64), TideError>
+{
+    let current_block_number = web3_utils::get_current_block_number(web3).await?;
+    let maybe_receipt = web3_utils::get_tx_receipt(web3, tx_hash).await?;
+
+    Ok((maybe_receipt, current_block_number))
+}
+
+/// Gets the receipt from the preferred healthy endpoint, failing over to the next one if it does not respond
+async fn get_tx_receipt_with_failover<Block: BlockT, ClientT>(config: &Config<Block, ClientT>, tx_hash: H256)
+    -> Result<(Option<TransactionReceipt>, u64), TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    for endpoint in get_healthy_web3s(config)? {
+        match get_tx_receipt_and_block_number(&endpoint.web3, tx_hash).await {
+            Ok(result) => return Ok(result),
+            Err(e) => {
+                log::warn!("⚠️ Error getting tx receipt from {:?}: {:?}", endpoint.url, e);
+                mark_unhealthy(config, &endpoint);
+            }
+        }
+    }
+
+    Err(service_error(ExternalServiceErrorCode::EthereumNodeUnavailable, "No Ethereum endpoint returned the tx receipt".to_string()))
+}
+
+/// Only accepts a receipt if at least `quorum` healthy endpoints return exactly the same one,
+/// so a single faulty or malicious endpoint cannot make us vote on a fabricated receipt.
+async fn get_tx_receipt_with_quorum<Block: BlockT, ClientT>(config: &Config<Block, ClientT>, tx_hash: H256, quorum: u32)
+    -> Result<(Option<TransactionReceipt>, u64), TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    let mut responses: Vec<(Option<TransactionReceipt>, u64)> = vec![];
+
+    for endpoint in get_healthy_web3s(config)? {
+        match get_tx_receipt_and_block_number(&endpoint.web3, tx_hash).await {
+            Ok(response) => responses.push(response),
+            Err(e) => {
+                log::warn!("⚠️ Error getting tx receipt from {:?}: {:?}", endpoint.url, e);
+                mark_unhealthy(config, &endpoint);
+            }
+        }
+    }
+
+    return select_quorum_receipt(responses, quorum, tx_hash).ok_or_else(|| service_error(
+        ExternalServiceErrorCode::QuorumNotReached,
+        format!("Ethereum endpoints did not reach a quorum of {} on the tx receipt", quorum)
+    ));
+}
+
+/// Returns the receipt returned by at least `quorum` endpoints, with the lowest block number of the agreeing endpoints
+/// so we never overstate the number of confirmations
+fn select_quorum_receipt(responses: Vec<(Option<TransactionReceipt>, u64)>, quorum: u32, tx_hash: H256)
+    -> Option<(Option<TransactionReceipt>, u64)>
+{
+    let mut agreeing_responses: Vec<(Option<TransactionReceipt>, Vec<u64>)> = vec![];
+
+    for (receipt, block_number) in responses {
+        match agreeing_responses.iter_mut().find(|(r, _)| *r == receipt) {
+            Some((_, block_numbers)) => block_numbers.push(block_number),
+            None => agreeing_responses.push((receipt, vec![block_number])),
         }
-        let web3 = mutex_web3.as_ref().expect("Already checked");
+    }
+
+    if agreeing_responses.len() > 1 {
+        log::warn!("⚠️ Ethereum endpoints returned {} different receipts for tx {:?}", agreeing_responses.len(), tx_hash);
+    }
+
+    return agreeing_responses.into_iter()
+        .find(|(_, block_numbers)| block_numbers.len() as u32 >= quorum)
+        .map(|(receipt, block_numbers)| (receipt, block_numbers.into_iter().min().unwrap_or_default()));
+}
 
-        let current_block_number = web3_utils::get_current_block_number(web3).await?;
-        let maybe_receipt = web3_utils::get_tx_receipt(web3, tx_hash).await?;
-        match maybe_receipt {
-            None => Err(TideError::from_str(StatusCode::Ok, "❗Transaction receipt is empty".to_string())),
-            Some(receipt) => Ok(get_tx_receipt_json(receipt, current_block_number)?)
+#[tokio::main]
+async fn logs_main<Block: BlockT, ClientT>(mut req: tide::Request<Arc<Config<Block, ClientT>>>) -> Result<String, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    log::info!("ℹ️ avn-service eth logs");
+    let post_body = req.body_bytes().await?;
+    let query = EthLogsQuery::decode(&mut &post_body[..])
+        .map_err(|e| service_error(ExternalServiceErrorCode::InvalidRequest, format!("Invalid logs query: {:?}", e)))?;
+
+    if query.max_block_range == 0 {
+        return Err(service_error(ExternalServiceErrorCode::InvalidRequest, "max_block_range must be greater than 0".to_string()));
+    }
+
+    for endpoint in get_healthy_web3s(req.state())? {
+        match scan_logs(&endpoint.web3, &query).await {
+            Ok(scan_result) => return Ok(hex::encode(scan_result.encode())),
+            Err(e) => {
+                log::warn!("⚠️ Error getting logs from {:?}: {:?}", endpoint.url, e);
+                mark_unhealthy(req.state(), &endpoint);
+            }
         }
     }
-    else {
-        Err(TideError::from_str(StatusCode::FailedDependency, "Failed to get web3"))
+
+    Err(service_error(ExternalServiceErrorCode::EthereumNodeUnavailable, "No Ethereum endpoint returned the logs".to_string()))
+}
+
+/// Scans, at most, `max_block_range` blocks starting at `from_block`, but never blocks without enough confirmations
+async fn scan_logs(web3: &Web3<EthTransport>, query: &EthLogsQuery) -> Result<EthLogsScanResult, TideError> {
+    let current_block_number = get_current_block_number(web3).await?;
+    let last_block_to_scan = cmp::min(
+        current_block_number.saturating_sub(query.min_confirmations),
+        query.from_block.saturating_add(query.max_block_range - 1)
+    );
+
+    let mut scan_result = EthLogsScanResult { from_block: query.from_block, to_block: last_block_to_scan, logs: vec![] };
+    if !scan_result.has_scanned_blocks() {
+        return Ok(scan_result);
+    }
+
+    let logs = get_logs(
+        web3,
+        query.addresses.iter().map(|a| web3::types::H160::from_slice(a.as_bytes())).collect(),
+        query.signatures.iter().map(|s| web3::types::H256::from_slice(s.as_bytes())).collect(),
+        scan_result.from_block,
+        scan_result.to_block,
+    ).await?;
+
+    scan_result.logs = logs.into_iter().filter_map(to_discovered_log).collect();
+
+    Ok(scan_result)
+}
+
+/// Ignores logs removed by a reorg and logs that are not mined yet
+fn to_discovered_log(log: Log) -> Option<DiscoveredEthLog> {
+    if log.removed == Some(true) {
+        return None;
     }
+
+    return Some(DiscoveredEthLog {
+        signature: sp_core::H256::from_slice(log.topics.first()?.as_bytes()),
+        transaction_hash: sp_core::H256::from_slice(log.transaction_hash?.as_bytes()),
+        contract_address: sp_core::H160::from_slice(log.address.as_bytes()),
+        block_number: log.block_number?.as_u64(),
+        block_log_index: log.log_index?.as_u64(),
+    });
 }
 
 pub async fn start<Block: BlockT, ClientT>(mut config: Config<Block, ClientT>) where
     ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
  {
-    let web3 = setup_web3_connection(&config.eth_node_url);
+    let web3_pool = Web3Pool::new(&config.eth_node_urls).await;
 
-    if web3.is_none() {
-        log::error!("💔 Error creating a web3 connection. URL is not valid {:?}", &config.eth_node_url);
+    if web3_pool.is_empty() {
+        log::error!("💔 Error creating a web3 connection. No valid URL in {:?}", &config.eth_node_urls);
         return;
     }
 
-
-    if let Err(e) = setup_nonce_storage(&web3.as_ref(), &config.keystore).await {
+    let web3 = web3_pool.active().expect("Already checked").web3;
+    if let Err(e) = setup_nonce_manager(&web3, &config.keystore, config.signer.as_ref(), &config.nonce_manager).await {
         log::error!("💔 Error setting up nonce storage {:?}", e);
         return;
     }
 
-    config.web3_mutex = Arc::new(Mutex::new(web3));
+    if let Err(e) = setup_tx_monitor(&config.keystore, &config.tx_monitor) {
+        log::error!("💔 Error setting up transaction replacements storage {:?}", e);
+        return;
+    }
 
-    let port = format!("127.0.0.1:{}", &config.avn_port.clone().unwrap_or_else(|| DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER.to_string()));
+    config.web3_pool = Arc::new(Mutex::new(web3_pool));
 
-    let mut app = tide::with_state(Arc::<Config<Block, ClientT>>::from(config));
+    if config.send_policy.permitted_contracts().is_empty() {
+        log::error!("💔 No Ethereum contract is allowed yet, so transactions sent to Ethereum will be refused until a T1 contract is set on chain");
+    }
 
-    app.at("/eth/sign/:data_to_sign").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
-        log::info!("ℹ️ avn-service sign Request");
-        let keystore = &req.state().keystore;
-        let data_to_sign: Vec<u8> = hex::decode(req.param("data_to_sign")?.trim_start_matches("0x"))
-            .map_err(|e| server_error(format!("Error converting data_to_sign into hex string {:?}", e)))?;
+    let port = format!("127.0.0.1:{}", &config.avn_port.clone().unwrap_or_else(|| DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER.to_string()));
+
+    let config = Arc::<Config<Block, ClientT>>::from(config);
 
-        let hashed_message = hash_with_ethereum_prefix(data_to_sign);
+    let monitor_config = config.clone();
+    std::thread::spawn(move || monitor_dispatched_transactions(monitor_config));
 
-        let my_eth_address = get_eth_address_bytes_from_keystore(keystore)?;
-        let my_priv_key = get_priv_key(keystore, &my_eth_address)?;
+    let health_check_config = config.clone();
+    std::thread::spawn(move || check_endpoints_health(health_check_config));
 
-        let secret = SecretKey::parse(&my_priv_key)?;
-        let message = secp256k1::Message::parse(&hashed_message);
-        let signature: Signature = secp256k1::sign(&message, &secret).into();
+    let auth_secret = config.auth_secret.clone();
+    let metrics = config.metrics.clone();
+    let mut app = tide::with_state(config);
+    // Must be registered first so it also sees the errors of the other middlewares
+    app.with(tide::utils::After(to_error_envelope));
+    app.with(MetricsMiddleware::new(metrics));
+    app.with(AuthMiddleware::new(auth_secret));
 
-        Ok(hex::encode(signature.encode()))
+    app.at("/eth/sign/:data_to_sign").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        // A remote signer needs the tokio runtime (#[tokio::main])
+        return sign_main(req);
     });
 
     app.at("/eth/send").post(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
@@ -215,11 +590,39 @@ pub async fn start<Block: BlockT, ClientT>(mut config: Config<Block, ClientT>) w
         return send_main(req);
     });
 
+    app.at("/eth/wallet").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        // Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+        return wallet_main(req);
+    });
+
     app.at("/eth/events/:txHash").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
         // Methods that require web3 must be run within the tokio runtime (#[tokio::main])
         return root_hash_main(req);
     });
 
+    app.at("/eth/logs").post(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        // Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+        return logs_main(req);
+    });
+
+    app.at("/eth/tx_status/:txHash").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        // Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+        return tx_status_main(req);
+    });
+
+    app.at("/eth/tx_status/:txHash/:callIndex").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        // Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+        return tx_status_main(req);
+    });
+
+    app.at("/eth/tx_hashes/:txHash").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        return tx_hashes_main(req);
+    });
+
+    app.at("/eth/stop_monitoring/:txHash").post(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
+        return stop_monitoring_main(req);
+    });
+
     app.at("/roothash/:from_block/:to_block").get(|req: tide::Request<Arc<Config<Block, ClientT>>>| async move {
         log::info!("ℹ️ avn-service roothash");
         // We cannot use a number bigger than a u32, but with block times of 3 sec it would take about
@@ -0,0 +1,184 @@
+//! Prometheus metrics of avn-service and of the offchain workers. They are registered with the node's registry
+//! so they are served by the node's Prometheus endpoint.
+use crate::{Config, errors::error_code, web3_pool::get_active_web3, web3_utils::{get_balance, get_nonce_from_ethereum}};
+use async_trait::async_trait;
+use prometheus_endpoint::{register, Counter, CounterVec, Gauge, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, F64, U64};
+use codec::Decode;
+use sc_client_api::{UsageProvider, client::BlockBackend};
+use sp_avn_common::{
+    external_service_errors::ExternalServiceErrorCode,
+    ocw_metrics::{OCW_EVENTS_CHECKED_COUNTER_KEY, OCW_ROOTS_VOTED_COUNTER_KEY},
+};
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::Block as BlockT;
+use std::time::{Duration, Instant};
+use tide::{Error as TideError, Middleware, Next, Request};
+pub use std::sync::Arc;
+
+/// How often the counters of the offchain workers are read from the local storage
+const OCW_METRICS_INTERVAL_SECONDS: u64 = 10;
+/// Requests to any other path are reported under a single label to bound the number of time series
+const KNOWN_ENDPOINTS: [&str; 8] = [
+    "/eth/sign", "/eth/send", "/eth/wallet", "/eth/events", "/eth/logs", "/eth/tx_status", "/roothash", "/latest_finalised_block"
+];
+const WEI_PER_ETHER: f64 = 1_000_000_000_000_000_000.0;
+
+#[derive(Clone)]
+pub struct Metrics {
+    pub request_duration: HistogramVec,
+    pub ethereum_errors: CounterVec<U64>,
+    pub gas_used: Counter<U64>,
+    pub wallet_nonce: Gauge<U64>,
+    pub wallet_balance: Gauge<F64>,
+    pub pending_transactions: Gauge<U64>,
+    pub ocw_events_checked: Counter<U64>,
+    pub ocw_roots_voted: Counter<U64>,
+}
+
+impl Metrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            request_duration: register(
+                HistogramVec::new(
+                    HistogramOpts::new("avn_service_request_duration_seconds", "Time taken to serve avn-service requests"),
+                    &["endpoint"]
+                )?,
+                registry,
+            )?,
+            ethereum_errors: register(
+                CounterVec::new(
+                    Opts::new("avn_service_ethereum_errors_total", "Number of failed Ethereum requests"),
+                    &["code"]
+                )?,
+                registry,
+            )?,
+            gas_used: register(
+                Counter::new("avn_service_ethereum_gas_used_total", "Gas used by the mined transactions sent by this node")?,
+                registry,
+            )?,
+            wallet_nonce: register(
+                Gauge::new("avn_service_ethereum_wallet_nonce", "Nonce of the node's Ethereum account")?,
+                registry,
+            )?,
+            wallet_balance: register(
+                Gauge::new("avn_service_ethereum_wallet_balance_ether", "Balance of the node's Ethereum account")?,
+                registry,
+            )?,
+            pending_transactions: register(
+                Gauge::new("avn_service_pending_transactions", "Number of transactions sent to Ethereum that are not mined yet")?,
+                registry,
+            )?,
+            ocw_events_checked: register(
+                Counter::new("avn_ocw_events_checked_total", "Number of Ethereum events checked or validated by the offchain workers")?,
+                registry,
+            )?,
+            ocw_roots_voted: register(
+                Counter::new("avn_ocw_roots_voted_total", "Number of votes on summary roots cast by the offchain workers")?,
+                registry,
+            )?,
+        })
+    }
+
+    pub fn report_ethereum_error(&self, code: ExternalServiceErrorCode) {
+        self.ethereum_errors.with_label_values(&[code.as_str()]).inc();
+    }
+
+    fn report_request_error(&self, error: &TideError) {
+        let code = error_code(error);
+        match code {
+            ExternalServiceErrorCode::EthereumNodeUnavailable |
+            ExternalServiceErrorCode::QuorumNotReached |
+            ExternalServiceErrorCode::GasEstimationFailed |
+            ExternalServiceErrorCode::NonceTooLow => self.report_ethereum_error(code),
+            _ => {}
+        }
+    }
+}
+
+fn endpoint_label(path: &str) -> &'static str {
+    return KNOWN_ENDPOINTS.iter()
+        .find(|endpoint| path == **endpoint || path.starts_with(&format!("{}/", endpoint)))
+        .cloned()
+        .unwrap_or("other");
+}
+
+/// Records the latency of every request and the Ethereum errors they hit
+pub struct MetricsMiddleware {
+    metrics: Option<Metrics>,
+}
+
+impl MetricsMiddleware {
+    pub fn new(metrics: Option<Metrics>) -> Self {
+        return MetricsMiddleware { metrics };
+    }
+}
+
+#[async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for MetricsMiddleware {
+    async fn handle(&self, request: Request<State>, next: Next<'_, State>) -> tide::Result {
+        let metrics = match &self.metrics {
+            Some(metrics) => metrics,
+            None => return Ok(next.run(request).await),
+        };
+
+        let endpoint = endpoint_label(request.url().path());
+        let start_time = Instant::now();
+        let response = next.run(request).await;
+
+        metrics.request_duration.with_label_values(&[endpoint]).observe(start_time.elapsed().as_secs_f64());
+        if let Some(error) = response.error() {
+            metrics.report_request_error(error);
+        }
+
+        return Ok(response);
+    }
+}
+
+/// Updates the nonce, balance and pending transactions of the node's Ethereum account
+pub async fn update_wallet_metrics<Block: BlockT, ClientT>(config: &Arc<Config<Block, ClientT>>) -> Result<(), TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    let metrics = match &config.metrics {
+        Some(metrics) => metrics,
+        None => return Ok(()),
+    };
+
+    if let Ok(tx_monitor) = config.tx_monitor.lock() {
+        metrics.pending_transactions.set(tx_monitor.pending_transactions().len() as u64);
+    }
+
+    let web3 = get_active_web3(config)?.web3;
+    let my_eth_address = config.signer.address().await?;
+
+    metrics.wallet_nonce.set(get_nonce_from_ethereum(&web3, &my_eth_address).await?.low_u64());
+    metrics.wallet_balance.set(get_balance(&web3, &my_eth_address).await?.low_u128() as f64 / WEI_PER_ETHER);
+
+    Ok(())
+}
+
+fn read_counter<S: OffchainStorage>(storage: &S, key: &[u8]) -> u64 {
+    return storage.get(STORAGE_PREFIX, key)
+        .and_then(|value| u64::decode(&mut &value[..]).ok())
+        .unwrap_or(0);
+}
+
+/// Exports the counters the offchain workers keep in the local storage. Only the increments since the node started
+/// are exported, so the metrics behave like any other counter when the node restarts.
+pub async fn export_ocw_metrics<S: OffchainStorage>(metrics: Metrics, storage: S) {
+    let counters = [
+        (OCW_EVENTS_CHECKED_COUNTER_KEY, metrics.ocw_events_checked.clone()),
+        (OCW_ROOTS_VOTED_COUNTER_KEY, metrics.ocw_roots_voted.clone()),
+    ];
+
+    let mut last_values: Vec<u64> = counters.iter().map(|(key, _)| read_counter(&storage, key)).collect();
+
+    loop {
+        tokio::time::delay_for(Duration::from_secs(OCW_METRICS_INTERVAL_SECONDS)).await;
+
+        for ((key, counter), last_value) in counters.iter().zip(last_values.iter_mut()) {
+            let value = read_counter(&storage, key);
+            counter.inc_by(value.saturating_sub(*last_value));
+            *last_value = value;
+        }
+    }
+}
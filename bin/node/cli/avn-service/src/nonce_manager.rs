@@ -0,0 +1,135 @@
+use crate::server_error;
+use std::{collections::BTreeSet, fs::{self, File}, io::Write, path::PathBuf, str::FromStr};
+use tide::Error as TideError;
+
+/// Hands out the nonces used to send transactions from the validator's Ethereum wallet.
+///
+/// A nonce is first reserved, then either committed once the transaction has been sent or released if it was not.
+/// The next free nonce is persisted before a reservation is returned, so a crash between sending a transaction and
+/// recording it can leave a gap but never causes the same nonce to be used twice. Gaps are closed by reconciling
+/// against the `pending` transaction count of the Ethereum node.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    path: Option<PathBuf>,
+    next_nonce: u64,
+    /// Nonces handed out that are not committed or released yet
+    reserved: BTreeSet<u64>,
+    /// Nonces that were released and can be handed out again
+    released: BTreeSet<u64>,
+}
+
+impl NonceManager {
+    /// Sets up the nonce storage in `path` and reconciles it with the `pending` transaction count of the sender.
+    /// A stored nonce ahead of the pending count is kept, because the transactions sent with the nonces in between
+    /// may still reach Ethereum and reusing their nonces could replace them.
+    pub fn initialise(&mut self, path: PathBuf, pending_nonce: u64) -> Result<(), TideError> {
+        let stored_nonce = read_nonce_from_file(&path)?;
+        if stored_nonce > pending_nonce {
+            log::warn!(
+                "⚠️ Stored nonce ({:?}) is ahead of the pending Ethereum nonce ({:?}). Nonces reserved before a restart will not be reused.",
+                stored_nonce, pending_nonce
+            );
+        }
+
+        self.path = Some(path);
+        self.next_nonce = stored_nonce.max(pending_nonce);
+        self.reserved.clear();
+        self.released.clear();
+
+        return self.persist();
+    }
+
+    pub fn reserve(&mut self) -> Result<u64, TideError> {
+        if self.path.is_none() {
+            return Err(server_error("Nonce storage not setup".to_string()));
+        }
+
+        let nonce = match self.released.iter().next().cloned() {
+            Some(released_nonce) => {
+                self.released.remove(&released_nonce);
+                released_nonce
+            },
+            None => {
+                let nonce = self.next_nonce;
+                self.next_nonce += 1;
+                self.persist()?;
+                nonce
+            }
+        };
+
+        self.reserved.insert(nonce);
+        return Ok(nonce);
+    }
+
+    /// Marks a reserved nonce as used by a transaction that reached Ethereum
+    pub fn commit(&mut self, nonce: u64) {
+        self.reserved.remove(&nonce);
+    }
+
+    /// Makes a reserved nonce available again because no transaction was sent with it
+    pub fn release(&mut self, nonce: u64) -> Result<(), TideError> {
+        if !self.reserved.remove(&nonce) {
+            return Ok(());
+        }
+
+        self.released.insert(nonce);
+
+        // Shrink the range of handed out nonces rather than leaving released nonces at the end of it
+        while self.next_nonce > 0 && self.released.remove(&(self.next_nonce - 1)) {
+            self.next_nonce -= 1;
+        }
+
+        return self.persist();
+    }
+
+    /// Aligns the nonces with the `pending` transaction count of the sender.
+    /// If there are outstanding reservations we can only move forward, otherwise the pending count is authoritative.
+    pub fn reconcile(&mut self, pending_nonce: u64) -> Result<(), TideError> {
+        if self.reserved.is_empty() {
+            self.next_nonce = pending_nonce;
+            self.released.clear();
+        } else {
+            self.next_nonce = self.next_nonce.max(pending_nonce);
+            self.released = self.released.split_off(&pending_nonce);
+        }
+
+        return self.persist();
+    }
+
+    fn persist(&self) -> Result<(), TideError> {
+        let path = self.path.as_ref().ok_or_else(|| server_error("Nonce storage not setup".to_string()))?;
+        return write_nonce_to_file(path, self.next_nonce);
+    }
+}
+
+fn read_nonce_from_file(path: &PathBuf) -> Result<u64, TideError> {
+    if !path.exists() {
+        return Ok(0);
+    }
+
+    let nonce = fs::read_to_string(path)
+        .map_err(|e| server_error(format!("Error reading nonce from db: {:?}", e)))?
+        .replace("\n", "");
+
+    if nonce.is_empty() {
+        return Ok(0);
+    }
+
+    return Ok(u64::from_str(&nonce).map_err(|_| server_error(format!("Invalid nonce value in database: {:?}", nonce)))?);
+}
+
+/// Writes to a temporary file and renames it so the stored nonce is never left partially written
+fn write_nonce_to_file(path: &PathBuf, nonce: u64) -> Result<(), TideError> {
+    let temp_path = path.with_extension("tmp");
+
+    let mut temp_file = File::create(&temp_path)
+        .map_err(|e| server_error(format!("Error opening nonce database: {:?}", e)))?;
+    serde_json::to_writer(&temp_file, &nonce)
+        .map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
+    temp_file.flush().map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
+    temp_file.sync_all().map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
+
+    fs::rename(&temp_path, path).map_err(|e| server_error(format!("Error writing to nonce DB: {:?}", e)))?;
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+//! Signers for the validator's Ethereum (`ethk`) key.
+//!
+//! The key can either be held in the node's local keystore or in an external signing service.
+//! A remote signer must implement the following HTTP/JSON protocol (modelled on web3signer):
+//!  - `GET  <url>/api/v1/eth1/publicKeys` returns a JSON array with the hex encoded, uncompressed, public key of the signing key
+//!  - `POST <url>/api/v1/eth1/sign/<0x address>` with a `{"data": "<0x 32 byte hash>"}` body signs the hash as is and
+//!    returns the hex encoded 65 byte (r, s, v) signature
+
+use crate::{errors::service_error, keystore_utils::{get_eth_address_bytes_from_keystore, get_priv_key}};
+use async_trait::async_trait;
+use hyper::{Body, Client, Method, Request, StatusCode, client::HttpConnector};
+use hyper_tls::HttpsConnector;
+use sc_keystore::LocalKeystore;
+use secp256k1::{SecretKey, Message, RecoveryId, Signature as SecpSignature};
+use sp_core::{ecdsa::Signature, hashing::keccak_256};
+use sp_avn_common::external_service_errors::ExternalServiceErrorCode;
+use tide::Error as TideError;
+use std::sync::Mutex;
+pub use std::sync::Arc;
+
+const REMOTE_SIGNER_PUBLIC_KEYS_PATH: &str = "api/v1/eth1/publicKeys";
+const REMOTE_SIGNER_SIGN_PATH: &str = "api/v1/eth1/sign";
+
+fn signing_error(message: String) -> TideError {
+    return service_error(ExternalServiceErrorCode::SigningFailed, message);
+}
+
+#[async_trait]
+pub trait EthereumSigner: Send + Sync {
+    /// Returns the Ethereum address of the signing key
+    async fn address(&self) -> Result<Vec<u8>, TideError>;
+
+    /// Signs a 32 byte hash and returns a 65 byte (r, s, v) signature, where v is the recovery id (0 or 1)
+    async fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature, TideError>;
+}
+
+pub fn create_signer(keystore: Arc<LocalKeystore>, remote_signer_url: Option<String>) -> Arc<dyn EthereumSigner> {
+    match remote_signer_url {
+        Some(url) => Arc::new(RemoteSigner::new(url)),
+        None => Arc::new(LocalKeystoreSigner { keystore }),
+    }
+}
+
+/// Signs with the `ethk` key stored in the node's local keystore
+pub struct LocalKeystoreSigner {
+    pub keystore: Arc<LocalKeystore>,
+}
+
+#[async_trait]
+impl EthereumSigner for LocalKeystoreSigner {
+    async fn address(&self) -> Result<Vec<u8>, TideError> {
+        return get_eth_address_bytes_from_keystore(&self.keystore);
+    }
+
+    async fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature, TideError> {
+        let my_eth_address = get_eth_address_bytes_from_keystore(&self.keystore)?;
+        let my_priv_key = get_priv_key(&self.keystore, &my_eth_address)?;
+
+        let secret = SecretKey::parse(&my_priv_key)
+            .map_err(|e| signing_error(format!("Error parsing private key: {:?}", e)))?;
+        let message = Message::parse(&hash);
+
+        return Ok(secp256k1::sign(&message, &secret).into());
+    }
+}
+
+/// Signs with a key held by an external signing service, so the key never enters the node process
+pub struct RemoteSigner {
+    url: String,
+    client: Client<HttpsConnector<HttpConnector>>,
+    /// The address of the remote key, once it has been fetched
+    address: Mutex<Option<Vec<u8>>>,
+}
+
+#[derive(serde::Serialize)]
+struct SignRequest {
+    data: String,
+}
+
+impl RemoteSigner {
+    pub fn new(url: String) -> Self {
+        return RemoteSigner {
+            url: url.trim_end_matches('/').to_string(),
+            client: Client::builder().build::<_, Body>(HttpsConnector::new()),
+            address: Mutex::new(None),
+        };
+    }
+
+    async fn call(&self, method: Method, path: String, body: Body) -> Result<Vec<u8>, TideError> {
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("{}/{}", self.url, path))
+            .header("content-type", "application/json")
+            .body(body)
+            .map_err(|e| signing_error(format!("Error building remote signer request: {:?}", e)))?;
+
+        let response = self.client.request(request)
+            .await
+            .map_err(|e| signing_error(format!("Error calling remote signer: {:?}", e)))?;
+
+        let status = response.status();
+        let response_body = hyper::body::to_bytes(response.into_body())
+            .await
+            .map_err(|e| signing_error(format!("Error reading remote signer response: {:?}", e)))?;
+
+        if status != StatusCode::OK {
+            return Err(signing_error(format!("Remote signer returned {:?}: {:?}", status, String::from_utf8_lossy(&response_body))));
+        }
+
+        return Ok(response_body.to_vec());
+    }
+
+    fn cached_address(&self) -> Option<Vec<u8>> {
+        return self.address.lock().ok().and_then(|address| address.clone());
+    }
+
+    async fn fetch_address(&self) -> Result<Vec<u8>, TideError> {
+        let response = self.call(Method::GET, REMOTE_SIGNER_PUBLIC_KEYS_PATH.to_string(), Body::empty()).await?;
+        let public_keys: Vec<String> = serde_json::from_slice(&response)
+            .map_err(|e| signing_error(format!("Error decoding remote signer public keys: {:?}", e)))?;
+
+        let public_key = public_keys.first()
+            .ok_or_else(|| signing_error("Remote signer does not have any public key".to_string()))?;
+
+        return eth_address_from_public_key(public_key);
+    }
+}
+
+#[async_trait]
+impl EthereumSigner for RemoteSigner {
+    /// The address is only fetched once because the remote key cannot change while the node is running
+    async fn address(&self) -> Result<Vec<u8>, TideError> {
+        if let Some(address) = self.cached_address() {
+            return Ok(address);
+        }
+
+        let address = self.fetch_address().await?;
+        if let Ok(mut cached_address) = self.address.lock() {
+            *cached_address = Some(address.clone());
+        }
+
+        return Ok(address);
+    }
+
+    async fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature, TideError> {
+        let address = self.address().await?;
+        let body = serde_json::to_vec(&SignRequest { data: format!("0x{}", hex::encode(hash)) })
+            .map_err(|e| signing_error(format!("Error encoding remote signer request: {:?}", e)))?;
+
+        let response = self.call(
+            Method::POST,
+            format!("{}/0x{}", REMOTE_SIGNER_SIGN_PATH, hex::encode(address)),
+            Body::from(body)
+        ).await?;
+
+        let signature_hex = String::from_utf8_lossy(&response).trim().trim_matches('"').trim_start_matches("0x").to_string();
+        let mut signature: [u8; 65] = [0; 65];
+        hex::decode_to_slice(&signature_hex, &mut signature)
+            .map_err(|e| signing_error(format!("Remote signer returned an invalid signature {:?}: {:?}", signature_hex, e)))?;
+
+        // Some signers return v as 27 or 28
+        if signature[64] >= 27 {
+            signature[64] -= 27;
+        }
+
+        // Never hand out a signature that Ethereum would attribute to a different account
+        if recover_eth_address(&hash, &signature)? != address {
+            return Err(signing_error(format!("Remote signer returned a signature that is not from 0x{}", hex::encode(address))));
+        }
+
+        return Ok(Signature::from_raw(signature));
+    }
+}
+
+fn recover_eth_address(hash: &[u8; 32], signature: &[u8; 65]) -> Result<Vec<u8>, TideError> {
+    let recovery_id = RecoveryId::parse(signature[64])
+        .map_err(|e| signing_error(format!("Remote signer returned an invalid recovery id: {:?}", e)))?;
+    let secp_signature = SecpSignature::parse_slice(&signature[0..64])
+        .map_err(|e| signing_error(format!("Remote signer returned an invalid signature: {:?}", e)))?;
+
+    let public_key = secp256k1::recover(&Message::parse(hash), &secp_signature, &recovery_id)
+        .map_err(|e| signing_error(format!("Error recovering the remote signer public key: {:?}", e)))?;
+
+    return Ok(keccak_256(&public_key.serialize()[1..])[12..].to_vec());
+}
+
+/// The Ethereum address is the last 20 bytes of the keccak hash of the uncompressed public key (without the 0x04 prefix)
+fn eth_address_from_public_key(public_key: &String) -> Result<Vec<u8>, TideError> {
+    let mut public_key_bytes = hex::decode(public_key.trim_start_matches("0x"))
+        .map_err(|e| signing_error(format!("Invalid public key {:?}: {:?}", public_key, e)))?;
+
+    if public_key_bytes.len() == 65 && public_key_bytes[0] == 4 {
+        public_key_bytes.remove(0);
+    }
+
+    if public_key_bytes.len() != 64 {
+        return Err(signing_error(format!("Public key {:?} is not an uncompressed public key", public_key)));
+    }
+
+    return Ok(keccak_256(&public_key_bytes)[12..].to_vec());
+}
@@ -0,0 +1,130 @@
+use crate::auth::{AuthMiddleware, SendPolicy};
+use sp_avn_common::{EthTransaction, EXTERNAL_SERVICE_AUTH_HEADER};
+use sp_core::H160;
+use std::sync::{Arc, RwLock};
+use tide::http::{Method, Request, Response, StatusCode, Url};
+
+const SECRET: &str = "0123456789abcdef";
+const SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+fn t1_contract() -> H160 {
+    return H160::repeat_byte(1);
+}
+
+fn other_contract() -> H160 {
+    return H160::repeat_byte(2);
+}
+
+fn t1_contracts(contracts: Vec<H160>) -> Arc<RwLock<Vec<H160>>> {
+    return Arc::new(RwLock::new(contracts));
+}
+
+fn transaction(to: H160, data: Vec<u8>) -> EthTransaction {
+    return EthTransaction { from: [0; 32], to, data };
+}
+
+async fn response_status(secret: Option<&str>) -> StatusCode {
+    let mut app = tide::new();
+    app.with(AuthMiddleware::new(SECRET.to_string()));
+    app.at("/eth/wallet").get(|_| async { Ok("wallet") });
+
+    let mut request = Request::new(Method::Get, Url::parse("http://127.0.0.1:2020/eth/wallet").unwrap());
+    if let Some(secret) = secret {
+        request.insert_header(EXTERNAL_SERVICE_AUTH_HEADER, secret);
+    }
+
+    let response: Response = app.respond(request).await.expect("Request is handled");
+    return response.status();
+}
+
+mod auth_middleware {
+    use super::*;
+
+    #[test]
+    fn accepts_the_secret() {
+        assert!(AuthMiddleware::new(SECRET.to_string()).is_valid(SECRET));
+    }
+
+    #[test]
+    fn rejects_a_secret_that_differs_in_any_byte() {
+        let auth = AuthMiddleware::new(SECRET.to_string());
+
+        assert!(!auth.is_valid("1123456789abcdef"));
+        assert!(!auth.is_valid("0123456789abcdee"));
+        assert!(!auth.is_valid("01234567x9abcdef"));
+    }
+
+    #[test]
+    fn rejects_a_secret_of_a_different_length() {
+        let auth = AuthMiddleware::new(SECRET.to_string());
+
+        assert!(!auth.is_valid(""));
+        assert!(!auth.is_valid("0123456789abcde"));
+        assert!(!auth.is_valid("0123456789abcdef0"));
+    }
+
+    #[tokio::test]
+    async fn lets_requests_with_the_secret_through() {
+        assert_eq!(response_status(Some(SECRET)).await, StatusCode::Ok);
+    }
+
+    #[tokio::test]
+    async fn refuses_requests_without_the_secret() {
+        assert_eq!(response_status(None).await, StatusCode::Unauthorized);
+        assert_eq!(response_status(Some("wrong secret")).await, StatusCode::Unauthorized);
+    }
+}
+
+mod send_policy {
+    use super::*;
+
+    #[test]
+    fn refuses_every_contract_by_default() {
+        let policy = SendPolicy::default();
+
+        assert!(policy.check(&transaction(t1_contract(), SELECTOR.to_vec())).is_err());
+    }
+
+    #[test]
+    fn only_allows_the_t1_contracts_when_no_contract_is_configured() {
+        let policy = SendPolicy { t1_contracts: t1_contracts(vec![t1_contract()]), ..Default::default() };
+
+        assert!(policy.check(&transaction(t1_contract(), SELECTOR.to_vec())).is_ok());
+        assert!(policy.check(&transaction(other_contract(), SELECTOR.to_vec())).is_err());
+    }
+
+    #[test]
+    fn follows_changes_to_the_t1_contracts() {
+        let policy = SendPolicy { t1_contracts: t1_contracts(vec![t1_contract()]), ..Default::default() };
+
+        *policy.t1_contracts.write().unwrap() = vec![other_contract()];
+
+        assert!(policy.check(&transaction(other_contract(), SELECTOR.to_vec())).is_ok());
+        assert!(policy.check(&transaction(t1_contract(), SELECTOR.to_vec())).is_err());
+    }
+
+    #[test]
+    fn configured_contracts_replace_the_t1_contracts() {
+        let policy = SendPolicy {
+            t1_contracts: t1_contracts(vec![t1_contract()]),
+            allowed_contracts: vec![other_contract()],
+            ..Default::default()
+        };
+
+        assert!(policy.check(&transaction(other_contract(), SELECTOR.to_vec())).is_ok());
+        assert!(policy.check(&transaction(t1_contract(), SELECTOR.to_vec())).is_err());
+    }
+
+    #[test]
+    fn only_allows_the_configured_functions() {
+        let policy = SendPolicy {
+            t1_contracts: t1_contracts(vec![t1_contract()]),
+            allowed_function_selectors: vec![SELECTOR],
+            ..Default::default()
+        };
+
+        assert!(policy.check(&transaction(t1_contract(), [&SELECTOR[..], &[1, 2, 3]].concat())).is_ok());
+        assert!(policy.check(&transaction(t1_contract(), vec![0x12, 0x34, 0x56, 0x78])).is_err());
+        assert!(policy.check(&transaction(t1_contract(), SELECTOR[0..3].to_vec())).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use crate::errors::{service_error, to_error_envelope};
+use sp_avn_common::external_service_errors::ExternalServiceErrorCode;
+use tide::{http::StatusCode, Error as TideError, Response};
+
+async fn get_envelope(error: TideError) -> (StatusCode, serde_json::Value) {
+    let mut response = to_error_envelope(Response::from(error)).await.expect("Envelope is created");
+    let body = response.take_body().into_string().await.expect("Body is a string");
+
+    return (response.status(), serde_json::from_str(&body).expect("Body is json"));
+}
+
+#[tokio::test]
+async fn service_errors_are_returned_with_their_code() {
+    let (status, envelope) = get_envelope(
+        service_error(ExternalServiceErrorCode::ReceiptNotFound, "Transaction receipt is empty".to_string())
+    ).await;
+
+    assert_eq!(status, StatusCode::NotFound);
+    assert_eq!(envelope["version"], 1);
+    assert_eq!(envelope["error"]["code"], "RECEIPT_NOT_FOUND");
+    assert_eq!(envelope["error"]["message"], "Transaction receipt is empty");
+}
+
+#[tokio::test]
+async fn other_errors_are_classified_by_status_code() {
+    let (status, envelope) = get_envelope(TideError::from_str(StatusCode::BadRequest, "bad request")).await;
+
+    assert_eq!(status, StatusCode::BadRequest);
+    assert_eq!(envelope["error"]["code"], "INVALID_REQUEST");
+    assert_eq!(envelope["error"]["message"], "bad request");
+}
+
+#[tokio::test]
+async fn successful_responses_are_not_changed() {
+    let mut response = to_error_envelope(Response::builder(StatusCode::Ok).body("0x1234").build()).await.expect("Response is returned");
+
+    assert_eq!(response.take_body().into_string().await.expect("Body is a string"), "0x1234");
+}
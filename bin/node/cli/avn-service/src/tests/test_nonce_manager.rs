@@ -0,0 +1,174 @@
+use crate::nonce_manager::NonceManager;
+use std::{fs, path::PathBuf};
+
+/// Returns a path to a nonce file that does not exist yet
+fn nonce_file(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("avn-service-nonce-manager-{}", std::process::id()));
+    fs::create_dir_all(&directory).expect("Directory is created");
+
+    let path = directory.join(name);
+    let _ = fs::remove_file(&path);
+    return path;
+}
+
+fn stored_nonce(path: &PathBuf) -> u64 {
+    return fs::read_to_string(path).expect("Nonce file exists").trim().parse().expect("Valid nonce");
+}
+
+fn nonce_manager(path: &PathBuf, pending_nonce: u64) -> NonceManager {
+    let mut nonce_manager = NonceManager::default();
+    nonce_manager.initialise(path.clone(), pending_nonce).expect("Nonce manager is initialised");
+    return nonce_manager;
+}
+
+mod initialise {
+    use super::*;
+
+    #[test]
+    fn starts_from_the_pending_nonce_without_a_stored_nonce() {
+        let path = nonce_file("no_stored_nonce");
+        let mut nonce_manager = nonce_manager(&path, 5);
+
+        assert_eq!(nonce_manager.reserve().unwrap(), 5);
+    }
+
+    #[test]
+    fn keeps_a_stored_nonce_that_is_ahead_of_the_pending_nonce() {
+        let path = nonce_file("stored_nonce_ahead");
+        fs::write(&path, "8").unwrap();
+
+        let mut nonce_manager = nonce_manager(&path, 5);
+
+        assert_eq!(nonce_manager.reserve().unwrap(), 8);
+    }
+
+    #[test]
+    fn moves_to_a_pending_nonce_that_is_ahead_of_the_stored_nonce() {
+        let path = nonce_file("pending_nonce_ahead");
+        fs::write(&path, "3").unwrap();
+
+        let mut nonce_manager = nonce_manager(&path, 5);
+
+        assert_eq!(nonce_manager.reserve().unwrap(), 5);
+        assert_eq!(stored_nonce(&path), 6);
+    }
+
+    #[test]
+    fn does_not_reuse_nonces_reserved_before_a_restart() {
+        let path = nonce_file("restart");
+        let mut nonce_manager_before_restart = nonce_manager(&path, 0);
+        assert_eq!(nonce_manager_before_restart.reserve().unwrap(), 0);
+        assert_eq!(nonce_manager_before_restart.reserve().unwrap(), 1);
+
+        // The transactions have not reached the Ethereum node yet
+        let mut nonce_manager_after_restart = nonce_manager(&path, 0);
+
+        assert_eq!(nonce_manager_after_restart.reserve().unwrap(), 2);
+    }
+}
+
+mod reserve {
+    use super::*;
+
+    #[test]
+    fn fails_before_the_nonce_manager_is_initialised() {
+        assert!(NonceManager::default().reserve().is_err());
+    }
+
+    #[test]
+    fn hands_out_consecutive_nonces_and_persists_the_next_one() {
+        let path = nonce_file("consecutive");
+        let mut nonce_manager = nonce_manager(&path, 0);
+
+        assert_eq!(nonce_manager.reserve().unwrap(), 0);
+        assert_eq!(nonce_manager.reserve().unwrap(), 1);
+        assert_eq!(stored_nonce(&path), 2);
+    }
+
+    #[test]
+    fn hands_out_released_nonces_first() {
+        let path = nonce_file("released_first");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        let first_nonce = nonce_manager.reserve().unwrap();
+        nonce_manager.reserve().unwrap();
+
+        nonce_manager.release(first_nonce).unwrap();
+
+        assert_eq!(nonce_manager.reserve().unwrap(), first_nonce);
+        assert_eq!(nonce_manager.reserve().unwrap(), 2);
+    }
+}
+
+mod release {
+    use super::*;
+
+    #[test]
+    fn shrinks_the_handed_out_range_when_the_last_nonce_is_released() {
+        let path = nonce_file("release_last");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        nonce_manager.reserve().unwrap();
+        let last_nonce = nonce_manager.reserve().unwrap();
+
+        nonce_manager.release(last_nonce).unwrap();
+
+        assert_eq!(stored_nonce(&path), last_nonce);
+        assert_eq!(nonce_manager.reserve().unwrap(), last_nonce);
+    }
+
+    #[test]
+    fn ignores_committed_nonces() {
+        let path = nonce_file("release_committed");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        let nonce = nonce_manager.reserve().unwrap();
+        nonce_manager.commit(nonce);
+
+        nonce_manager.release(nonce).unwrap();
+
+        assert_eq!(nonce_manager.reserve().unwrap(), nonce + 1);
+    }
+}
+
+mod reconcile {
+    use super::*;
+
+    #[test]
+    fn uses_the_pending_nonce_when_nothing_is_reserved() {
+        let path = nonce_file("reconcile_nothing_reserved");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        let nonce = nonce_manager.reserve().unwrap();
+        nonce_manager.commit(nonce);
+
+        // The transaction was dropped by Ethereum, so its nonce is free again
+        nonce_manager.reconcile(0).unwrap();
+
+        assert_eq!(stored_nonce(&path), 0);
+        assert_eq!(nonce_manager.reserve().unwrap(), 0);
+    }
+
+    #[test]
+    fn only_moves_forward_while_nonces_are_reserved() {
+        let path = nonce_file("reconcile_reserved");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        nonce_manager.reserve().unwrap();
+        nonce_manager.reserve().unwrap();
+
+        nonce_manager.reconcile(1).unwrap();
+        assert_eq!(stored_nonce(&path), 2);
+
+        nonce_manager.reconcile(4).unwrap();
+        assert_eq!(stored_nonce(&path), 4);
+    }
+
+    #[test]
+    fn forgets_released_nonces_that_ethereum_has_used() {
+        let path = nonce_file("reconcile_released");
+        let mut nonce_manager = nonce_manager(&path, 0);
+        let first_nonce = nonce_manager.reserve().unwrap();
+        nonce_manager.reserve().unwrap();
+        nonce_manager.release(first_nonce).unwrap();
+
+        nonce_manager.reconcile(2).unwrap();
+
+        assert_eq!(nonce_manager.reserve().unwrap(), 2);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::signer::{EthereumSigner, RemoteSigner};
+use hyper::{Body, Request, Response, Server, StatusCode, service::{make_service_fn, service_fn}};
+use secp256k1::{Message, PublicKey, RecoveryId, SecretKey, Signature};
+use sp_core::hashing::keccak_256;
+use std::{convert::Infallible, net::SocketAddr, sync::{Arc, atomic::{AtomicUsize, Ordering}}};
+
+const STUB_SIGNER_SECRET_KEY: [u8; 32] = [7u8; 32];
+const OTHER_SECRET_KEY: [u8; 32] = [8u8; 32];
+
+fn stub_signer_eth_address() -> Vec<u8> {
+    let secret = SecretKey::parse(&STUB_SIGNER_SECRET_KEY).expect("Valid secret key");
+    let public_key = PublicKey::from_secret_key(&secret).serialize();
+    return keccak_256(&public_key[1..])[12..].to_vec();
+}
+
+/// A minimal remote signer that advertises `STUB_SIGNER_SECRET_KEY` but signs with `signing_secret_key`
+async fn stub_signer(
+    request: Request<Body>,
+    signing_secret_key: [u8; 32],
+    public_key_requests: Arc<AtomicUsize>) -> Result<Response<Body>, Infallible>
+{
+    let secret = SecretKey::parse(&STUB_SIGNER_SECRET_KEY).expect("Valid secret key");
+    let path = request.uri().path().to_string();
+
+    if path == "/api/v1/eth1/publicKeys" {
+        public_key_requests.fetch_add(1, Ordering::SeqCst);
+        let public_key = PublicKey::from_secret_key(&secret).serialize();
+        return Ok(Response::new(Body::from(format!("[\"0x{}\"]", hex::encode(&public_key[..])))));
+    }
+
+    if path != format!("/api/v1/eth1/sign/0x{}", hex::encode(stub_signer_eth_address())) {
+        let mut not_found = Response::new(Body::empty());
+        *not_found.status_mut() = StatusCode::NOT_FOUND;
+        return Ok(not_found);
+    }
+
+    let body = hyper::body::to_bytes(request.into_body()).await.expect("Valid body");
+    let sign_request: serde_json::Value = serde_json::from_slice(&body).expect("Valid json");
+    let hash = hex::decode(sign_request["data"].as_str().expect("data is set").trim_start_matches("0x")).expect("Valid hex");
+
+    let signing_secret = SecretKey::parse(&signing_secret_key).expect("Valid secret key");
+    let (signature, recovery_id) = secp256k1::sign(&Message::parse_slice(&hash).expect("32 bytes"), &signing_secret);
+    let mut signature_bytes = signature.serialize().to_vec();
+    signature_bytes.push(recovery_id.serialize() + 27);
+
+    Ok(Response::new(Body::from(format!("\"0x{}\"", hex::encode(signature_bytes)))))
+}
+
+/// Returns the url of the stub signer and a counter of the public key requests it received
+fn start_stub_signer_with_key(port: u16, signing_secret_key: [u8; 32]) -> (String, Arc<AtomicUsize>) {
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+    let public_key_requests = Arc::new(AtomicUsize::new(0));
+    let requests_counter = public_key_requests.clone();
+
+    let make_service = make_service_fn(move |_| {
+        let requests_counter = requests_counter.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| stub_signer(request, signing_secret_key, requests_counter.clone())))
+        }
+    });
+    tokio::spawn(Server::bind(&address).serve(make_service));
+
+    return (format!("http://127.0.0.1:{}/", port), public_key_requests);
+}
+
+fn start_stub_signer(port: u16) -> String {
+    return start_stub_signer_with_key(port, STUB_SIGNER_SECRET_KEY).0;
+}
+
+#[tokio::test]
+async fn remote_signer_returns_the_address_of_the_remote_key() {
+    let signer = RemoteSigner::new(start_stub_signer(23451));
+
+    assert_eq!(signer.address().await.expect("Address is returned"), stub_signer_eth_address());
+}
+
+#[tokio::test]
+async fn remote_signer_signature_is_from_the_remote_key() {
+    let signer = RemoteSigner::new(start_stub_signer(23452));
+    let hash = keccak_256(b"data to sign");
+
+    let signature = signer.sign_hash(hash).await.expect("Hash is signed");
+    let signature_bytes: &[u8] = signature.as_ref();
+
+    // v is normalised to the recovery id
+    assert!(signature_bytes[64] < 2);
+
+    let recovered_public_key = secp256k1::recover(
+        &Message::parse(&hash),
+        &Signature::parse_slice(&signature_bytes[0..64]).expect("Valid signature"),
+        &RecoveryId::parse(signature_bytes[64]).expect("Valid recovery id")
+    ).expect("Public key is recovered");
+
+    assert_eq!(keccak_256(&recovered_public_key.serialize()[1..])[12..].to_vec(), stub_signer_eth_address());
+}
+
+#[tokio::test]
+async fn remote_signer_only_fetches_the_address_once() {
+    let (url, public_key_requests) = start_stub_signer_with_key(23453, STUB_SIGNER_SECRET_KEY);
+    let signer = RemoteSigner::new(url);
+
+    signer.address().await.expect("Address is returned");
+    signer.sign_hash(keccak_256(b"first")).await.expect("Hash is signed");
+    signer.sign_hash(keccak_256(b"second")).await.expect("Hash is signed");
+
+    assert_eq!(public_key_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn remote_signer_rejects_a_signature_from_another_key() {
+    let (url, _) = start_stub_signer_with_key(23454, OTHER_SECRET_KEY);
+    let signer = RemoteSigner::new(url);
+
+    assert!(signer.sign_hash(keccak_256(b"data to sign")).await.is_err());
+}
@@ -0,0 +1,155 @@
+use crate::transaction_utils::{UnsignedTransaction, LegacyTransaction, Eip1559Transaction};
+use crate::web3_utils::{calculate_eip1559_fees, Eip1559Fees, FeeHistory};
+use ethereum_types::{H160, U256};
+use sp_core::ecdsa::Signature;
+use web3::types::U256 as Web3U256;
+
+const ONE_GWEI: u64 = 1_000_000_000;
+const ONE_ETHER: u64 = 1_000_000_000_000_000_000;
+
+// r and s of the signature in the EIP-155 example: https://eips.ethereum.org/EIPS/eip-155
+const SIGNATURE_R: &str = "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276";
+const SIGNATURE_S: &str = "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+fn signature(recovery_id: u8) -> Signature {
+    let mut signature: [u8; 65] = [0; 65];
+    hex::decode_to_slice(SIGNATURE_R, &mut signature[0..32]).expect("Valid r");
+    hex::decode_to_slice(SIGNATURE_S, &mut signature[32..64]).expect("Valid s");
+    signature[64] = recovery_id;
+
+    return Signature::from_raw(signature);
+}
+
+/// The transaction of the EIP-155 example
+fn legacy_transaction() -> UnsignedTransaction {
+    return UnsignedTransaction::Legacy(LegacyTransaction {
+        chain_id: 1,
+        nonce: 9.into(),
+        gas_price: U256::from(20 * ONE_GWEI),
+        gas: 21000.into(),
+        to: Some(H160::repeat_byte(0x35)),
+        value: U256::from(ONE_ETHER),
+        data: vec![],
+    });
+}
+
+fn eip1559_transaction() -> UnsignedTransaction {
+    return UnsignedTransaction::Eip1559(Eip1559Transaction {
+        chain_id: 1,
+        nonce: 9.into(),
+        max_priority_fee_per_gas: U256::from(2 * ONE_GWEI),
+        max_fee_per_gas: U256::from(42 * ONE_GWEI),
+        gas: 21000.into(),
+        to: Some(H160::repeat_byte(0x35)),
+        value: U256::from(ONE_ETHER),
+        data: hex::decode("a9059cbb").expect("Valid data"),
+    });
+}
+
+fn fee_history(base_fees: Vec<u64>, rewards: Vec<Vec<u64>>) -> FeeHistory {
+    return FeeHistory {
+        base_fee_per_gas: base_fees.into_iter().map(Web3U256::from).collect(),
+        reward: rewards.into_iter().map(|block| block.into_iter().map(Web3U256::from).collect()).collect(),
+    };
+}
+
+mod legacy_transactions {
+    use super::*;
+
+    #[test]
+    fn signing_hash_matches_the_eip155_example() {
+        assert_eq!(
+            hex::encode(legacy_transaction().signing_hash()),
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+        );
+    }
+
+    #[test]
+    fn signed_encoding_matches_the_eip155_example() {
+        assert_eq!(
+            hex::encode(legacy_transaction().encode_signed(&signature(0))),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a0\
+             28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a0\
+             67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+}
+
+mod eip1559_transactions {
+    use super::*;
+
+    #[test]
+    fn signing_hash_is_the_hash_of_the_typed_payload() {
+        // keccak256(0x02 || rlp([1, 9, 2 gwei, 42 gwei, 21000, 0x3535..35, 1 ether, 0xa9059cbb, []]))
+        assert_eq!(
+            hex::encode(eip1559_transaction().signing_hash()),
+            "241424ef102f18819fa6aaaa8665e9276a0231e7177d7bf4cfa8994af997ed4b"
+        );
+    }
+
+    #[test]
+    fn signed_encoding_is_typed_and_uses_the_y_parity() {
+        let encoded_tx = eip1559_transaction().encode_signed(&signature(1));
+
+        assert_eq!(encoded_tx[0], 0x02);
+        assert_eq!(
+            hex::encode(encoded_tx),
+            "02f877010984773594008509c7652400825208943535353535353535353535353535353535353535880de0b6b3a7640000\
+             84a9059cbbc001a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a0\
+             67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn signing_hash_does_not_depend_on_the_legacy_fields() {
+        assert_ne!(eip1559_transaction().signing_hash(), legacy_transaction().signing_hash());
+    }
+}
+
+mod eip1559_fees {
+    use super::*;
+
+    #[test]
+    fn max_fee_is_twice_the_next_base_fee_plus_the_average_priority_fee() {
+        let fees = calculate_eip1559_fees(fee_history(vec![90, 100, 120], vec![vec![10], vec![20], vec![30]]))
+            .expect("Fees are calculated");
+
+        assert_eq!(fees, Eip1559Fees { max_fee_per_gas: Web3U256::from(260), max_priority_fee_per_gas: Web3U256::from(20) });
+    }
+
+    #[test]
+    fn priority_fee_is_zero_without_rewards() {
+        let fees = calculate_eip1559_fees(fee_history(vec![100], vec![])).expect("Fees are calculated");
+
+        assert_eq!(fees, Eip1559Fees { max_fee_per_gas: Web3U256::from(200), max_priority_fee_per_gas: Web3U256::zero() });
+    }
+
+    #[test]
+    fn blocks_without_rewards_are_ignored() {
+        let fees = calculate_eip1559_fees(fee_history(vec![100], vec![vec![10], vec![], vec![30]]))
+            .expect("Fees are calculated");
+
+        assert_eq!(fees.max_priority_fee_per_gas, Web3U256::from(20));
+    }
+
+    #[test]
+    fn fails_without_a_base_fee() {
+        assert!(calculate_eip1559_fees(fee_history(vec![], vec![vec![10]])).is_err());
+    }
+
+    #[test]
+    fn fee_history_is_decoded_from_the_rpc_response() {
+        let response = serde_json::json!({
+            "oldestBlock": "0x1",
+            "baseFeePerGas": ["0x64", "0x78"],
+            "gasUsedRatio": [0.5],
+            "reward": [["0xa"]]
+        });
+        let fee_history: FeeHistory = serde_json::from_value(response).expect("Fee history is decoded");
+
+        assert_eq!(
+            calculate_eip1559_fees(fee_history).expect("Fees are calculated"),
+            Eip1559Fees { max_fee_per_gas: Web3U256::from(250), max_priority_fee_per_gas: Web3U256::from(10) }
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{tx_monitor::*, web3_utils::TransactionFees};
+use ethereum_types::H256;
+use sp_avn_common::EthTransaction;
+use std::{fs, path::PathBuf};
+use web3::types::U256;
+
+/// Returns a path to a replacements file that does not exist yet
+fn replacements_file(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("avn-service-tx-monitor-{}", std::process::id()));
+    fs::create_dir_all(&directory).expect("Directory is created");
+
+    let path = directory.join(name);
+    let _ = fs::remove_file(&path);
+    return path;
+}
+
+fn tx_monitor(path: &PathBuf) -> TransactionMonitor {
+    let mut tx_monitor = TransactionMonitor::default();
+    tx_monitor.initialise(path.clone()).expect("Transaction monitor is initialised");
+    return tx_monitor;
+}
+
+fn dispatched_transaction(nonce: u64, tx_hash: H256) -> DispatchedTransaction {
+    return DispatchedTransaction {
+        send_request: EthTransaction::new([1; 32], sp_core::H160::from([2; 20]), vec![]),
+        nonce,
+        gas: U256::from(21_000),
+        fees: TransactionFees::Legacy { gas_price: U256::from(1) },
+        tx_hashes: vec![tx_hash],
+        last_sent_at_eth_block: 1,
+    };
+}
+
+fn replace(tx_monitor: &mut TransactionMonitor, transaction: &DispatchedTransaction, new_tx_hash: H256) {
+    tx_monitor.record_replacement(transaction, new_tx_hash, TransactionFees::Legacy { gas_price: U256::from(2) }, 2)
+        .expect("Replacement is recorded");
+}
+
+mod tx_hashes {
+    use super::*;
+
+    #[test]
+    fn is_the_hash_itself_when_the_transaction_was_not_replaced() {
+        let tx_monitor = tx_monitor(&replacements_file("not_replaced"));
+
+        assert_eq!(tx_monitor.tx_hashes(&H256::from([1; 32])), vec![H256::from([1; 32])]);
+    }
+
+    #[test]
+    fn lists_every_replacement_of_a_tracked_transaction() {
+        let mut tx_monitor = tx_monitor(&replacements_file("tracked"));
+        let transaction = dispatched_transaction(0, H256::from([1; 32]));
+        tx_monitor.track(transaction.clone()).unwrap();
+
+        replace(&mut tx_monitor, &transaction, H256::from([2; 32]));
+        replace(&mut tx_monitor, &transaction, H256::from([3; 32]));
+
+        assert_eq!(
+            tx_monitor.tx_hashes(&H256::from([1; 32])),
+            vec![H256::from([1; 32]), H256::from([2; 32]), H256::from([3; 32])]
+        );
+    }
+
+    #[test]
+    fn lists_the_replacements_of_a_transaction_that_is_no_longer_tracked() {
+        let mut tx_monitor = tx_monitor(&replacements_file("settled"));
+        let transaction = dispatched_transaction(0, H256::from([1; 32]));
+        tx_monitor.track(transaction.clone()).unwrap();
+        replace(&mut tx_monitor, &transaction, H256::from([2; 32]));
+        replace(&mut tx_monitor, &transaction, H256::from([3; 32]));
+
+        tx_monitor.stop_tracking(0).unwrap();
+
+        assert!(tx_monitor.pending_transactions().is_empty());
+        assert_eq!(
+            tx_monitor.tx_hashes(&H256::from([1; 32])),
+            vec![H256::from([1; 32]), H256::from([2; 32]), H256::from([3; 32])]
+        );
+    }
+}
+
+mod stop_tracking_tx_hash {
+    use super::*;
+
+    #[test]
+    fn stops_tracking_the_transaction_sent_with_any_of_its_hashes() {
+        let mut tx_monitor = tx_monitor(&replacements_file("stop_by_replacement"));
+        let transaction = dispatched_transaction(0, H256::from([1; 32]));
+        tx_monitor.track(transaction.clone()).unwrap();
+        tx_monitor.track(dispatched_transaction(1, H256::from([4; 32]))).unwrap();
+        replace(&mut tx_monitor, &transaction, H256::from([2; 32]));
+
+        tx_monitor.stop_tracking_tx_hash(&H256::from([2; 32])).unwrap();
+
+        let pending_nonces: Vec<u64> = tx_monitor.pending_transactions().iter().map(|tx| tx.nonce).collect();
+        assert_eq!(pending_nonces, vec![1]);
+        assert_eq!(tx_monitor.tx_hashes(&H256::from([1; 32])), vec![H256::from([1; 32]), H256::from([2; 32])]);
+    }
+
+    #[test]
+    fn does_nothing_when_the_transaction_is_not_tracked() {
+        let mut tx_monitor = tx_monitor(&replacements_file("stop_untracked"));
+        tx_monitor.track(dispatched_transaction(0, H256::from([1; 32]))).unwrap();
+
+        assert!(tx_monitor.stop_tracking_tx_hash(&H256::from([9; 32])).is_ok());
+
+        assert_eq!(tx_monitor.pending_transactions().len(), 1);
+    }
+}
+
+mod initialise {
+    use super::*;
+
+    #[test]
+    fn keeps_the_replacements_of_tracked_transactions_after_a_restart() {
+        let path = replacements_file("restart_tracked");
+        let mut tx_monitor_before_restart = tx_monitor(&path);
+        let transaction = dispatched_transaction(0, H256::from([1; 32]));
+        tx_monitor_before_restart.track(transaction.clone()).unwrap();
+        replace(&mut tx_monitor_before_restart, &transaction, H256::from([2; 32]));
+
+        let tx_monitor_after_restart = tx_monitor(&path);
+
+        assert!(tx_monitor_after_restart.pending_transactions().is_empty());
+        assert_eq!(tx_monitor_after_restart.tx_hashes(&H256::from([1; 32])), vec![H256::from([1; 32]), H256::from([2; 32])]);
+    }
+
+    #[test]
+    fn keeps_the_replacements_of_settled_transactions_after_a_restart() {
+        let path = replacements_file("restart_settled");
+        let mut tx_monitor_before_restart = tx_monitor(&path);
+        let transaction = dispatched_transaction(0, H256::from([1; 32]));
+        tx_monitor_before_restart.track(transaction.clone()).unwrap();
+        replace(&mut tx_monitor_before_restart, &transaction, H256::from([2; 32]));
+        tx_monitor_before_restart.stop_tracking(0).unwrap();
+
+        let tx_monitor_after_restart = tx_monitor(&path);
+
+        assert_eq!(tx_monitor_after_restart.tx_hashes(&H256::from([1; 32])), vec![H256::from([1; 32]), H256::from([2; 32])]);
+    }
+
+    #[test]
+    fn fails_when_the_stored_replacements_are_invalid() {
+        let path = replacements_file("invalid");
+        fs::write(&path, "not json").unwrap();
+
+        assert!(TransactionMonitor::default().initialise(path).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{select_quorum_receipt, web3_pool::Web3Pool};
+use ethereum_types::H256;
+use web3::types::{TransactionReceipt, U64};
+
+const PRIMARY_URL: &str = "http://127.0.0.1:23461";
+const SECONDARY_URL: &str = "http://127.0.0.1:23462";
+const TERTIARY_URL: &str = "http://127.0.0.1:23463";
+
+async fn web3_pool() -> Web3Pool {
+    return Web3Pool::new(&vec![PRIMARY_URL.to_string(), SECONDARY_URL.to_string(), TERTIARY_URL.to_string()]).await;
+}
+
+fn active_url(web3_pool: &Web3Pool) -> Option<String> {
+    return web3_pool.active().map(|endpoint| endpoint.url);
+}
+
+fn healthy_urls(web3_pool: &Web3Pool) -> Vec<String> {
+    return web3_pool.healthy().into_iter().map(|endpoint| endpoint.url).collect();
+}
+
+fn receipt(block_number: u64, status: u64) -> Option<TransactionReceipt> {
+    return Some(TransactionReceipt {
+        block_number: Some(U64::from(block_number)),
+        status: Some(U64::from(status)),
+        ..Default::default()
+    });
+}
+
+mod web3_pool {
+    use super::*;
+
+    #[tokio::test]
+    async fn prefers_the_first_endpoint() {
+        let web3_pool = web3_pool().await;
+
+        assert_eq!(active_url(&web3_pool), Some(PRIMARY_URL.to_string()));
+        assert_eq!(healthy_urls(&web3_pool), vec![PRIMARY_URL.to_string(), SECONDARY_URL.to_string(), TERTIARY_URL.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn ignores_invalid_urls() {
+        let web3_pool = Web3Pool::new(&vec!["not a url".to_string(), SECONDARY_URL.to_string()]).await;
+
+        assert_eq!(healthy_urls(&web3_pool), vec![SECONDARY_URL.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_the_next_healthy_endpoint() {
+        let mut web3_pool = web3_pool().await;
+
+        web3_pool.set_health(&PRIMARY_URL.to_string(), false);
+        assert_eq!(active_url(&web3_pool), Some(SECONDARY_URL.to_string()));
+
+        web3_pool.set_health(&SECONDARY_URL.to_string(), false);
+        assert_eq!(active_url(&web3_pool), Some(TERTIARY_URL.to_string()));
+        assert_eq!(healthy_urls(&web3_pool), vec![TERTIARY_URL.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn has_no_active_endpoint_when_all_are_unhealthy() {
+        let mut web3_pool = web3_pool().await;
+
+        for url in vec![PRIMARY_URL, SECONDARY_URL, TERTIARY_URL] {
+            web3_pool.set_health(&url.to_string(), false);
+        }
+
+        assert_eq!(active_url(&web3_pool), None);
+        assert!(healthy_urls(&web3_pool).is_empty());
+        assert_eq!(web3_pool.all().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn moves_back_to_a_preferred_endpoint_once_it_recovers() {
+        let mut web3_pool = web3_pool().await;
+        web3_pool.set_health(&PRIMARY_URL.to_string(), false);
+        assert_eq!(active_url(&web3_pool), Some(SECONDARY_URL.to_string()));
+
+        web3_pool.set_health(&PRIMARY_URL.to_string(), true);
+
+        assert_eq!(active_url(&web3_pool), Some(PRIMARY_URL.to_string()));
+    }
+}
+
+mod quorum_receipt {
+    use super::*;
+
+    #[test]
+    fn is_accepted_when_enough_endpoints_agree() {
+        let responses = vec![(receipt(10, 1), 15), (receipt(10, 1), 14), (receipt(10, 1), 16)];
+
+        assert_eq!(select_quorum_receipt(responses, 2, H256::zero()), Some((receipt(10, 1), 14)));
+    }
+
+    #[test]
+    fn is_rejected_when_the_endpoints_disagree() {
+        let responses = vec![(receipt(10, 1), 15), (receipt(10, 0), 15), (receipt(11, 1), 15)];
+
+        assert_eq!(select_quorum_receipt(responses, 2, H256::zero()), None);
+    }
+
+    #[test]
+    fn ignores_a_minority_of_disagreeing_endpoints() {
+        let responses = vec![(receipt(10, 0), 15), (receipt(10, 1), 15), (receipt(10, 1), 15)];
+
+        assert_eq!(select_quorum_receipt(responses, 2, H256::zero()), Some((receipt(10, 1), 15)));
+    }
+
+    #[test]
+    fn can_agree_that_there_is_no_receipt() {
+        let responses = vec![(None, 15), (receipt(10, 1), 15), (None, 15)];
+
+        assert_eq!(select_quorum_receipt(responses, 2, H256::zero()), Some((None, 15)));
+    }
+
+    #[test]
+    fn is_rejected_when_too_few_endpoints_respond() {
+        assert_eq!(select_quorum_receipt(vec![(receipt(10, 1), 15)], 2, H256::zero()), None);
+        assert_eq!(select_quorum_receipt(vec![], 1, H256::zero()), None);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::web3_utils::batch_call_succeeded;
+use ethereum_types::{H160, H256};
+use sp_core::hashing::keccak_256;
+use web3::types::{Bytes, Log, TransactionReceipt, U256};
+
+const MULTICALL_CONTRACT: [u8; 20] = [9; 20];
+const TARGET_CONTRACT: [u8; 20] = [7; 20];
+
+fn call_result_log(emitter: [u8; 20], call_index: u64, success: bool) -> Log {
+    let mut data = [0u8; 64];
+    U256::from(call_index).to_big_endian(&mut data[0..32]);
+    data[63] = success as u8;
+
+    return Log {
+        address: H160::from(emitter),
+        topics: vec![H256(keccak_256(b"LogCallResult(uint256,bool)"))],
+        data: Bytes(data.to_vec()),
+        ..Default::default()
+    };
+}
+
+fn batch_receipt(logs: Vec<Log>) -> TransactionReceipt {
+    return TransactionReceipt {
+        to: Some(H160::from(MULTICALL_CONTRACT)),
+        logs,
+        ..Default::default()
+    };
+}
+
+mod batch_call_succeeded {
+    use super::*;
+
+    #[test]
+    fn reads_the_result_of_each_call() {
+        let receipt = batch_receipt(vec![
+            call_result_log(MULTICALL_CONTRACT, 0, true),
+            call_result_log(MULTICALL_CONTRACT, 1, false),
+        ]);
+
+        assert_eq!(batch_call_succeeded(&receipt, 0).unwrap(), true);
+        assert_eq!(batch_call_succeeded(&receipt, 1).unwrap(), false);
+    }
+
+    #[test]
+    fn ignores_results_emitted_by_another_contract() {
+        let receipt = batch_receipt(vec![
+            call_result_log(TARGET_CONTRACT, 0, true),
+            call_result_log(MULTICALL_CONTRACT, 0, false),
+        ]);
+
+        assert_eq!(batch_call_succeeded(&receipt, 0).unwrap(), false);
+    }
+
+    #[test]
+    fn fails_when_the_call_has_no_result() {
+        let receipt = batch_receipt(vec![call_result_log(MULTICALL_CONTRACT, 0, true)]);
+
+        assert!(batch_call_succeeded(&receipt, 1).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use ethereum_types::{H160, U256};
+use rlp::RlpStream;
+use sp_core::{ecdsa::Signature, hashing::keccak_256};
+use tide::Error as TideError;
+use crate::signer::EthereumSigner;
+
+/// EIP-2718 envelope type of an EIP-1559 transaction
+pub const EIP1559_TRANSACTION_TYPE: u8 = 0x02;
+
+/// A legacy Ethereum transaction, replay protected as described in https://eips.ethereum.org/EIPS/eip-155
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyTransaction {
+    pub chain_id: u64,
+    pub nonce: U256,
+    pub gas_price: U256,
+    pub gas: U256,
+    pub to: Option<H160>,
+    pub value: U256,
+    pub data: Vec<u8>,
+}
+
+/// An EIP-1559 (type-2) Ethereum transaction.
+/// See https://eips.ethereum.org/EIPS/eip-1559
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Transaction {
+    pub chain_id: u64,
+    pub nonce: U256,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas: U256,
+    pub to: Option<H160>,
+    pub value: U256,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnsignedTransaction {
+    Legacy(LegacyTransaction),
+    Eip1559(Eip1559Transaction),
+}
+
+impl UnsignedTransaction {
+    /// Signs the transaction and returns the bytes that can be sent via `eth_sendRawTransaction`
+    pub async fn sign(&self, signer: &dyn EthereumSigner) -> Result<Vec<u8>, TideError> {
+        let signature = signer.sign_hash(self.signing_hash()).await?;
+        return Ok(self.encode_signed(&signature));
+    }
+
+    pub fn signing_hash(&self) -> [u8; 32] {
+        match self {
+            UnsignedTransaction::Legacy(tx) => keccak_256(&tx.encode(None)),
+            UnsignedTransaction::Eip1559(tx) => keccak_256(&tx.encode(None)),
+        }
+    }
+
+    /// `signature` must be a 65 byte (r, s, v) signature where v is the recovery id (0 or 1)
+    pub fn encode_signed(&self, signature: &Signature) -> Vec<u8> {
+        let signature_bytes: &[u8] = signature.as_ref();
+        let r = U256::from_big_endian(&signature_bytes[0..32]);
+        let s = U256::from_big_endian(&signature_bytes[32..64]);
+        let recovery_id = signature_bytes[64] as u64;
+
+        match self {
+            UnsignedTransaction::Legacy(tx) => tx.encode(Some((recovery_id + tx.chain_id * 2 + 35, r, s))),
+            UnsignedTransaction::Eip1559(tx) => tx.encode(Some((recovery_id, r, s))),
+        }
+    }
+}
+
+impl LegacyTransaction {
+    /// Returns `rlp([nonce, gas_price, gas, to, value, data, v, r, s])`.
+    /// When there is no signature, `[chain_id, 0, 0]` is used instead of `[v, r, s]` to get the signing payload.
+    fn encode(&self, signature: Option<(u64, U256, U256)>) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(9);
+
+        stream.append(&self.nonce);
+        stream.append(&self.gas_price);
+        stream.append(&self.gas);
+        append_recipient(&mut stream, &self.to);
+        stream.append(&self.value);
+        stream.append(&self.data);
+
+        match signature {
+            Some((v, r, s)) => {
+                stream.append(&v);
+                stream.append(&r);
+                stream.append(&s);
+            },
+            None => {
+                stream.append(&self.chain_id);
+                stream.append(&0u8);
+                stream.append(&0u8);
+            }
+        }
+
+        return stream.out().to_vec();
+    }
+}
+
+impl Eip1559Transaction {
+    /// Returns `0x02 || rlp([chain_id, nonce, max_priority_fee_per_gas, max_fee_per_gas, gas, to, value, data, access_list])`
+    /// with the `[y_parity, r, s]` fields appended to the list when a signature is provided.
+    fn encode(&self, signature: Option<(u64, U256, U256)>) -> Vec<u8> {
+        let mut stream = RlpStream::new();
+        stream.begin_list(if signature.is_some() { 12 } else { 9 });
+
+        stream.append(&self.chain_id);
+        stream.append(&self.nonce);
+        stream.append(&self.max_priority_fee_per_gas);
+        stream.append(&self.max_fee_per_gas);
+        stream.append(&self.gas);
+        append_recipient(&mut stream, &self.to);
+        stream.append(&self.value);
+        stream.append(&self.data);
+        // We don't use access lists
+        stream.begin_list(0);
+
+        if let Some((y_parity, r, s)) = signature {
+            stream.append(&y_parity);
+            stream.append(&r);
+            stream.append(&s);
+        }
+
+        let mut encoded_tx = vec![EIP1559_TRANSACTION_TYPE];
+        encoded_tx.extend_from_slice(&stream.out());
+        return encoded_tx;
+    }
+}
+
+fn append_recipient(stream: &mut RlpStream, to: &Option<H160>) {
+    match to {
+        Some(ref to) => stream.append(to),
+        None => stream.append(&""),
+    };
+}
@@ -0,0 +1,316 @@
+use crate::{server_error, Config, web3_utils::*, web3_pool::get_active_web3, metrics::update_wallet_metrics};
+use sp_avn_common::EthTransaction;
+use sp_runtime::{traits::Block as BlockT};
+use sc_client_api::{UsageProvider, client::BlockBackend};
+use std::{collections::{HashMap, VecDeque}, fs::{self, File}, io::Write, path::PathBuf, time::Duration};
+use tide::Error as TideError;
+use web3::{Web3, types::{Bytes, TransactionReceipt, U256}};
+use ethereum_types::H256;
+pub use std::sync::Arc;
+
+/// How often we check if the transactions we sent have been mined
+const MONITOR_INTERVAL_SECONDS: u64 = 30;
+/// Percentage by which the fees of a stuck transaction are increased when it is replaced
+const FEE_BUMP_PERCENTAGE: u64 = 20;
+/// Number of Ethereum blocks after which an unmined transaction is replaced, unless configured otherwise
+pub const DEFAULT_STUCK_TRANSACTION_BLOCKS: u64 = 20;
+/// Number of replacements of transactions that are no longer tracked we remember, so they can still be queried
+const MAX_SETTLED_REPLACEMENTS: usize = 1024;
+
+/// A transaction sent to Ethereum by this node that has not been mined yet
+#[derive(Debug, Clone)]
+pub struct DispatchedTransaction {
+    pub send_request: EthTransaction,
+    pub nonce: u64,
+    pub gas: U256,
+    pub fees: TransactionFees,
+    /// Every hash sent with this nonce. The last one is the most recent replacement.
+    pub tx_hashes: Vec<H256>,
+    pub last_sent_at_eth_block: u64,
+}
+
+impl DispatchedTransaction {
+    pub fn original_tx_hash(&self) -> H256 {
+        return self.tx_hashes[0];
+    }
+
+    pub fn latest_tx_hash(&self) -> H256 {
+        return *self.tx_hashes.last().expect("There is always at least one hash");
+    }
+}
+
+/// Tracks the transactions sent by this node until they are mined, or until the runtime hands them over to another
+/// validator.
+///
+/// Which transaction replaced which is persisted, so the replacements of a transaction can still be found after a
+/// restart. The transactions that were tracked before a restart are not tracked again.
+#[derive(Debug, Default)]
+pub struct TransactionMonitor {
+    path: Option<PathBuf>,
+    pending: Vec<DispatchedTransaction>,
+    /// Maps the hash returned by `/eth/send` to the hashes of its replacements, oldest first, for the tracked transactions
+    replacements: HashMap<H256, Vec<H256>>,
+    /// Replacements of transactions that are no longer tracked, as (original hash, replacement hash), oldest first
+    settled_replacements: VecDeque<(H256, H256)>,
+}
+
+impl TransactionMonitor {
+    /// Sets up the replacements storage in `path` and loads the replacements recorded before a restart
+    pub fn initialise(&mut self, path: PathBuf) -> Result<(), TideError> {
+        self.settled_replacements = read_replacements_from_file(&path)?.into_iter().collect();
+        self.replacements.clear();
+        self.pending.clear();
+        self.path = Some(path);
+
+        return self.persist();
+    }
+
+    pub fn track(&mut self, transaction: DispatchedTransaction) -> Result<(), TideError> {
+        // A nonce can only be used by one transaction, so any older entry has been superseded
+        let result = self.stop_tracking(transaction.nonce);
+        self.pending.push(transaction);
+
+        return result;
+    }
+
+    /// Returns `tx_hash` followed by the hashes of the transactions that replaced it, oldest first
+    pub fn tx_hashes(&self, tx_hash: &H256) -> Vec<H256> {
+        let replacements: Vec<H256> = match self.replacements.get(tx_hash) {
+            Some(replacements) => replacements.clone(),
+            None => self.settled_replacements.iter()
+                .filter(|(original_tx_hash, _)| original_tx_hash == tx_hash)
+                .map(|(_, replacement_tx_hash)| *replacement_tx_hash)
+                .collect(),
+        };
+
+        return vec![*tx_hash].into_iter().chain(replacements.into_iter()).collect();
+    }
+
+    pub fn pending_transactions(&self) -> Vec<DispatchedTransaction> {
+        return self.pending.clone();
+    }
+
+    pub fn stop_tracking(&mut self, nonce: u64) -> Result<(), TideError> {
+        let (stopped, pending): (Vec<DispatchedTransaction>, Vec<DispatchedTransaction>) =
+            self.pending.drain(..).partition(|tx| tx.nonce == nonce);
+        self.pending = pending;
+
+        let mut settled_any_replacement = false;
+        for tx in stopped {
+            if let Some(replacements) = self.replacements.remove(&tx.original_tx_hash()) {
+                for replacement_tx_hash in replacements {
+                    self.settled_replacements.push_back((tx.original_tx_hash(), replacement_tx_hash));
+                }
+                settled_any_replacement = true;
+            }
+        }
+
+        while self.settled_replacements.len() > MAX_SETTLED_REPLACEMENTS {
+            self.settled_replacements.pop_front();
+        }
+
+        if settled_any_replacement {
+            return self.persist();
+        }
+
+        return Ok(());
+    }
+
+    /// Stops replacing the transaction sent with `tx_hash`, or with any of its replacements, if it is still tracked
+    pub fn stop_tracking_tx_hash(&mut self, tx_hash: &H256) -> Result<(), TideError> {
+        let nonce = self.pending.iter()
+            .find(|tx| tx.tx_hashes.contains(tx_hash))
+            .map(|tx| tx.nonce);
+
+        return match nonce {
+            Some(nonce) => self.stop_tracking(nonce),
+            None => Ok(()),
+        };
+    }
+
+    pub fn record_replacement(&mut self,
+        replaced: &DispatchedTransaction,
+        new_tx_hash: H256,
+        fees: TransactionFees,
+        eth_block: u64) -> Result<(), TideError>
+    {
+        if let Some(tx) = self.pending.iter_mut().find(|tx| tx.nonce == replaced.nonce) {
+            tx.tx_hashes.push(new_tx_hash);
+            tx.fees = fees;
+            tx.last_sent_at_eth_block = eth_block;
+        }
+
+        self.replacements.entry(replaced.original_tx_hash()).or_insert_with(Vec::new).push(new_tx_hash);
+
+        // The replacement must survive a restart, otherwise the outcome of the original transaction is lost
+        return self.persist();
+    }
+
+    /// Stores every replacement we know of, oldest first. Nothing is stored until the storage is set up.
+    fn persist(&self) -> Result<(), TideError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let replacements: Vec<(H256, H256)> = self.settled_replacements.iter().cloned()
+            .chain(self.replacements.iter().flat_map(|(original_tx_hash, replacements)|
+                replacements.iter().map(move |replacement_tx_hash| (*original_tx_hash, *replacement_tx_hash))
+            ))
+            .collect();
+
+        return write_replacements_to_file(path, &replacements);
+    }
+}
+
+fn read_replacements_from_file(path: &PathBuf) -> Result<Vec<(H256, H256)>, TideError> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let file = File::open(path).map_err(|e| server_error(format!("Error reading transaction replacements: {:?}", e)))?;
+    let mut replacements: Vec<(H256, H256)> = serde_json::from_reader(file)
+        .map_err(|e| server_error(format!("Invalid transaction replacements in {:?}: {:?}", path, e)))?;
+
+    if replacements.len() > MAX_SETTLED_REPLACEMENTS {
+        replacements.drain(..replacements.len() - MAX_SETTLED_REPLACEMENTS);
+    }
+
+    return Ok(replacements);
+}
+
+/// Writes to a temporary file and renames it so the stored replacements are never left partially written
+fn write_replacements_to_file(path: &PathBuf, replacements: &Vec<(H256, H256)>) -> Result<(), TideError> {
+    let temp_path = path.with_extension("tmp");
+
+    let mut temp_file = File::create(&temp_path)
+        .map_err(|e| server_error(format!("Error opening transaction replacements file: {:?}", e)))?;
+    serde_json::to_writer(&temp_file, replacements)
+        .map_err(|e| server_error(format!("Error writing transaction replacements: {:?}", e)))?;
+    temp_file.flush().map_err(|e| server_error(format!("Error writing transaction replacements: {:?}", e)))?;
+    temp_file.sync_all().map_err(|e| server_error(format!("Error writing transaction replacements: {:?}", e)))?;
+
+    fs::rename(&temp_path, path).map_err(|e| server_error(format!("Error writing transaction replacements: {:?}", e)))?;
+
+    Ok(())
+}
+
+/// Watches the transactions sent by `/eth/send` and replaces the ones that stay unmined for more than
+/// `eth_stuck_tx_blocks` Ethereum blocks with a copy that uses the same nonce and higher fees.
+// Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+#[tokio::main]
+pub async fn monitor_dispatched_transactions<Block: BlockT, ClientT>(config: Arc<Config<Block, ClientT>>)
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    loop {
+        tokio::time::delay_for(Duration::from_secs(MONITOR_INTERVAL_SECONDS)).await;
+
+        if let Err(e) = check_dispatched_transactions(&config).await {
+            log::error!("💔 Error checking dispatched transactions: {:?}", e);
+        }
+
+        if let Err(e) = update_wallet_metrics(&config).await {
+            log::error!("💔 Error updating wallet metrics: {:?}", e);
+        }
+    }
+}
+
+async fn check_dispatched_transactions<Block: BlockT, ClientT>(config: &Arc<Config<Block, ClientT>>) -> Result<(), TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    let pending_transactions = config.tx_monitor.lock()
+        .map_err(|_| server_error("Failed to get the transaction monitor".to_string()))?
+        .pending_transactions();
+
+    if pending_transactions.is_empty() {
+        return Ok(());
+    }
+
+    let web3 = get_active_web3(config)?.web3;
+
+    let my_eth_address = config.signer.address().await?;
+    let confirmed_nonce = get_nonce_from_ethereum(&web3, &my_eth_address).await?.low_u64();
+    let current_block_number = get_current_block_number(&web3).await?;
+
+    for transaction in pending_transactions {
+        let mined_receipt = match get_mined_receipt(&web3, &transaction).await {
+            Ok(mined_receipt) => mined_receipt,
+            Err(e) => {
+                log::error!("💔 Error getting the receipt of transaction {:?}: {:?}", transaction.latest_tx_hash(), e);
+                continue;
+            }
+        };
+        if let (Some(receipt), Some(metrics)) = (&mined_receipt, &config.metrics) {
+            metrics.gas_used.inc_by(receipt.gas_used.unwrap_or_default().low_u64());
+        }
+
+        if mined_receipt.is_some() || confirmed_nonce > transaction.nonce {
+            if let Ok(mut monitor) = config.tx_monitor.lock() {
+                if let Err(e) = monitor.stop_tracking(transaction.nonce) {
+                    log::error!("💔 Error storing the replacement of transaction {:?}: {:?}", transaction.original_tx_hash(), e);
+                }
+            }
+            continue;
+        }
+
+        if current_block_number.saturating_sub(transaction.last_sent_at_eth_block) < config.eth_stuck_tx_blocks {
+            continue;
+        }
+
+        match replace_transaction(&web3, &config, &transaction).await {
+            Ok((new_tx_hash, new_fees)) => {
+                log::info!(
+                    "ℹ️ Replaced stuck transaction {:?} (nonce: {:?}) with {:?}",
+                    transaction.latest_tx_hash(), transaction.nonce, new_tx_hash
+                );
+
+                if let Ok(mut monitor) = config.tx_monitor.lock() {
+                    if let Err(e) = monitor.record_replacement(&transaction, new_tx_hash, new_fees, current_block_number) {
+                        log::error!("💔 Error storing the replacement of transaction {:?}: {:?}", transaction.original_tx_hash(), e);
+                    }
+                }
+            },
+            Err(e) => log::error!("💔 Error replacing stuck transaction {:?}: {:?}", transaction.latest_tx_hash(), e)
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_mined_receipt(web3: &Web3<EthTransport>, transaction: &DispatchedTransaction) -> Result<Option<TransactionReceipt>, TideError> {
+    // Any of the hashes could have been mined, not just the latest one
+    for tx_hash in &transaction.tx_hashes {
+        if let Some(receipt) = get_tx_receipt(web3, *tx_hash).await? {
+            return Ok(Some(receipt));
+        }
+    }
+
+    return Ok(None);
+}
+
+async fn replace_transaction<Block: BlockT, ClientT>(
+    web3: &Web3<EthTransport>,
+    config: &Arc<Config<Block, ClientT>>,
+    transaction: &DispatchedTransaction) -> Result<(H256, TransactionFees), TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    let chain_id = get_chain_id(web3).await?;
+
+    let market_fees = get_transaction_fees(web3, transaction.fees.is_legacy()).await?;
+    let new_fees = transaction.fees.bump(FEE_BUMP_PERCENTAGE, &market_fees);
+
+    let my_eth_address = config.signer.address().await?;
+    ensure_wallet_can_pay(web3, &my_eth_address, new_fees.max_cost(transaction.gas), config.eth_wallet_reserve).await?;
+
+    let signed_tx = build_transaction(
+        &transaction.send_request,
+        transaction.nonce,
+        transaction.gas,
+        &new_fees,
+        chain_id
+    ).sign(config.signer.as_ref()).await?;
+
+    let new_tx_hash = send_raw_transaction(web3, Bytes::from(signed_tx)).await?;
+
+    return Ok((H256::from_slice(new_tx_hash.as_bytes()), new_fees));
+}
@@ -0,0 +1,147 @@
+use crate::{server_error, errors::service_error, Config, web3_utils::{EthTransport, setup_web3_connection, get_current_block_number}};
+use sp_runtime::{traits::Block as BlockT};
+use sp_avn_common::external_service_errors::ExternalServiceErrorCode;
+use sc_client_api::{UsageProvider, client::BlockBackend};
+use std::time::Duration;
+use tide::Error as TideError;
+use web3::Web3;
+pub use std::sync::Arc;
+
+/// How often the health of every Ethereum endpoint is checked
+const HEALTH_CHECK_INTERVAL_SECONDS: u64 = 15;
+
+#[derive(Debug, Clone)]
+pub struct Web3Endpoint {
+    pub url: String,
+    pub web3: Web3<EthTransport>,
+    pub healthy: bool,
+}
+
+/// The Ethereum endpoints this node can use, in order of preference.
+/// Requests are sent to the first healthy endpoint, so if it goes down we automatically fail over to the next one
+/// and move back once it recovers.
+#[derive(Debug, Default)]
+pub struct Web3Pool {
+    endpoints: Vec<Web3Endpoint>,
+}
+
+impl Web3Pool {
+    pub async fn new(urls: &Vec<String>) -> Self {
+        let mut endpoints = vec![];
+
+        for url in urls {
+            match setup_web3_connection(url).await {
+                Some(web3) => endpoints.push(Web3Endpoint { url: url.clone(), web3, healthy: true }),
+                None => log::error!("💔 Error creating a web3 connection. URL is not valid {:?}", url),
+            }
+        }
+
+        return Web3Pool { endpoints };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.endpoints.is_empty();
+    }
+
+    /// Returns the preferred healthy endpoint
+    pub fn active(&self) -> Option<Web3Endpoint> {
+        return self.endpoints.iter().find(|endpoint| endpoint.healthy).cloned();
+    }
+
+    /// Returns every healthy endpoint, in order of preference
+    pub fn healthy(&self) -> Vec<Web3Endpoint> {
+        return self.endpoints.iter().filter(|endpoint| endpoint.healthy).cloned().collect();
+    }
+
+    pub fn all(&self) -> Vec<Web3Endpoint> {
+        return self.endpoints.clone();
+    }
+
+    pub fn replace_connection(&mut self, url: &String, web3: Web3<EthTransport>) {
+        if let Some(endpoint) = self.endpoints.iter_mut().find(|endpoint| &endpoint.url == url) {
+            endpoint.web3 = web3;
+        }
+    }
+
+    pub fn set_health(&mut self, url: &String, healthy: bool) {
+        if let Some(endpoint) = self.endpoints.iter_mut().find(|endpoint| &endpoint.url == url) {
+            if endpoint.healthy != healthy {
+                match healthy {
+                    true => log::info!("ℹ️ Ethereum endpoint {:?} is healthy again", url),
+                    false => log::warn!("⚠️ Ethereum endpoint {:?} is unhealthy, failing over", url),
+                }
+            }
+            endpoint.healthy = healthy;
+        }
+    }
+}
+
+pub fn get_active_web3<Block: BlockT, ClientT>(config: &Config<Block, ClientT>) -> Result<Web3Endpoint, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    return config.web3_pool.lock()
+        .map_err(|_| server_error("Failed to get web3".to_string()))?
+        .active()
+        .ok_or_else(|| service_error(ExternalServiceErrorCode::EthereumNodeUnavailable, "No healthy web3 connection".to_string()));
+}
+
+pub fn get_healthy_web3s<Block: BlockT, ClientT>(config: &Config<Block, ClientT>) -> Result<Vec<Web3Endpoint>, TideError>
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    return Ok(
+        config.web3_pool.lock()
+            .map_err(|_| server_error("Failed to get web3".to_string()))?
+            .healthy()
+    );
+}
+
+pub fn mark_unhealthy<Block: BlockT, ClientT>(config: &Config<Block, ClientT>, endpoint: &Web3Endpoint)
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    if let Ok(mut web3_pool) = config.web3_pool.lock() {
+        web3_pool.set_health(&endpoint.url, false);
+    }
+
+    if let Some(metrics) = &config.metrics {
+        metrics.report_ethereum_error(ExternalServiceErrorCode::EthereumNodeUnavailable);
+    }
+}
+
+/// Periodically checks that every configured Ethereum endpoint responds
+// Methods that require web3 must be run within the tokio runtime (#[tokio::main])
+#[tokio::main]
+pub async fn check_endpoints_health<Block: BlockT, ClientT>(config: Arc<Config<Block, ClientT>>)
+    where ClientT: BlockBackend<Block> + UsageProvider<Block> + Send + Sync + 'static
+{
+    loop {
+        let endpoints = match config.web3_pool.lock() {
+            Ok(web3_pool) => web3_pool.all(),
+            Err(_) => vec![],
+        };
+
+        for endpoint in endpoints {
+            let mut healthy = get_current_block_number(&endpoint.web3).await.is_ok();
+
+            // WebSocket and IPC connections don't recover by themselves once they drop, so we reconnect
+            if !healthy {
+                if let Some(web3) = setup_web3_connection(&endpoint.url).await {
+                    healthy = get_current_block_number(&web3).await.is_ok();
+
+                    if let Ok(mut web3_pool) = config.web3_pool.lock() {
+                        web3_pool.replace_connection(&endpoint.url, web3);
+                    }
+                }
+            }
+
+            if let Ok(mut web3_pool) = config.web3_pool.lock() {
+                web3_pool.set_health(&endpoint.url, healthy);
+            }
+
+            if let (false, Some(metrics)) = (healthy, &config.metrics) {
+                metrics.report_ethereum_error(ExternalServiceErrorCode::EthereumNodeUnavailable);
+            }
+        }
+
+        tokio::time::delay_for(Duration::from_secs(HEALTH_CHECK_INTERVAL_SECONDS)).await;
+    }
+}
@@ -1,71 +1,277 @@
 use tide::Error as TideError;
-use web3::{Web3, transports::Http, types::{CallRequest, TransactionReceipt, Bytes, H160, U256}};
-use sp_avn_common::EthTransaction;
-use ethereum_tx_sign::{RawTransaction};
+use web3::{Web3, Transport, transports::{Either, Http, WebSocket, Ipc}, types::{BlockNumber, CallRequest, FilterBuilder, Log, TransactionReceipt, Bytes, H160, U64, U256}};
+use sp_avn_common::{EthTransaction, external_service_errors::ExternalServiceErrorCode};
+use sp_core::hashing::keccak_256;
 use ethereum_types;
-use sc_keystore::LocalKeystore;
 pub use std::sync::{Arc, MutexGuard};
-use crate::{server_error, keystore_utils::get_nonce};
+use crate::{server_error, errors::{service_error, error_code}, transaction_utils::{UnsignedTransaction, LegacyTransaction, Eip1559Transaction}};
 
-pub fn setup_web3_connection(url: &String) -> Option<Web3<Http>> {
-    let transport_init_result = web3::transports::Http::new(url);
+/// Number of blocks used to work out the EIP-1559 fees
+const FEE_HISTORY_BLOCK_COUNT: u64 = 10;
+/// Percentile of the priority fees paid in the sampled blocks that we are willing to pay
+const PRIORITY_FEE_REWARD_PERCENTILE: f64 = 50.0;
+/// Multiplier applied to the next base fee so the transaction stays valid if the base fee keeps rising
+const BASE_FEE_MULTIPLIER: u64 = 2;
+/// Emitted by the multicall contract for each call of a batch
+const BATCH_CALL_RESULT_EVENT_SIGNATURE: &[u8] = b"LogCallResult(uint256,bool)";
 
-    if transport_init_result.is_err() {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Fees {
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FeeHistory {
+    pub(crate) base_fee_per_gas: Vec<U256>,
+    #[serde(default)]
+    pub(crate) reward: Vec<Vec<U256>>,
+}
+
+/// The transport used to connect to Ethereum. It is chosen from the scheme of the node URL.
+pub type EthTransport = Either<Http, Either<WebSocket, Ipc>>;
+
+const IPC_URL_PREFIX: &str = "ipc://";
+
+/// Connects to Ethereum using:
+///  - WebSocket for `ws://` and `wss://` URLs
+///  - IPC for `ipc://<path>` URLs and paths to a `.ipc` file
+///  - HTTP otherwise
+pub async fn setup_web3_connection(url: &String) -> Option<Web3<EthTransport>> {
+    let transport: Result<EthTransport, web3::Error> = if url.starts_with("ws://") || url.starts_with("wss://") {
+        WebSocket::new(url).await.map(|ws| Either::Right(Either::Left(ws)))
+    } else if url.starts_with(IPC_URL_PREFIX) || url.ends_with(".ipc") {
+        Ipc::new(url.trim_start_matches(IPC_URL_PREFIX)).await.map(|ipc| Either::Right(Either::Right(ipc)))
+    } else {
+        Http::new(url).map(Either::Left)
+    };
+
+    if let Err(ref e) = transport {
+        log::error!("💔 Error connecting to {:?}: {:?}", url, e);
         return None;
     }
-    let transport = transport_init_result.expect("Already checked");
-    return Some(web3::Web3::new(transport));
+
+    return Some(web3::Web3::new(transport.expect("Already checked")));
 }
 
-pub async fn get_nonce_from_ethereum(web3: &Web3<Http>, sender_eth_address: &Vec<u8>) -> Result<U256, TideError> {
+pub async fn get_nonce_from_ethereum(web3: &Web3<EthTransport>, sender_eth_address: &Vec<u8>) -> Result<U256, TideError> {
+    return get_transaction_count(web3, sender_eth_address, None).await;
+}
+
+/// Returns the nonce of the sender including the transactions that are still in the mempool
+pub async fn get_pending_nonce_from_ethereum(web3: &Web3<EthTransport>, sender_eth_address: &Vec<u8>) -> Result<U256, TideError> {
+    return get_transaction_count(web3, sender_eth_address, Some(BlockNumber::Pending)).await;
+}
+
+async fn get_transaction_count(web3: &Web3<EthTransport>, sender_eth_address: &Vec<u8>, block: Option<BlockNumber>) -> Result<U256, TideError> {
     if sender_eth_address.len() != 20 {
         return Err(server_error(format!("sender address ({:?}) is not a valid Ethereum address", sender_eth_address)));
     }
 
     return Ok(
         web3.eth()
-            .transaction_count(H160::from_slice(sender_eth_address), None)
+            .transaction_count(H160::from_slice(sender_eth_address), block)
             .await
             .map_err(|_| server_error("Error getting nonce from Ethereum".to_string()))?
     );
 }
 
-/// Note: this is called by the signer which has different ethereum types to web3
-pub async fn build_raw_transaction(
-    web3: &Web3<Http>,
-    keystore: &LocalKeystore,
+pub async fn get_balance(web3: &Web3<EthTransport>, eth_address: &Vec<u8>) -> Result<U256, TideError> {
+    if eth_address.len() != 20 {
+        return Err(server_error(format!("address ({:?}) is not a valid Ethereum address", eth_address)));
+    }
+
+    return Ok(
+        web3.eth()
+            .balance(H160::from_slice(eth_address), None)
+            .await
+            .map_err(|_| service_error(ExternalServiceErrorCode::EthereumNodeUnavailable, "Error getting balance from Ethereum".to_string()))?
+    );
+}
+
+/// Fails with `InsufficientFunds` if paying `cost` wei would drop the balance of `eth_address` below `reserve`
+pub async fn ensure_wallet_can_pay(web3: &Web3<EthTransport>, eth_address: &Vec<u8>, cost: U256, reserve: U256) -> Result<(), TideError> {
+    let balance = get_balance(web3, eth_address).await?;
+
+    if balance < reserve.saturating_add(cost) {
+        return Err(service_error(
+            ExternalServiceErrorCode::InsufficientFunds,
+            format!("Wallet balance of {} wei cannot pay up to {} wei for the transaction and keep a reserve of {} wei", balance, cost, reserve)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns true if Ethereum rejected a transaction because its nonce has already been used
+pub fn is_nonce_error(error: &TideError) -> bool {
+    return error_code(error) == ExternalServiceErrorCode::NonceTooLow;
+}
+
+fn is_nonce_error_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    return message.contains("nonce too low") || message.contains("the tx doesn't have the correct nonce");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionFees {
+    Legacy { gas_price: U256 },
+    Eip1559(Eip1559Fees),
+}
+
+impl TransactionFees {
+    /// Returns the fees increased by `percentage`, making sure they are not lower than the current `market_fees`.
+    /// Ethereum nodes only accept a replacement transaction (same nonce) if its fees are higher than the original ones.
+    pub fn bump(&self, percentage: u64, market_fees: &TransactionFees) -> TransactionFees {
+        let increase = |fee: U256| fee.saturating_add(fee.saturating_mul(U256::from(percentage)) / U256::from(100));
+
+        match (self, market_fees) {
+            (TransactionFees::Eip1559(fees), TransactionFees::Eip1559(market_fees)) => TransactionFees::Eip1559(Eip1559Fees {
+                max_fee_per_gas: increase(fees.max_fee_per_gas).max(market_fees.max_fee_per_gas),
+                max_priority_fee_per_gas: increase(fees.max_priority_fee_per_gas).max(market_fees.max_priority_fee_per_gas),
+            }),
+            (TransactionFees::Legacy { gas_price }, TransactionFees::Legacy { gas_price: market_gas_price }) =>
+                TransactionFees::Legacy { gas_price: increase(*gas_price).max(*market_gas_price) },
+            // The transaction type of a replacement must not change, so ignore the market fees
+            (TransactionFees::Eip1559(fees), _) => TransactionFees::Eip1559(Eip1559Fees {
+                max_fee_per_gas: increase(fees.max_fee_per_gas),
+                max_priority_fee_per_gas: increase(fees.max_priority_fee_per_gas),
+            }),
+            (TransactionFees::Legacy { gas_price }, _) => TransactionFees::Legacy { gas_price: increase(*gas_price) },
+        }
+    }
+
+    /// The most a transaction using `gas` can cost with these fees
+    pub fn max_cost(&self, gas: U256) -> U256 {
+        let fee_per_gas = match self {
+            TransactionFees::Legacy { gas_price } => *gas_price,
+            TransactionFees::Eip1559(fees) => fees.max_fee_per_gas,
+        };
+
+        return gas.saturating_mul(fee_per_gas);
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        return matches!(self, TransactionFees::Legacy { .. });
+    }
+
+    fn gas_price_for_estimate(&self) -> Option<U256> {
+        match self {
+            TransactionFees::Legacy { gas_price } => Some(*gas_price),
+            TransactionFees::Eip1559(_) => None,
+        }
+    }
+}
+
+pub async fn get_transaction_fees(web3: &Web3<EthTransport>, use_legacy_transaction: bool) -> Result<TransactionFees, TideError> {
+    if use_legacy_transaction {
+        let gas_price = web3.eth()
+            .gas_price()
+            .await
+            .map_err(|_| server_error("Error getting gas price".to_string()))?;
+
+        return Ok(TransactionFees::Legacy { gas_price });
+    }
+
+    return Ok(TransactionFees::Eip1559(get_eip1559_fees(web3).await?));
+}
+
+pub async fn estimate_gas_for_transaction(
+    web3: &Web3<EthTransport>,
     send_request: &EthTransaction,
-    sender_eth_address: &Vec<u8>) -> Result<RawTransaction, TideError>
+    sender_eth_address: &Vec<u8>,
+    fees: &TransactionFees) -> Result<U256, TideError>
 {
-    let recipient = send_request.to.as_bytes();
-    let gas_price = web3.eth()
-        .gas_price()
-        .await
-        .map_err(|_| server_error("Error getting gas price".to_string()))?;
-
-    let nonce = get_nonce(keystore)?;
-    let maybe_gas_estimate = estimate_gas(web3, gas_price, sender_eth_address, recipient, &send_request.data).await;
+    let maybe_gas_estimate = estimate_gas(
+        web3,
+        fees.gas_price_for_estimate(),
+        sender_eth_address,
+        send_request.to.as_bytes(),
+        &send_request.data
+    ).await;
 
     if let Err(ref gas_estimate_error) = maybe_gas_estimate {
         log::error!("💔 Error estimating gas (this may be due to the transaction failing on Ethereum) {:?}", gas_estimate_error);
-        return Err(server_error("Error estimating gas".to_string()));
+        return Err(service_error(ExternalServiceErrorCode::GasEstimationFailed, "Error estimating gas".to_string()));
     }
 
-    let gas_estimate = maybe_gas_estimate.expect("Checked for errors");
+    return Ok(maybe_gas_estimate.expect("Checked for errors"));
+}
 
-    Ok(
-        RawTransaction {
-            nonce: to_eth_u256(nonce.into()),
-            to: Some(ethereum_types::H160::from_slice(recipient)),
+/// Note: this is called by the signer which has different ethereum types to web3
+pub fn build_transaction(
+    send_request: &EthTransaction,
+    nonce: u64,
+    gas: U256,
+    fees: &TransactionFees,
+    chain_id: u64) -> UnsignedTransaction
+{
+    let recipient = Some(ethereum_types::H160::from_slice(send_request.to.as_bytes()));
+
+    match fees {
+        TransactionFees::Legacy { gas_price } => UnsignedTransaction::Legacy(LegacyTransaction {
+            chain_id,
+            nonce: nonce.into(),
+            gas_price: to_eth_u256(*gas_price),
+            gas: to_eth_u256(gas),
+            to: recipient,
             value: ethereum_types::U256::zero(),
-            gas_price: to_eth_u256(gas_price),
-            gas: to_eth_u256(gas_estimate),
             data: send_request.data.clone()
-        }
-    )
+        }),
+        TransactionFees::Eip1559(fees) => UnsignedTransaction::Eip1559(Eip1559Transaction {
+            chain_id,
+            nonce: nonce.into(),
+            max_priority_fee_per_gas: to_eth_u256(fees.max_priority_fee_per_gas),
+            max_fee_per_gas: to_eth_u256(fees.max_fee_per_gas),
+            gas: to_eth_u256(gas),
+            to: recipient,
+            value: ethereum_types::U256::zero(),
+            data: send_request.data.clone()
+        }),
+    }
+}
+
+/// Works out the fees of a type-2 transaction using `eth_feeHistory`:
+///  - max priority fee: the average of the `PRIORITY_FEE_REWARD_PERCENTILE` rewards of the last `FEE_HISTORY_BLOCK_COUNT` blocks
+///  - max fee: `BASE_FEE_MULTIPLIER` times the base fee of the next block plus the max priority fee
+pub async fn get_eip1559_fees(web3: &Web3<EthTransport>) -> Result<Eip1559Fees, TideError> {
+    let params = vec![
+        serde_json::json!(format!("0x{:x}", FEE_HISTORY_BLOCK_COUNT)),
+        serde_json::json!("latest"),
+        serde_json::json!([PRIORITY_FEE_REWARD_PERCENTILE]),
+    ];
+
+    let response = web3.transport()
+        .execute("eth_feeHistory", params)
+        .await
+        .map_err(|e| server_error(format!("Error getting fee history: {:?}", e)))?;
+
+    let fee_history: FeeHistory = serde_json::from_value(response)
+        .map_err(|e| server_error(format!("Error decoding fee history: {:?}", e)))?;
+
+    return calculate_eip1559_fees(fee_history);
 }
 
-pub async fn get_chain_id(web3: &Web3<Http>) -> Result<u64, TideError> {
+pub(crate) fn calculate_eip1559_fees(fee_history: FeeHistory) -> Result<Eip1559Fees, TideError> {
+    // The last entry is the base fee of the next block
+    let next_base_fee = fee_history.base_fee_per_gas.last()
+        .ok_or_else(|| server_error("Fee history does not contain a base fee".to_string()))?;
+
+    let rewards: Vec<U256> = fee_history.reward.iter().filter_map(|block_rewards| block_rewards.first().cloned()).collect();
+    let max_priority_fee_per_gas = match rewards.len() {
+        0 => U256::zero(),
+        n => rewards.iter().fold(U256::zero(), |total, reward| total.saturating_add(*reward)) / U256::from(n)
+    };
+
+    let max_fee_per_gas = next_base_fee
+        .saturating_mul(U256::from(BASE_FEE_MULTIPLIER))
+        .saturating_add(max_priority_fee_per_gas);
+
+    Ok(Eip1559Fees { max_fee_per_gas, max_priority_fee_per_gas })
+}
+
+pub async fn get_chain_id(web3: &Web3<EthTransport>) -> Result<u64, TideError> {
     Ok(
         web3
             .eth()
@@ -76,13 +282,13 @@ pub async fn get_chain_id(web3: &Web3<Http>) -> Result<u64, TideError> {
     )
 }
 
-async fn estimate_gas(web3: &Web3<Http>, gas_price: U256, sender: &Vec<u8>, recipient: &[u8], data: &Vec<u8>) -> Result<U256, TideError>
+async fn estimate_gas(web3: &Web3<EthTransport>, gas_price: Option<U256>, sender: &Vec<u8>, recipient: &[u8], data: &Vec<u8>) -> Result<U256, TideError>
 {
     let call_request = CallRequest {
         from: Some(H160::from_slice(&sender)),
         to: Some(H160::from_slice(recipient)),
         gas: None,
-        gas_price: Some(gas_price),
+        gas_price,
         value: Some(U256::zero()),
         data: Some(Bytes(data.to_vec())),
     };
@@ -95,7 +301,7 @@ async fn estimate_gas(web3: &Web3<Http>, gas_price: U256, sender: &Vec<u8>, reci
     )
 }
 
-pub async fn get_current_block_number(web3: &Web3<Http>) -> Result<u64, TideError>
+pub async fn get_current_block_number(web3: &Web3<EthTransport>) -> Result<u64, TideError>
 {
     Ok(
         web3.eth()
@@ -106,7 +312,7 @@ pub async fn get_current_block_number(web3: &Web3<Http>) -> Result<u64, TideErro
     )
 }
 
-pub async fn get_tx_receipt(web3: &Web3<Http>, tx_hash: ethereum_types::H256) -> Result<Option<TransactionReceipt>, TideError>
+pub async fn get_tx_receipt(web3: &Web3<EthTransport>, tx_hash: ethereum_types::H256) -> Result<Option<TransactionReceipt>, TideError>
 {
     Ok(
         web3.eth()
@@ -116,12 +322,59 @@ pub async fn get_tx_receipt(web3: &Web3<Http>, tx_hash: ethereum_types::H256) ->
     )
 }
 
-pub async fn send_raw_transaction(web3: &Web3<Http>, tx: Bytes) -> Result<web3::types::H256, TideError> {
+/// Whether the call at `call_index` of a batch sent to the multicall contract succeeded. The batch does not revert when
+/// one of its calls fails, so the result of each call is read from the `LogCallResult` event the contract emitted for it.
+pub fn batch_call_succeeded(receipt: &TransactionReceipt, call_index: u64) -> Result<bool, TideError> {
+    let result_topic = web3::types::H256(keccak_256(BATCH_CALL_RESULT_EVENT_SIGNATURE));
+    // Only trust the events of the multicall contract itself, not the ones of the contracts it called
+    let call_result = receipt.logs.iter()
+        .filter(|log| receipt.to == Some(log.address) && log.topics.first() == Some(&result_topic) && log.data.0.len() == 64)
+        .find(|log| U256::from_big_endian(&log.data.0[0..32]) == U256::from(call_index));
+
+    return match call_result {
+        Some(log) => Ok(U256::from_big_endian(&log.data.0[32..64]) == U256::one()),
+        None => Err(service_error(
+            ExternalServiceErrorCode::ReceiptNotFound,
+            format!("The receipt does not have the result of call {} of the batch", call_index)
+        )),
+    };
+}
+
+/// Returns the logs emitted by `addresses`, with one of `signatures` as topic0, in the blocks `[from_block, to_block]`
+pub async fn get_logs(
+    web3: &Web3<EthTransport>,
+    addresses: Vec<H160>,
+    signatures: Vec<web3::types::H256>,
+    from_block: u64,
+    to_block: u64) -> Result<Vec<Log>, TideError>
+{
+    let filter = FilterBuilder::default()
+        .address(addresses)
+        .topics(Some(signatures), None, None, None)
+        .from_block(BlockNumber::Number(U64::from(from_block)))
+        .to_block(BlockNumber::Number(U64::from(to_block)))
+        .build();
+
+    Ok(
+        web3.eth()
+            .logs(filter)
+            .await
+            .map_err(|e| service_error(ExternalServiceErrorCode::EthereumNodeUnavailable, format!("Error getting logs: {:?}", e)))?
+    )
+}
+
+pub async fn send_raw_transaction(web3: &Web3<EthTransport>, tx: Bytes) -> Result<web3::types::H256, TideError> {
     Ok(
         web3.eth()
             .send_raw_transaction(tx)
             .await
-            .map_err(|_| server_error("Error sending raw transaction".to_string()))?
+            .map_err(|e| {
+                let message = format!("Error sending raw transaction: {:?}", e);
+                match is_nonce_error_message(&message) {
+                    true => service_error(ExternalServiceErrorCode::NonceTooLow, message),
+                    false => server_error(message),
+                }
+            })?
     )
 }
 
@@ -28,6 +28,9 @@ pub struct Cli {
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub run: RunCmd,
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub avn: avn_service::cli::AvnCliConfiguration,
 }
 
 /// Possible subcommands of the main binary.
@@ -20,7 +20,7 @@
 
 //! Service implementation. Specialized wrapper over substrate service.
 
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 use sc_consensus_babe;
 use node_primitives::Block;
 use node_runtime::RuntimeApi;
@@ -32,11 +32,12 @@ use sp_inherents::InherentDataProviders;
 use sc_network::{Event, NetworkService};
 use sp_runtime::traits::Block as BlockT;
 use futures::prelude::*;
-use sc_client_api::{ExecutorProvider, RemoteBackend, Backend};
-use sp_core::{offchain::OffchainStorage, Encode};
+use sc_client_api::{BlockchainEvents, ExecutorProvider, RemoteBackend, Backend, StorageProvider, UsageProvider};
+use sp_core::{offchain::OffchainStorage, storage::StorageKey, twox_128, Decode, Encode, H160};
+use sp_runtime::generic::BlockId;
 use node_executor::Executor;
 use avn_service;
-use sp_avn_common::{DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER, EXTERNAL_SERVICE_PORT_NUMBER_KEY};
+use sp_avn_common::{DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER, EXTERNAL_SERVICE_PORT_NUMBER_KEY, EXTERNAL_SERVICE_AUTH_SECRET_KEY};
 use sc_telemetry::TelemetryConnectionNotifier;
 
 type FullClient = sc_service::TFullClient<Block, RuntimeApi, Executor>;
@@ -46,6 +47,30 @@ type FullGrandpaBlockImport =
 	grandpa::GrandpaBlockImport<FullBackend, Block, FullClient, FullSelectChain>;
 type LightClient = sc_service::TLightClient<Block, RuntimeApi, Executor>;
 
+/// Reads an `H160` stored in the plain storage item `item` of `pallet` at `block`
+fn read_contract_address(client: &FullClient, block: &BlockId<Block>, pallet: &[u8], item: &[u8]) -> Option<H160> {
+	let key = [twox_128(pallet), twox_128(item)].concat();
+
+	return client.storage(block, &StorageKey(key))
+		.ok()
+		.flatten()
+		.and_then(|data| H160::decode(&mut &data.0[..]).ok());
+}
+
+/// The T1 contracts the runtime sends transactions to, as of the best block
+fn get_t1_contracts(client: &FullClient) -> Vec<H160> {
+	let best_block = BlockId::Hash(client.usage_info().chain.best_hash);
+
+	return vec![
+		read_contract_address(client, &best_block, b"EthereumTransactions", b"PublishRootContract"),
+		read_contract_address(client, &best_block, b"EthereumEvents", b"ValidatorManagerContractAddress"),
+	]
+	.into_iter()
+	.flatten()
+	.filter(|contract| !contract.is_zero())
+	.collect();
+}
+
 pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponents<
 	FullClient, FullBackend, FullSelectChain,
 	sp_consensus::DefaultImportQueue<Block, FullClient>,
@@ -178,6 +203,7 @@ pub struct NewFullBase {
 /// Creates a full service from the configuration.
 pub fn new_full_base(
 	mut config: Configuration,
+	avn_cli_config: avn_service::cli::AvnCliConfiguration,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -215,10 +241,14 @@ pub fn new_full_base(
 			block_announce_validator_builder: None,
 		})?;
 
+	// A new secret every time the node starts. Only the offchain workers and avn-service know it.
+	let avn_auth_secret = format!("{}", sp_core::hexdisplay::HexDisplay::from(&rand::random::<[u8; 32]>()));
+
 	if config.offchain_worker.enabled {
 		let port_number = config.avn_port.clone().unwrap_or_else(|| DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER.to_string());
 		if let Some(mut local_db) = backend.offchain_storage() {
 			local_db.set(sp_core::offchain::STORAGE_PREFIX, EXTERNAL_SERVICE_PORT_NUMBER_KEY, &port_number.encode());
+			local_db.set(sp_core::offchain::STORAGE_PREFIX, EXTERNAL_SERVICE_AUTH_SECRET_KEY, &avn_auth_secret.encode());
 		}
 
 		sc_service::build_offchain_workers(
@@ -234,7 +264,8 @@ pub fn new_full_base(
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let avn_port = config.avn_port.clone();
-	let eth_node_url = config.ethereum_node_url.clone().unwrap_or_default();
+	let mut eth_node_urls: Vec<String> = config.ethereum_node_url.clone().into_iter().collect();
+	eth_node_urls.extend(avn_cli_config.eth_fallback_node_urls.clone());
 
 	let (_rpc_handlers, telemetry_connection_notifier) = sc_service::spawn_tasks(
 		sc_service::SpawnTasksParams {
@@ -317,11 +348,56 @@ pub fn new_full_base(
 
 	if role.is_authority() {
 		// TODO [TYPE: business logic][PRI: high][JIRA: 347]: If OCW is enabled, we should error if `ethereum_node_url` is set to None
+		let avn_metrics = prometheus_registry.as_ref()
+			.map(avn_service::metrics::Metrics::register)
+			.transpose()
+			.map_err(|e| ServiceError::Other(format!("Failed to register avn-service metrics: {:?}", e)))?;
+
+		if let (Some(metrics), Some(offchain_storage)) = (avn_metrics.clone(), backend.offchain_storage()) {
+			task_manager.spawn_handle().spawn(
+				"avn-ocw-metrics",
+				avn_service::metrics::export_ocw_metrics(metrics, offchain_storage),
+			);
+		}
+
+		// The T1 contracts can be changed on chain, so they are read again every time the best block changes
+		let t1_contracts = Arc::new(RwLock::new(get_t1_contracts(&client)));
+		let block_imports = client.import_notification_stream();
+		task_manager.spawn_handle().spawn("avn-t1-contracts", {
+			let client = client.clone();
+			let t1_contracts = t1_contracts.clone();
+			block_imports
+				.filter(|notification| future::ready(notification.is_new_best))
+				.for_each(move |_| {
+					match t1_contracts.write() {
+						Ok(mut contracts) => *contracts = get_t1_contracts(&client),
+						Err(e) => log::error!("💔 Error updating the T1 contracts: {:?}", e),
+					}
+					future::ready(())
+				})
+		});
+
+		let local_keystore = keystore_container.local_keystore().unwrap();
 		let avn_config = super::avn_service::Config::<Block, _> {
-			keystore: keystore_container.local_keystore().unwrap(),
+			signer: avn_service::signer::create_signer(local_keystore.clone(), avn_cli_config.eth_remote_signer_url.clone()),
+			keystore: local_keystore,
 			avn_port,
-			eth_node_url,
-			web3_mutex: Arc::new(Mutex::new(None)),
+			auth_secret: avn_auth_secret,
+			send_policy: avn_service::auth::SendPolicy {
+				t1_contracts,
+				allowed_contracts: avn_cli_config.eth_send_allowed_contracts.clone(),
+				allowed_function_selectors: avn_cli_config.eth_send_allowed_function_selectors.clone(),
+			},
+			eth_node_urls,
+			eth_events_quorum: avn_cli_config.eth_events_quorum.unwrap_or(1),
+			eth_legacy_transactions: avn_cli_config.eth_legacy_transactions,
+			eth_stuck_tx_blocks: avn_cli_config.eth_stuck_tx_blocks
+				.unwrap_or(avn_service::tx_monitor::DEFAULT_STUCK_TRANSACTION_BLOCKS),
+			eth_wallet_reserve: avn_cli_config.eth_wallet_reserve.unwrap_or(0).into(),
+			web3_pool: Arc::new(Mutex::new(Default::default())),
+			tx_monitor: Default::default(),
+			nonce_manager: Default::default(),
+			metrics: avn_metrics,
 			client: client.clone(),
 			_block: Default::default()
 		};
@@ -376,9 +452,9 @@ pub fn new_full_base(
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration)
+pub fn new_full(config: Configuration, avn_cli_config: avn_service::cli::AvnCliConfiguration)
 -> Result<TaskManager, ServiceError> {
-	new_full_base(config, |_, _| ()).map(|NewFullBase { task_manager, .. }| {
+	new_full_base(config, avn_cli_config, |_, _| ()).map(|NewFullBase { task_manager, .. }| {
 		task_manager
 	})
 }
@@ -549,7 +625,7 @@ mod tests {
 				let mut setup_handles = None;
 				let NewFullBase {
 					task_manager, inherent_data_providers, client, network, transaction_pool, ..
-				} = new_full_base(config,
+				} = new_full_base(config, Default::default(),
 					|
 						block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						babe_link: &sc_consensus_babe::BabeLink<Block>,
@@ -718,7 +794,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, transaction_pool, .. }
-					= new_full_base(config,|_, _| ())?;
+					= new_full_base(config, Default::default(), |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(task_manager, client, network, transaction_pool))
 			},
 			|config| {
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use codec::Codec;
+use jsonrpc_derive::rpc;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_avn_common::event_types::{EthEventId, EthEventStatusInfo, ValidEvents};
+use pallet_ethereum_events::runtime_api::EthereumEventsApi;
+use serde::{Serialize, Deserialize};
+
+const FIRST_LOG_INDEX: u64 = 0;
+
+/// Error type of this RPC api.
+pub enum Error {
+    RuntimeError,
+}
+
+impl From<Error> for i64 {
+    fn from(e: Error) -> i64 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// The type of an ethereum event: one of the `ValidEvents` (eg: `"Lifted"`), or the signature of any event (eg: an event
+/// registered with a descriptor), as in ethereum's Topic0
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum EthEventType {
+    Valid(ValidEvents),
+    Signature(H256),
+}
+
+impl EthEventType {
+    pub fn signature(&self) -> H256 {
+        match self {
+            EthEventType::Valid(event_type) => event_type.signature(),
+            EthEventType::Signature(signature) => *signature,
+        }
+    }
+}
+
+#[rpc]
+pub trait EthEventsRpc<BlockHash, AccountId, BlockNumber> {
+    /// Where an event of `event_type` emitted by the ethereum transaction `tx_hash` is on its way to being processed.
+    /// `log_index` picks one of the events of this type the transaction emitted, and defaults to the first one.
+    #[rpc(name = "ethEvents_status")]
+    fn status(
+        &self,
+        event_type: EthEventType,
+        tx_hash: H256,
+        log_index: Option<u64>,
+        at: Option<BlockHash>) -> Result<EthEventStatusInfo<AccountId, BlockNumber>>;
+}
+
+pub struct EthEventsStatusProvider<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl <C, Block> EthEventsStatusProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl <C, Block, AccountId, BlockNumber> EthEventsRpc<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for EthEventsStatusProvider<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EthereumEventsApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec,
+    BlockNumber: Codec,
+{
+    fn status(
+        &self,
+        event_type: EthEventType,
+        tx_hash: H256,
+        log_index: Option<u64>,
+        at: Option<<Block as BlockT>::Hash>) -> Result<EthEventStatusInfo<AccountId, BlockNumber>>
+    {
+        let api = self.client.runtime_api();
+        // If the block hash is not supplied assume the best block
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let event_id = EthEventId {
+            signature: event_type.signature(),
+            transaction_hash: tx_hash,
+            log_index: log_index.unwrap_or(FIRST_LOG_INDEX),
+        };
+
+        return api.event_status(&at, event_id).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(Error::RuntimeError.into()),
+            message: "Unable to query the status of the ethereum event".into(),
+            data: Some(format!("{:?}", e).into()),
+        });
+    }
+}
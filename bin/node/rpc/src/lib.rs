@@ -54,6 +54,8 @@ use sc_client_api::{UsageProvider, client::BlockBackend};
 
 mod lower_rpc;
 
+mod eth_events_rpc;
+
 /// Utility methods for extracting and filtering specific extrinsics
 pub mod extrinsic_utils;
 
@@ -128,6 +130,7 @@ pub fn create_full<C, P, SC, B>(
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_ethereum_events::runtime_api::EthereumEventsApi<Block, AccountId, BlockNumber>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
@@ -203,6 +206,10 @@ pub fn create_full<C, P, SC, B>(
 		lower_rpc::LowerDataProvider::new(client.clone()),
 	));
 
+	io.extend_with(eth_events_rpc::EthEventsRpc::to_delegate(
+		eth_events_rpc::EthEventsStatusProvider::new(client.clone()),
+	));
+
 	io.extend_with(
 		sc_sync_state_rpc::SyncStateRpcApi::to_delegate(
 			sc_sync_state_rpc::SyncStateRpcHandler::new(
@@ -999,6 +999,11 @@ impl pallet_nft_manager::Config for Runtime {
 
 parameter_types! {
 	pub const MinEthBlockConfirmation: u64 = 20;
+	pub const MaxEventsCheckedPerBlock: u32 = 10;
+	pub const MaxEventCheckAttempts: u32 = 5;
+	pub const EventCheckRetryDelay: BlockNumber = 10;
+	// About a year of ethereum blocks
+	pub const ProcessedEventsEthBlockHorizon: u64 = 2_400_000;
 }
 
 impl pallet_ethereum_events::Config for Runtime {
@@ -1006,6 +1011,10 @@ impl pallet_ethereum_events::Config for Runtime {
     type Event = Event;
     type ProcessedEventHandler = (TokenManager,  NftManager);
     type MinEthBlockConfirmation = MinEthBlockConfirmation;
+    type MaxEventsCheckedPerBlock = MaxEventsCheckedPerBlock;
+    type MaxEventCheckAttempts = MaxEventCheckAttempts;
+    type EventCheckRetryDelay = EventCheckRetryDelay;
+    type ProcessedEventsEthBlockHorizon = ProcessedEventsEthBlockHorizon;
     type Public = <Signature as traits::Verify>::Signer;
     type Signature = Signature;
     type ReportInvalidEthereumLog = Offences;
@@ -1014,6 +1023,19 @@ impl pallet_ethereum_events::Config for Runtime {
 
 parameter_types! {
 	pub ValidatorManagerContractAddress: H160 = pallet_ethereum_events::ValidatorManagerContractAddress::get();
+	// An hour of AvN blocks
+	pub const EthTransactionDropDelay: BlockNumber = 1_200;
+	// Half an hour of AvN blocks
+	pub const DispatchedTransactionTimeout: BlockNumber = 600;
+	// No multicall contract is deployed yet, so transactions are sent one at a time. The targets of a batch see the
+	// multicall contract as `msg.sender`: only set it once it appends the original sender to each call (as in EIP-2771)
+	// and every Tier1 contract trusts it as a forwarder. It is not one of the contracts the avn-service sends to by
+	// default, so validators must also allow it, along with the Tier1 contracts, with `--eth-send-allowed-contract`.
+	pub MulticallContractAddress: H160 = H160::zero();
+	pub const MaxTransactionsPerBatch: u32 = 5;
+	// A week of AvN blocks
+	pub const TransactionRetentionPeriod: BlockNumber = 201_600;
+	pub const MaxTransactionsPrunedPerBlock: u32 = 20;
 }
 
 impl pallet_ethereum_transactions::Config for Runtime {
@@ -1021,6 +1043,14 @@ impl pallet_ethereum_transactions::Config for Runtime {
 	type Call = Call;
 	type AccountToBytesConvert = Avn;
 	type ValidatorManagerContractAddress = ValidatorManagerContractAddress;
+	type MinEthBlockConfirmation = MinEthBlockConfirmation;
+	type EthTransactionDropDelay = EthTransactionDropDelay;
+	type DispatchedTransactionTimeout = DispatchedTransactionTimeout;
+	type ReportTransactionOffence = Offences;
+	type MulticallContractAddress = MulticallContractAddress;
+	type MaxTransactionsPerBatch = MaxTransactionsPerBatch;
+	type TransactionRetentionPeriod = TransactionRetentionPeriod;
+	type MaxTransactionsPrunedPerBlock = MaxTransactionsPrunedPerBlock;
 	type WeightInfo = pallet_ethereum_transactions::default_weights::SubstrateWeight<Runtime>;
 }
 
@@ -1521,6 +1551,14 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ethereum_events::runtime_api::EthereumEventsApi<Block, AccountId, BlockNumber> for Runtime {
+		fn event_status(
+			event_id: sp_avn_common::event_types::EthEventId,
+		) -> sp_avn_common::event_types::EthEventStatusInfo<AccountId, BlockNumber> {
+			EthereumEvents::get_event_status(&event_id)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
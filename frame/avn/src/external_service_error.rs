@@ -0,0 +1,38 @@
+//! Decodes the json error envelope returned by the external service.
+//! See `sp_avn_common::external_service_errors` for the format.
+use sp_std::prelude::*;
+use simple_json2::{self as json, json::{JsonValue, JsonObject}};
+use sp_avn_common::external_service_errors::ExternalServiceErrorCode;
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExternalServiceError {
+    pub version: u32,
+    pub code: ExternalServiceErrorCode,
+    pub message: String,
+}
+
+fn get_value_of<'a>(key: &str, object: &'a JsonObject) -> Option<&'a JsonValue> {
+    let key_chars: Vec<char> = key.chars().collect();
+    return object.iter().find(|(k, _)| *k == key_chars).map(|(_, value)| value);
+}
+
+/// Returns None if the body is not an error envelope or the error code is unknown
+pub fn decode_external_service_error(response_body: &[u8]) -> Option<ExternalServiceError> {
+    let body = core::str::from_utf8(response_body).ok()?;
+    let envelope = json::parse_json(body).ok()?;
+    let envelope = envelope.get_object().ok()?;
+
+    let version = get_value_of("version", envelope)?.get_number_f64().ok()? as u32;
+    let error = get_value_of("error", envelope)?.get_object().ok()?;
+    let code = ExternalServiceErrorCode::from_str(&get_value_of("code", error)?.get_string().ok()?)?;
+    let message = get_value_of("message", error)
+        .and_then(|message| message.get_string().ok())
+        .unwrap_or_default();
+
+    return Some(ExternalServiceError { version, code, message });
+}
@@ -30,7 +30,9 @@ use sp_application_crypto::RuntimeAppPublic;
 use sp_avn_common::{
     event_types::Validator,
     offchain_worker_storage_lock:: {self as OcwLock, OcwStorageError},
-    recover_public_key_from_ecdsa_signature, EXTERNAL_SERVICE_PORT_NUMBER_KEY, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER
+    recover_public_key_from_ecdsa_signature, EXTERNAL_SERVICE_PORT_NUMBER_KEY, DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER,
+    EXTERNAL_SERVICE_AUTH_SECRET_KEY, EXTERNAL_SERVICE_AUTH_HEADER, EthWalletStatus,
+    external_service_errors::ExternalServiceErrorCode,
 };
 
 use core::convert::TryInto;
@@ -42,6 +44,9 @@ pub mod testing;
 
 pub mod weights;
 
+pub mod external_service_error;
+pub use external_service_error::{ExternalServiceError, decode_external_service_error};
+
 // Definition of the crypto to use for signing
 pub mod sr25519 {
 	mod app_sr25519 {
@@ -86,6 +91,19 @@ decl_error! {
         InvalidVote,
         ErrorRecoveringPublicKeyFromSignature,
         InvalidECDSASignature,
+        ExternalServiceInvalidRequest,
+        ExternalServiceUnauthorised,
+        ExternalServiceForbidden,
+        EthereumNodeUnavailable,
+        EthereumQuorumNotReached,
+        EthereumReceiptNotFound,
+        EthereumGasEstimationFailed,
+        EthereumNonceTooLow,
+        ExternalServiceSigningFailed,
+        ExternalServiceInternalError,
+        EthereumInsufficientFunds,
+        ErrorDecodingEthereumWalletStatus,
+        IoError,
  	}
 }
 
@@ -201,6 +219,17 @@ impl<T: Config> Module<T> {
         Ok(ecdsa::Signature::from_raw(data))
     }
 
+    /// Returns the balance of this node's Ethereum wallet and the reserve it must keep
+    pub fn get_ethereum_wallet_status() -> Result<EthWalletStatus, DispatchError> {
+        let response = Self::get_data_from_service(String::from("eth/wallet"))?;
+
+        let wallet_status_bytes = hex::decode(&response).map_err(|_| Error::<T>::ErrorDecodingHex)?;
+        let wallet_status = EthWalletStatus::decode(&mut &wallet_status_bytes[..])
+            .map_err(|_| Error::<T>::ErrorDecodingEthereumWalletStatus)?;
+
+        Ok(wallet_status)
+    }
+
     pub fn signature_is_valid<D: Encode>(
         data: &D,
         validator: &Validator<T::AuthorityId, T::AccountId>,
@@ -304,17 +333,36 @@ impl<T: Config> Module<T> {
         return port_number.into();
     }
 
+    pub fn get_external_service_auth_secret() -> Option<String> {
+        let stored_value: Option<Option<Vec<u8>>> = StorageValueRef::persistent(EXTERNAL_SERVICE_AUTH_SECRET_KEY).get();
+        return match stored_value {
+            Some(Some(secret_bytes)) => String::from_utf8(secret_bytes)
+                .map_err(|e| debug::native::error!("❌ External service secret is not valid utf8: {:?}", e))
+                .ok(),
+            _ => None
+        };
+    }
+
+    /// Adds the secret expected by the external service to the request, if the node has set one
+    pub fn with_external_service_auth<'a, B>(request: http::Request<'a, B>, auth_secret: &'a Option<String>) -> http::Request<'a, B> {
+        return match auth_secret {
+            Some(secret) => request.add_header(EXTERNAL_SERVICE_AUTH_HEADER, secret),
+            None => request
+        };
+    }
+
     fn invoke_external_service(request: http::Request<Vec<Vec<u8>>>, url_path: String) -> Result<Vec<u8>, DispatchError> {
         // TODO: Make this configurable
         let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(300_000));
         let external_service_port_number = Self::get_external_service_port_number();
+        let auth_secret = Self::get_external_service_auth_secret();
 
         let mut url = String::from("http://127.0.0.1:");
         url.push_str(&external_service_port_number);
         url.push_str(&"/".to_string());
         url.push_str(&url_path);
 
-        let pending = request
+        let pending = Self::with_external_service_auth(request, &auth_secret)
             .deadline(deadline)
             .url(&url)
             .send()
@@ -324,14 +372,44 @@ impl<T: Config> Module<T> {
             .map_err(|_| Error::<T>::DeadlineReached)?
             .map_err(|_| Error::<T>::DeadlineReached)?;
 
+        let result: Vec<u8> = response.body().collect::<Vec<u8>>();
         if response.code != 200 {
-            debug::native::error!("❌ Unexpected status code: {}", response.code);
-            return Err(Error::<T>::UnexpectedStatusCode)?;
+            return Err(Self::get_external_service_error(response.code, &result))?;
         }
 
-        let result: Vec<u8> = response.body().collect::<Vec<u8>>();
         return Ok(result);
     }
+
+    /// Turns a failed response from the external service into the error matching its error code,
+    /// so callers can tell failures worth retrying apart from permanent ones.
+    pub fn get_external_service_error(status_code: u16, response_body: &[u8]) -> Error<T> {
+        return match decode_external_service_error(response_body) {
+            Some(error) => {
+                debug::native::error!("❌ External service error {} ({}): {}", error.code.as_str(), status_code, error.message);
+                Self::error_for_code(error.code)
+            },
+            None => {
+                debug::native::error!("❌ Unexpected status code: {}", status_code);
+                Error::<T>::UnexpectedStatusCode
+            }
+        };
+    }
+
+    pub fn error_for_code(code: ExternalServiceErrorCode) -> Error<T> {
+        return match code {
+            ExternalServiceErrorCode::InvalidRequest => Error::<T>::ExternalServiceInvalidRequest,
+            ExternalServiceErrorCode::Unauthorised => Error::<T>::ExternalServiceUnauthorised,
+            ExternalServiceErrorCode::Forbidden => Error::<T>::ExternalServiceForbidden,
+            ExternalServiceErrorCode::EthereumNodeUnavailable => Error::<T>::EthereumNodeUnavailable,
+            ExternalServiceErrorCode::QuorumNotReached => Error::<T>::EthereumQuorumNotReached,
+            ExternalServiceErrorCode::ReceiptNotFound => Error::<T>::EthereumReceiptNotFound,
+            ExternalServiceErrorCode::GasEstimationFailed => Error::<T>::EthereumGasEstimationFailed,
+            ExternalServiceErrorCode::NonceTooLow => Error::<T>::EthereumNonceTooLow,
+            ExternalServiceErrorCode::InsufficientFunds => Error::<T>::EthereumInsufficientFunds,
+            ExternalServiceErrorCode::SigningFailed => Error::<T>::ExternalServiceSigningFailed,
+            ExternalServiceErrorCode::Internal => Error::<T>::ExternalServiceInternalError,
+        };
+    }
 }
 
 // Session pallet interface
@@ -486,3 +564,7 @@ mod tests;
 #[cfg(test)]
 #[path = "tests/session_handler_tests.rs"]
 mod session_handler_tests;
+
+#[cfg(test)]
+#[path = "tests/external_service_error_tests.rs"]
+mod external_service_error_tests;
@@ -0,0 +1,59 @@
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+
+const RECEIPT_NOT_FOUND_ENVELOPE: &[u8] =
+    br#"{"version":1,"error":{"code":"RECEIPT_NOT_FOUND","message":"Transaction receipt is empty"}}"#;
+
+mod decoding_the_error_envelope {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_a_valid_envelope() {
+        let error = decode_external_service_error(RECEIPT_NOT_FOUND_ENVELOPE).unwrap();
+
+        assert_eq!(error.version, 1);
+        assert_eq!(error.code, ExternalServiceErrorCode::ReceiptNotFound);
+        assert_eq!(error.message, "Transaction receipt is empty".to_string());
+    }
+
+    #[test]
+    fn succeeds_without_a_message() {
+        let error = decode_external_service_error(br#"{"version":1,"error":{"code":"NONCE_TOO_LOW"}}"#).unwrap();
+
+        assert_eq!(error.code, ExternalServiceErrorCode::NonceTooLow);
+        assert_eq!(error.message, String::new());
+    }
+
+    #[test]
+    fn fails_with_an_unknown_code() {
+        assert_eq!(decode_external_service_error(br#"{"version":1,"error":{"code":"NOT_A_CODE","message":""}}"#), None);
+    }
+
+    #[test]
+    fn fails_without_a_version() {
+        assert_eq!(decode_external_service_error(br#"{"error":{"code":"INTERNAL","message":""}}"#), None);
+    }
+
+    #[test]
+    fn fails_with_a_plain_text_body() {
+        assert_eq!(decode_external_service_error(b"Internal server error"), None);
+    }
+}
+
+mod getting_the_external_service_error {
+    use super::*;
+
+    #[test]
+    fn returns_the_error_matching_the_code() {
+        let error: DispatchError = AVN::get_external_service_error(404, RECEIPT_NOT_FOUND_ENVELOPE).into();
+        assert_eq!(error, Error::<TestRuntime>::EthereumReceiptNotFound.into());
+    }
+
+    #[test]
+    fn returns_unexpected_status_code_without_an_envelope() {
+        let error: DispatchError = AVN::get_external_service_error(500, b"Internal server error").into();
+        assert_eq!(error, Error::<TestRuntime>::UnexpectedStatusCode.into());
+    }
+}
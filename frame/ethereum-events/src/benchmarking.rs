@@ -12,16 +12,20 @@ use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use pallet_avn::{self as avn};
 use sp_core::sr25519;
+use sp_avn_common::event_types::EthAbiParamType;
 
 pub type AVN<T> = avn::Module::<T>;
 
+const MAX_NUMBER_OF_DISCOVERED_LOGS: u32 = 20;
+
 fn setup_unchecked_events<T: Config>(event_type: &ValidEvents, number_of_unchecked_events: u32) {
     let mut unchecked_added_validator_events: Vec<(EthEventId, IngressCounter, T::BlockNumber)> = Vec::new();
     for i in 1 ..= number_of_unchecked_events {
         unchecked_added_validator_events.push((
             EthEventId {
                 signature: event_type.signature(),
-                transaction_hash: H256::from([2; 32])
+                transaction_hash: H256::from([2; 32]),
+                log_index: 0,
             },
             i as IngressCounter,
             0u32.into()
@@ -41,7 +45,8 @@ fn setup_events_pending_challenge<T: Config>(event_type: &ValidEvents, number_of
                 CheckResult::Ok,
                 &EthEventId {
                     signature: event_type.signature(),
-                    transaction_hash: H256::from([3; 32])
+                    transaction_hash: H256::from([3; 32]),
+                    log_index: 0,
                 },
                 &EventData::EmptyEvent,
                 account("dummy account", i, i),
@@ -92,7 +97,8 @@ fn setup_extrinsics_inputs<T: Config>(validators: Vec<Validator<<T as pallet_avn
 ) {
     let event_id = EthEventId {
         signature: ValidEvents::AddedValidator.signature(),
-        transaction_hash: H256::from([4; 32])
+        transaction_hash: H256::from([4; 32]),
+        log_index: 0,
     };
     let result: EthEventCheckResult<T::BlockNumber, T::AccountId> = EthEventCheckResult::new(
         0u32.into(),
@@ -110,6 +116,27 @@ fn setup_extrinsics_inputs<T: Config>(validators: Vec<Validator<<T as pallet_avn
     (result, ingress_counter, signature, validator)
 }
 
+fn setup_scan_result(number_of_logs: u32, contract_address: H160, from_block: u64) -> EthLogsScanResult {
+    let logs = (0 .. number_of_logs).map(|i| DiscoveredEthLog {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from_low_u64_be(100 + i as u64),
+        contract_address: contract_address.clone(),
+        block_number: from_block + i as u64,
+        block_log_index: 0,
+    }).collect::<Vec<DiscoveredEthLog>>();
+
+    return EthLogsScanResult { from_block, to_block: from_block + MAX_ETH_BLOCKS_TO_SCAN - 1, logs };
+}
+
+fn setup_event_descriptor() -> EthEventDescriptor {
+    return EthEventDescriptor {
+        signature: H256::from([5; 32]),
+        indexed_params: vec![EthAbiParamType::Address, EthAbiParamType::Address, EthAbiParamType::FixedBytes(32)],
+        non_indexed_params: vec![EthAbiParamType::Uint(256), EthAbiParamType::String],
+        contract_address: H160::from([6; 20]),
+    };
+}
+
 fn generate_signature<T: pallet_avn::Config>() -> <<T as avn::Config>::AuthorityId as RuntimeAppPublic>::Signature {
     let encoded_data = 0.encode();
     let authority_id = T::AuthorityId::generate_pair(None);
@@ -141,6 +168,7 @@ benchmarks! {
         let eth_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_hash,
+            log_index: 0,
         };
         let ingress_counter = TotalIngresses::get();
 
@@ -168,6 +196,7 @@ benchmarks! {
         let eth_event_id = EthEventId {
             signature: ValidEvents::Lifted.signature(),
             transaction_hash: tx_hash,
+            log_index: 0,
         };
         let ingress_counter = TotalIngresses::get();
 
@@ -194,6 +223,7 @@ benchmarks! {
         let eth_event_id = EthEventId {
             signature: ValidEvents::NftMint.signature(),
             transaction_hash: tx_hash,
+            log_index: 0,
         };
         let ingress_counter = TotalIngresses::get();
 
@@ -230,6 +260,7 @@ benchmarks! {
         let eth_event_id = EthEventId {
             signature: ValidEvents::NftMint.signature(),
             transaction_hash: tx_hash,
+            log_index: 0,
         };
         let ingress_counter = TotalIngresses::get();
 
@@ -355,6 +386,83 @@ benchmarks! {
         ).into());
     }
 
+    submit_ethereum_logs {
+        let l in 1 .. MAX_NUMBER_OF_DISCOVERED_LOGS;
+        let u in 1 .. MAX_NUMBER_OF_UNCHECKED_EVENTS;
+        let e in 1 .. MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES;
+
+        setup_unchecked_events::<T>(&ValidEvents::Lifted, u);
+        setup_events_pending_challenge::<T>(&ValidEvents::Lifted, e);
+        let validators = setup_validators::<T>(1);
+        let (_, _, signature, validator) = setup_extrinsics_inputs::<T>(validators.clone());
+
+        let lifting_contract = H160::from([1; 20]);
+        LiftingContractAddress::put(lifting_contract);
+        let from_block: u64 = 100;
+        NextEthBlockToScan::put(from_block);
+
+        let scan_result = setup_scan_result(l, lifting_contract, from_block);
+    }: _(RawOrigin::None, scan_result.clone(), validator, signature)
+    verify {
+        assert_eq!(NextEthBlockToScan::get(), scan_result.to_block + 1);
+        assert_eq!(UncheckedEvents::<T>::get().len(), (u + l) as usize);
+        assert_last_event::<T>(RawEvent::EthereumLogsScanned(scan_result.from_block, scan_result.to_block, l).into());
+    }
+
+    set_next_eth_block_to_scan {
+        let eth_block: u64 = 12_000_000;
+    }: _(RawOrigin::Root, eth_block)
+    verify {
+        assert_eq!(eth_block, NextEthBlockToScan::get());
+        assert_last_event::<T>(RawEvent::NextEthBlockToScanUpdated(eth_block).into());
+    }
+
+    register_event_descriptor {
+        let descriptor = setup_event_descriptor();
+    }: _(RawOrigin::Root, descriptor.clone())
+    verify {
+        assert_eq!(Some(descriptor.clone()), EventDescriptors::get(descriptor.signature));
+        assert_last_event::<T>(RawEvent::EventDescriptorRegistered(descriptor.signature, descriptor.contract_address).into());
+    }
+
+    remove_event_descriptor {
+        let descriptor = setup_event_descriptor();
+        EventDescriptors::insert(descriptor.signature, descriptor.clone());
+    }: _(RawOrigin::Root, descriptor.signature)
+    verify {
+        assert!(!EventDescriptors::contains_key(descriptor.signature));
+        assert_last_event::<T>(RawEvent::EventDescriptorRemoved(descriptor.signature).into());
+    }
+
+    set_event_validation_settings {
+        let settings = EventValidationSettings {
+            min_eth_block_confirmations: T::MinEthBlockConfirmation::get() + 10,
+            challenge_period: 1200u32.into(),
+        };
+    }: _(RawOrigin::Root, ValidEvents::Lifted, Some(settings.clone()))
+    verify {
+        assert_eq!(Some(settings.clone()), EventTypeValidationSettings::<T>::get(ValidEvents::Lifted));
+        assert_last_event::<T>(RawEvent::EventValidationSettingsUpdated(ValidEvents::Lifted, Some(settings)).into());
+    }
+
+    set_lift_validation_tiers {
+        let t in 1 .. MAX_LIFT_VALIDATION_TIERS;
+
+        let tiers: Vec<LiftValidationTier<T::BlockNumber>> = (1..=t)
+            .map(|i| LiftValidationTier {
+                min_amount: i as u128 * 1_000_000,
+                settings: EventValidationSettings {
+                    min_eth_block_confirmations: T::MinEthBlockConfirmation::get() + i as u64,
+                    challenge_period: (MINIMUM_EVENT_CHALLENGE_PERIOD + i).into(),
+                },
+            })
+            .collect();
+    }: _(RawOrigin::Root, tiers.clone())
+    verify {
+        assert_eq!(tiers.clone(), LiftValidationTiers::<T>::get());
+        assert_last_event::<T>(RawEvent::LiftValidationTiersUpdated(tiers).into());
+    }
+
     set_event_challenge_period {
         let new_event_challenge_period = 1200u32.into();
         assert_ne!(new_event_challenge_period, EventChallengePeriod::<T>::get());
@@ -387,6 +495,12 @@ mod tests {
             assert_ok!(test_benchmark_set_ethereum_contract_map_storage::<TestRuntime>());
             assert_ok!(test_benchmark_set_ethereum_contract_storage::<TestRuntime>());
             assert_ok!(test_benchmark_set_event_challenge_period::<TestRuntime>());
+            assert_ok!(test_benchmark_submit_ethereum_logs::<TestRuntime>());
+            assert_ok!(test_benchmark_set_next_eth_block_to_scan::<TestRuntime>());
+            assert_ok!(test_benchmark_register_event_descriptor::<TestRuntime>());
+            assert_ok!(test_benchmark_remove_event_descriptor::<TestRuntime>());
+            assert_ok!(test_benchmark_set_event_validation_settings::<TestRuntime>());
+            assert_ok!(test_benchmark_set_lift_validation_tiers::<TestRuntime>());
         });
     }
 }
@@ -18,6 +18,12 @@ pub trait WeightInfo {
     fn set_ethereum_contract_storage() -> Weight;
     fn set_event_challenge_period() -> Weight;
     fn signed_add_ethereum_log(u: u32, e: u32, ) -> Weight;
+    fn submit_ethereum_logs(l: u32, u: u32, e: u32, ) -> Weight;
+    fn set_next_eth_block_to_scan() -> Weight;
+    fn register_event_descriptor() -> Weight;
+    fn remove_event_descriptor() -> Weight;
+    fn set_event_validation_settings() -> Weight;
+    fn set_lift_validation_tiers(t: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ethereum_events
@@ -92,6 +98,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(DbWeight::get().reads(6 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
+	fn submit_ethereum_logs(l: u32, u: u32, e: u32, ) -> Weight {
+		(98_412_000 as Weight)
+			.saturating_add((104_387_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((806_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((3_915_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(l as Weight)))
+	}
+	fn set_next_eth_block_to_scan() -> Weight {
+		(44_870_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn register_event_descriptor() -> Weight {
+		(52_140_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_event_descriptor() -> Weight {
+		(49_630_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_event_validation_settings() -> Weight {
+		(47_215_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_lift_validation_tiers(t: u32, ) -> Weight {
+		(45_982_000 as Weight)
+			.saturating_add((1_127_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -165,4 +203,36 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(6 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
+	fn submit_ethereum_logs(l: u32, u: u32, e: u32, ) -> Weight {
+		(98_412_000 as Weight)
+			.saturating_add((104_387_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((806_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((3_915_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(l as Weight)))
+	}
+	fn set_next_eth_block_to_scan() -> Weight {
+		(44_870_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn register_event_descriptor() -> Weight {
+		(52_140_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_event_descriptor() -> Weight {
+		(49_630_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_event_validation_settings() -> Weight {
+		(47_215_000 as Weight)
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn set_lift_validation_tiers(t: u32, ) -> Weight {
+		(45_982_000 as Weight)
+			.saturating_add((1_127_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
 }
\ No newline at end of file
@@ -58,6 +58,20 @@ pub fn find_event(events: &Vec<JsonValue>, topic: H256) -> Option<(&JsonValue, H
     return None;
 }
 
+/// Returns the `index`-th event (starting at 0) with the `topic` signature that was emitted by a contract accepted by `is_valid_contract`
+pub fn find_nth_event<F: Fn(&H160) -> bool>(
+    events: &Vec<JsonValue>,
+    topic: H256,
+    index: u64,
+    is_valid_contract: F) -> Option<(&JsonValue, H160)>
+{
+    return events.into_iter()
+        .filter(|event| topic_matches(event, topic).map_or_else(|_| false, |v| v))
+        .filter_map(|event| get_contract_address(event).ok().map(|contract_address| (event, contract_address)))
+        .filter(|(_, contract_address)| is_valid_contract(contract_address))
+        .nth(index as usize);
+}
+
 pub fn get_data(event: &JsonValue) -> Result<Option<Vec<u8>>, SimpleError> {
     let event = event.get_object()?;
     let data = get_value_of(String::from("data"), event)?.get_string()?;
@@ -84,6 +98,22 @@ pub fn get_status(json_response: &JsonValue) -> Result<u8, SimpleError> {
     }
 }
 
+/// Returns the hash of the ethereum block that included the transaction
+pub fn get_block_hash(json_response: &JsonValue) -> Result<H256, SimpleError> {
+    let result = get_result(json_response)?;
+    let block_hash = get_value_of(String::from("blockHash"), result)?.get_string()?;
+    return Ok(H256::from(to_bytes32(block_hash)?));
+}
+
+pub fn get_block_number(json_response: &JsonValue) -> Result<u64, SimpleError> {
+    let result = get_result(json_response)?;
+    let block_number = get_value_of(String::from("blockNumber"), result)?.get_string()?;
+    match u64::from_str_radix(block_number.trim_start_matches("0x"), 16) {
+        Ok(n) => Ok(n),
+        Err(_e) => Err(SimpleError::plain_str("Block number is not a valid hex number"))
+    }
+}
+
 pub fn get_topics(event: &JsonValue) -> Result<Vec<Vec<u8>>, SimpleError> {
     let event = event.get_object()?;
     let topics = get_value_of(String::from("topics"), event)?.get_array()?;
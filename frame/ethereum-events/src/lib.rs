@@ -10,7 +10,7 @@ extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::{string::{String, ToString}};
 use simple_json2::json::JsonValue;
-use sp_std::{prelude::*, cmp};
+use sp_std::{prelude::*, cmp, convert::TryInto};
 use frame_support::{
     Parameter,
     decl_event,
@@ -31,7 +31,7 @@ use sp_core::{H256, H160};
 use sp_runtime::{
     DispatchError,
     offchain::{http, Duration, storage::StorageValueRef},
-    traits::{CheckedAdd, Dispatchable, Hash, IdentifyAccount, Member, Verify, Zero},
+    traits::{CheckedAdd, Dispatchable, Hash, IdentifyAccount, Member, Saturating, Verify, Zero},
     transaction_validity::{
         TransactionValidity,
         ValidTransaction,
@@ -47,11 +47,13 @@ use codec::{Encode, Decode};
 use sp_avn_common::{
     event_types::{
         EthEventId, EthEventCheckResult, CheckResult, ValidEvents, ChallengeReason, Challenge, ProcessedEventHandler, Validator,
-        EventData, AddedValidatorData, LiftedData, NftMintData, NftTransferToData, NftCancelListingData, NftEndBatchListingData
+        EventData, AddedValidatorData, LiftedData, NftMintData, NftTransferToData, NftCancelListingData, NftEndBatchListingData,
+        EthLogsQuery, EthLogsScanResult, DiscoveredEthLog, EthEventDescriptor, EthEventStatus, EthEventStatusInfo,
     },
     IngressCounter,
     Proof,
-    InnerCallValidator
+    InnerCallValidator,
+    ocw_metrics::{self, OCW_EVENTS_CHECKED_COUNTER_KEY},
 };
 
 use pallet_session::historical::IdentificationTuple;
@@ -61,23 +63,37 @@ use pallet_avn::{self as avn, Error as avn_error};
 pub mod offence;
 use crate::offence::{InvalidEthereumLogOffence, EthereumLogOffenceType, create_and_report_invalid_log_offence};
 
+pub mod runtime_api;
+
 pub mod event_parser;
-use crate::event_parser::{get_events, find_event, get_data, get_topics, get_status, get_num_confirmations};
+use crate::event_parser::{
+    get_events, find_nth_event, get_data, get_topics, get_status, get_num_confirmations, get_block_hash, get_block_number
+};
 
 pub type AVN<T> = avn::Module::<T>;
 
 const VALIDATED_EVENT_LOCAL_STORAGE: &'static [u8; 28] = b"eth_events::validated_events";
+const REVERIFIED_EVENT_LOCAL_STORAGE: &'static [u8; 29] = b"eth_events::reverified_events";
 
 const NAME: &'static [u8; 20] = b"eth_events::last_run";
 
 const ERROR_CODE_EVENT_NOT_IN_UNCHECKED: u8 = 0;
 const ERROR_CODE_INVALID_EVENT_DATA: u8 = 1;
-const ERROR_CODE_IS_PRIMARY_HAS_ERROR: u8 = 2;
-const ERROR_CODE_VALIDATOR_NOT_PRIMARY: u8 = 3;
+const ERROR_CODE_EVENT_CHECKER_HAS_ERROR: u8 = 2;
+const ERROR_CODE_VALIDATOR_NOT_ASSIGNED_CHECKER: u8 = 3;
 const ERROR_CODE_EVENT_NOT_IN_PENDING_CHALLENGES: u8 = 4;
+const ERROR_CODE_INVALID_SCAN_RANGE: u8 = 5;
+const ERROR_CODE_EVENT_WAITING_FOR_RETRY: u8 = 6;
 
 const MINIMUM_EVENT_CHALLENGE_PERIOD: u32 = 60;
 
+// Number of blocks before the end of the challenge period when validators check again that an event's ethereum block
+// is still canonical. This must be less than MINIMUM_EVENT_CHALLENGE_PERIOD.
+const EVENT_REVERIFICATION_WINDOW: u32 = 10;
+
+// Events submitted with just a transaction hash refer to the first event of their type in the transaction
+const FIRST_LOG_INDEX: u64 = 0;
+
 pub const SIGNED_ADD_ETHEREUM_LOG_CONTEXT: &'static [u8] = b"authorization for add ethereum log operation";
 
 #[cfg(test)]
@@ -128,6 +144,46 @@ mod tests_ethereum_logs;
 #[path = "tests/test_proxy_signed_add_ethereum_logs.rs"]
 mod test_proxy_signed_add_ethereum_logs;
 
+#[cfg(test)]
+#[path = "tests/test_log_scanning.rs"]
+mod test_log_scanning;
+
+#[cfg(test)]
+#[path = "tests/test_migrations.rs"]
+mod test_migrations;
+
+#[cfg(test)]
+#[path = "tests/test_event_descriptors.rs"]
+mod test_event_descriptors;
+
+#[cfg(test)]
+#[path = "tests/test_reorg_detection.rs"]
+mod test_reorg_detection;
+
+#[cfg(test)]
+#[path = "tests/test_event_validation_settings.rs"]
+mod test_event_validation_settings;
+
+#[cfg(test)]
+#[path = "tests/test_parallel_event_checks.rs"]
+mod test_parallel_event_checks;
+
+#[cfg(test)]
+#[path = "tests/test_event_check_retries.rs"]
+mod test_event_check_retries;
+
+#[cfg(test)]
+#[path = "tests/test_event_status.rs"]
+mod test_event_status;
+
+#[cfg(test)]
+#[path = "tests/test_challenge_evidence.rs"]
+mod test_challenge_evidence;
+
+#[cfg(test)]
+#[path = "tests/test_processed_events_archive.rs"]
+mod test_processed_events_archive;
+
 mod benchmarking;
 
 // TODO: [TYPE: business logic][PRI: high][CRITICAL]
@@ -142,6 +198,27 @@ pub enum EthereumContracts {
     NftMarketplace,
 }
 
+/// The ethereum block confirmations and challenge period an event needs before it can be processed
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct EventValidationSettings<BlockNumber> {
+    pub min_eth_block_confirmations: u64,
+    pub challenge_period: BlockNumber,
+}
+
+/// Validation settings for the lifts of at least `min_amount`
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct LiftValidationTier<BlockNumber> {
+    pub min_amount: u128,
+    pub settings: EventValidationSettings<BlockNumber>,
+}
+
+/// Number of checks of an unchecked event that failed with an http error, and the block after which it can be checked again
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct EventCheckRetry<BlockNumber> {
+    pub attempts: u32,
+    pub retry_after_block: BlockNumber,
+}
+
 pub trait ProcessedEventsChecker {
     fn check_event(event_id: &EthEventId) -> bool;
 }
@@ -149,12 +226,18 @@ pub trait ProcessedEventsChecker {
 const SUBMIT_CHECKEVENT_RESULT_CONTEXT: &'static [u8] = b"submit_checkevent_result";
 const CHALLENGE_EVENT_CONTEXT: &'static [u8] = b"challenge_event";
 const PROCESS_EVENT_CONTEXT: &'static [u8] = b"process_event";
+const SUBMIT_ETHEREUM_LOGS_CONTEXT: &'static [u8] = b"submit_ethereum_logs";
 
 const MAX_NUMBER_OF_VALIDATORS_ACCOUNTS: u32 = 10;
 const MAX_NUMBER_OF_UNCHECKED_EVENTS: u32 = 5;
 const MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES: u32 = 5;
 const MAX_CHALLENGES: u32 = 10;
 
+// Maximum number of Ethereum blocks scanned for logs by a single offchain worker run
+const MAX_ETH_BLOCKS_TO_SCAN: u64 = 500;
+
+const MAX_LIFT_VALIDATION_TIERS: u32 = 10;
+
 // Public interface of this pallet
 pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Config + pallet_session::historical::Config {
     type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
@@ -166,6 +249,21 @@ pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Confi
     /// Minimum number of blocks that have passed after an ethereum transaction has been mined
     type MinEthBlockConfirmation: Get<u64>;
 
+    /// Maximum number of unchecked events checked in a block, shared between all the validators
+    type MaxEventsCheckedPerBlock: Get<u32>;
+
+    /// Maximum number of checks that fail with an http error before an event is rejected.
+    /// Checks that find too few confirmations are not counted: the event is checked again until it has enough of them.
+    type MaxEventCheckAttempts: Get<u32>;
+
+    /// Number of blocks an event waits to be checked again after its first inconclusive check. It doubles after every attempt.
+    /// An event without enough confirmations always waits this many blocks.
+    type EventCheckRetryDelay: Get<Self::BlockNumber>;
+
+    /// Number of ethereum blocks after which an event is too old to be added.
+    /// Processed events are archived by eras of this many ethereum blocks, once all the events of an era are too old.
+    type ProcessedEventsEthBlockHorizon: Get<u64>;
+
     ///  A type that gives the pallet the ability to report offences
     type ReportInvalidEthereumLog: ReportOffence<
             Self::AccountId,
@@ -215,6 +313,24 @@ decl_event!(
         CallDispatched(Relayer, Hash),
         /// NFT related Ethereum event was added(EthEventId, AddedBy)
         NftEthereumEventAdded(EthEventId, AccountId),
+        /// EthereumLogsScanned(FromEthBlock, ToEthBlock, NumberOfEventsAdded)
+        EthereumLogsScanned(u64, u64, u32),
+        /// NextEthBlockToScanUpdated(EthBlock)
+        NextEthBlockToScanUpdated(u64),
+        /// EventDescriptorRegistered(EventSignature, T1 contract address)
+        EventDescriptorRegistered(H256, H160),
+        /// EventDescriptorRemoved(EventSignature)
+        EventDescriptorRemoved(H256),
+        /// EventBlockNoLongerCanonical(EthEventId, EthBlockNumber, EthBlockHash)
+        EventBlockNoLongerCanonical(EthEventId, u64, H256),
+        /// EventValidationSettingsUpdated(EventType, Settings)
+        EventValidationSettingsUpdated(ValidEvents, Option<EventValidationSettings<BlockNumber>>),
+        /// LiftValidationTiersUpdated(Tiers)
+        LiftValidationTiersUpdated(Vec<LiftValidationTier<BlockNumber>>),
+        /// EventCheckRetryScheduled(EthEventId, CheckResult, Attempts, RetryAfterBlock)
+        EventCheckRetryScheduled(EthEventId, CheckResult, u32, BlockNumber),
+        /// ProcessedEventsArchived(Era, NumberOfEventsArchived)
+        ProcessedEventsArchived(u64, u32),
     }
 );
 
@@ -241,6 +357,14 @@ decl_error! {
         SenderIsNotSigner,
         UnauthorizedTransaction,
         UnauthorizedSignedAddEthereumLogTransaction,
+        InvalidEthereumLogsScanRange,
+        ErrorDecodingEthereumLogs,
+        InvalidEventDescriptor,
+        CannotRegisterBuiltInEvent,
+        EventDescriptorNotFound,
+        InvalidEventValidationSettings,
+        InvalidLiftValidationTiers,
+        InvalidChallengeEvidence,
 	}
 }
 
@@ -261,6 +385,7 @@ decl_storage! {
 
         // Should be a set as requires quick access but Substrate doesn't support sets: they recommend using a bool HashMap.
         // This map holds all events that have been processed, regardless of the outcome of the execution of the events.
+        // The value is true if the event was accepted. Events processed before outcomes were recorded are all true.
         pub ProcessedEvents get(fn processed_events) config(): map hasher(blake2_128_concat) EthEventId => bool;
 
         pub Challenges get(fn challenges): map hasher(blake2_128_concat) EthEventId => Vec<T::AccountId>;
@@ -277,6 +402,51 @@ decl_storage! {
         /// An account nonce that represents the number of proxy transactions from this account
         pub ProxyNonces get(fn proxy_nonce): map hasher(blake2_128_concat) T::AccountId => u64;
 
+        /// The next Ethereum block the offchain workers scan for logs of our tier1 contracts. Scanning is disabled while this is 0.
+        pub NextEthBlockToScan get(fn next_eth_block_to_scan): u64;
+
+        /// T1 events registered by root, in addition to the built-in `ValidEvents`, keyed by their signature.
+        /// Their logs are decoded with the descriptor and handed to the processed event handlers as `EventData::LogDecoded`.
+        pub EventDescriptors get(fn event_descriptors): map hasher(blake2_128_concat) H256 => Option<EthEventDescriptor>;
+
+        /// The challengers of an event who found that the ethereum block that included it is no longer canonical.
+        /// They are also part of the event's `Challenges`.
+        pub BlockNoLongerCanonicalChallenges get(fn block_no_longer_canonical_challenges):
+            map hasher(blake2_128_concat) EthEventId => Vec<T::AccountId>;
+
+        /// What the challengers of an event found, for the challenges that carry evidence.
+        /// They are also part of the event's `Challenges`.
+        pub ChallengeEvidences get(fn challenge_evidences):
+            map hasher(blake2_128_concat) EthEventId => Vec<(T::AccountId, ChallengeReason)>;
+
+        /// Validation settings of the event types that do not use `MinEthBlockConfirmation` and `EventChallengePeriod`
+        pub EventTypeValidationSettings get(fn event_type_validation_settings):
+            map hasher(blake2_128_concat) ValidEvents => Option<EventValidationSettings<T::BlockNumber>>;
+
+        /// Validation settings of large lifts, by increasing `min_amount`. They take precedence over the settings of `Lifted`.
+        pub LiftValidationTiers get(fn lift_validation_tiers): Vec<LiftValidationTier<T::BlockNumber>>;
+
+        /// Events rejected in a way that allows them to be added again, until they are added again
+        pub ResubmittableEvents get(fn resubmittable_events): map hasher(blake2_128_concat) EthEventId => ();
+
+        /// Unchecked events whose last check was inconclusive. They stay unchecked until they can be checked again.
+        pub EventCheckRetries get(fn event_check_retries):
+            map hasher(blake2_128_concat) EthEventId => Option<EventCheckRetry<T::BlockNumber>>;
+
+        /// Processed events that are not archived yet, by the era of the ethereum block that included them.
+        /// Events whose ethereum block is unknown, for example invalid events, are never archived.
+        pub ProcessedEventsByEra get(fn processed_events_by_era): map hasher(blake2_128_concat) u64 => Vec<EthEventId>;
+
+        /// The most recent ethereum block of a processed event
+        pub LatestProcessedEthBlock get(fn latest_processed_eth_block): u64;
+
+        /// The oldest era of processed events that is not archived yet
+        pub NextEraToArchive get(fn next_era_to_archive): u64;
+
+        /// The last raw `ProcessedEvents` key checked by the migration to indexed event ids.
+        /// The migration runs over several blocks and is finished once this is cleared.
+        pub ProcessedEventsMigrationCursor get(fn processed_events_migration_cursor): Option<Vec<u8>>;
+
         /// Track the version of this storage. Mainly used for storage migration.
         StorageVersion: Releases;
     }
@@ -290,6 +460,7 @@ decl_storage! {
                         EthEventId {
                             signature: ValidEvents::Lifted.signature(),
                             transaction_hash: tx_hash,
+                            log_index: FIRST_LOG_INDEX,
                         },
                         ingress_counter,
                         <T as system::Config>::BlockNumber::zero()
@@ -297,6 +468,7 @@ decl_storage! {
                 })
                 .collect::<Vec<(EthEventId, IngressCounter, T::BlockNumber)>>();
             <UncheckedEvents<T>>::put(unchecked_lift_events);
+            StorageVersion::put(Releases::V4_0_0);
             assert_ne!(config.quorum_factor, 0, "Quorum factor cannot be 0");
         });
     }
@@ -314,7 +486,7 @@ decl_module! {
         // Keys: U - Number of unchecked events
         //       E - Number of pending challenge events
         //   DbReads: `ValidatorManagerContractAddress`, `ProcessedEvents`, `TotalIngresses`: O(1)
-        //   DbWrites: `TotalIngresses`, `UncheckedEvents`: O(1)
+        //   DbWrites: `TotalIngresses`, `UncheckedEvents`, `ResubmittableEvents`: O(1)
         //   Iterate UncheckedEvents vector: O(U)
         //   Iterate EventsPendingChallenge vector: O(E)
         //   Emitted Event: `EthereumEventAdded`: O(1)
@@ -331,14 +503,14 @@ decl_module! {
             ensure!(&tx_hash != &H256::zero(), Error::<T>::MalformedHash);
 
             // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            return Self::add_event(ValidEvents::AddedValidator, tx_hash, account_id);
+            return Self::add_event(ValidEvents::AddedValidator, tx_hash, FIRST_LOG_INDEX, account_id);
         }
 
         // # <weight>
         // Keys: U - Number of unchecked events
         //       E - Number of pending challenge events
         //   DbReads: `LiftingContractAddress`, `ProcessedEvents`, `TotalIngresses`: O(1)
-        //   DbWrites: `TotalIngresses`, `UncheckedEvents`: O(1)
+        //   DbWrites: `TotalIngresses`, `UncheckedEvents`, `ResubmittableEvents`: O(1)
         //   Iterate UncheckedEvents vector: O(U)
         //   Iterate EventsPendingChallenge vector: O(E)
         //   Emitted Event: `EthereumEventAdded`: O(1)
@@ -355,17 +527,18 @@ decl_module! {
             ensure!(&tx_hash != &H256::zero(), Error::<T>::MalformedHash);
 
             // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            return Self::add_event(ValidEvents::Lifted, tx_hash, account_id);
+            return Self::add_event(ValidEvents::Lifted, tx_hash, FIRST_LOG_INDEX, account_id);
         }
 
         /// # <weight>
         /// Keys: V - number of validators
         ///       U - number of unchecked events
-        ///   DbReads: `QuorumFactor`: O(1)
-        ///   DbWrites: `EventsPendingChallenge`, `UncheckedEvents`: O(1)
+        ///   DbReads: `QuorumFactor`, `EventChallengePeriod`, `EventTypeValidationSettings`, `LiftValidationTiers`,
+        ///            `EventCheckRetries`: O(1)
+        ///   DbWrites: `EventsPendingChallenge`, `UncheckedEvents`, `EventCheckRetries`: O(1)
         ///   avn pallet is_validator operation: O(V)
         ///   Iterate unchecked_events vector operation: O(U)
-        ///   Emitted event: `EventValidated`: O(1)
+        ///   Emitted event: `EventValidated` or `EventCheckRetryScheduled`: O(1)
         /// Total Complexity: O(1 + V + U)
         /// # </weight>
         #[weight = <T as Config>::WeightInfo::submit_checkevent_result(
@@ -388,9 +561,16 @@ decl_module! {
                 |(event, counter, _)| event == &result.event.event_id && counter == &ingress_counter);
             if let Some(event_index) = event_index {
                 let current_block = <system::Module<T>>::block_number();
+                // The event stays unchecked until its next check, unless it has run out of attempts
+                if Self::is_inconclusive(&result.result) && Self::schedule_check_retry(&result, current_block) {
+                    return Ok(());
+                }
+
+                <EventCheckRetries<T>>::remove(&result.event.event_id);
                 let mut result = result;
+                let challenge_period = Self::get_validation_settings(&result.event.event_id, &result.event.event_data).challenge_period;
                 result.ready_for_processing_after_block = current_block
-                    .checked_add(&Self::event_challenge_period())
+                    .checked_add(&challenge_period)
                     .ok_or(Error::<T>::Overflow)?
                     .into();
                 result.min_challenge_votes = (AVN::<T>::active_validators().len() as u32) / Self::quorum_factor();
@@ -414,15 +594,25 @@ decl_module! {
         /// # <weight>
         /// Keys: E - number of events pending challenge
         ///       V - number of validators
-        ///   DbWrites: `EventsPendingChallenge`, `ProcessedEvents`, `QuorumFactor`, `Challenges`
+        ///       C - number of challenges with evidence
+        ///   DbWrites: `EventsPendingChallenge`, `ProcessedEvents`, `ResubmittableEvents`, `QuorumFactor`, `Challenges`
+        ///   DbReads: `NextEraToArchive`: O(1)
+        ///   If the event's ethereum block is known:
+        ///     - DbWrites: `ProcessedEventsByEra`, `LatestProcessedEthBlock`: O(1)
         ///   Iterate EventsPendingChallenge vector: O(E)
         ///   avn pallet operations:
         ///     - is_validator operation: O(V)
         ///     - DbReads: `Validators`: O(1)
+        ///   DbReads: `ChallengeEvidences`: O(1)
+        ///   Iterate `ChallengeEvidences` operation: O(C^2)
+        ///   DbWrites: `ChallengeEvidences`: O(1)
         ///   If challenge is successful:
-        ///     - Create and report invalid log offence: O(1)
-        ///   Emitted event: `EventProcessed`, `ChallengeSucceeded`, : O(1)
-        /// Total Complexity: O(1 + E + V)
+        ///     - DbReads: `BlockNoLongerCanonicalChallenges`: O(1)
+        ///     - Create and report invalid log offences: O(1)
+        ///   If the event's ethereum block is no longer canonical:
+        ///     - DbWrites: `Challenges`, `BlockNoLongerCanonicalChallenges`: O(1)
+        ///   Emitted event: `EventProcessed`, `ChallengeSucceeded`, `EventBlockNoLongerCanonical`: O(1)
+        /// Total Complexity: O(1 + E + V + C^2)
         /// #</weight>
         #[weight = <T as Config>::WeightInfo::process_event_with_successful_challenge(
                 MAX_NUMBER_OF_VALIDATORS_ACCOUNTS,
@@ -451,21 +641,45 @@ decl_module! {
                 Error::<T>::InvalidEventToProcess
             );
 
-            let successful_challenge = Self::is_challenge_successful(validated);
+            let outvoted_challengers = Self::get_outvoted_challengers(&event_id);
+            let successful_challenge = Self::is_challenge_successful(validated, &outvoted_challengers);
+            // The check was correct when it was made, but the ethereum block that included the event has since been reorged out
+            let block_no_longer_canonical = successful_challenge && Self::is_block_no_longer_canonical(validated);
 
             // Once an event is added to the `ProcessedEvents` set, it cannot be processed again.
             // If there is a successfull challenge on an `Invalid` event, it means the event should
             // have been valid so DO NOT add it to the processed set to allow the event to be processed again in the future.
+            // The same applies to an event whose block is no longer canonical: the transaction may be mined again in a new block.
             let event_was_declared_invalid = validated.result == CheckResult::Invalid;
-            let event_can_be_resubmitted = event_was_declared_invalid && successful_challenge;
-            if !event_can_be_resubmitted {
-                <ProcessedEvents>::insert(event_id.clone(), true);
+            let event_can_be_resubmitted = (event_was_declared_invalid && successful_challenge) || block_no_longer_canonical;
+            // An event of an archived era is too old: it may have been processed already
+            let event_in_archived_era = Self::is_in_archived_era(validated);
+            let event_accepted = validated.result == CheckResult::Ok && !successful_challenge && !event_in_archived_era;
+            if event_can_be_resubmitted {
+                <ResubmittableEvents>::insert(event_id.clone(), ());
+            } else if !event_in_archived_era {
+                // Events of an archived era are not recorded, because their era would never remove them again.
+                // If one is added again, the checks reject it for being older than `ProcessedEventsEthBlockHorizon`.
+                <ProcessedEvents>::insert(event_id.clone(), event_accepted);
+                Self::add_to_era_if_required(&event_id, validated.eth_block_number);
             }
             <EventsPendingChallenge<T>>::mutate(|pending_events| pending_events.remove(event_index));
+            <ChallengeEvidences<T>>::remove(&event_id);
             // TODO: Remove this event's challenges from the Challenges map too.
             Self::deposit_event(Event::<T>::EventProcessed(event_id.clone(), validator.account_id.clone(), !successful_challenge));
 
-            if successful_challenge {
+            if block_no_longer_canonical {
+                Self::deposit_event(Event::<T>::ChallengeSucceeded(event_id.clone(), validated.result.clone()));
+                Self::deposit_event(Event::<T>::EventBlockNoLongerCanonical(
+                    event_id.clone(),
+                    validated.eth_block_number,
+                    validated.eth_block_hash));
+
+                // The validator who submitted the check did nothing wrong, so there is no offence to report.
+                // Clear the challenges so a resubmission of this event starts afresh.
+                <Challenges<T>>::remove(&event_id);
+                <BlockNoLongerCanonicalChallenges<T>>::remove(&event_id);
+            } else if successful_challenge {
                 Self::deposit_event(Event::<T>::ChallengeSucceeded(event_id.clone(), validated.result.clone()));
 
                 // Now report the offence of the validator who submitted the check
@@ -474,6 +688,15 @@ decl_module! {
                     &vec![validated.checked_by.clone()],
                     EthereumLogOffenceType::IncorrectValidationResultSubmitted,
                 );
+
+                // The challengers who disagreed with the majority of the challengers were wrong too
+                if !outvoted_challengers.is_empty() {
+                    create_and_report_invalid_log_offence::<T>(
+                        &validator.account_id,
+                        &outvoted_challengers,
+                        EthereumLogOffenceType::IncorrectChallengeEvidenceSubmitted,
+                    );
+                }
             } else {
                 // SYS-536 report the offence for the people who challenged
                 create_and_report_invalid_log_offence::<T>(
@@ -483,7 +706,7 @@ decl_module! {
                 );
             }
 
-            if validated.result == CheckResult::Ok && !successful_challenge {
+            if event_accepted {
                 // Let everyone know we have processed an event.
                 T::ProcessedEventHandler::on_event_processed(&validated.event)?;
 
@@ -518,7 +741,7 @@ decl_module! {
         ///     - DbReads: `Validators`: O(1)
         ///   Iterate `EventsPendingChallenge` operation: O(E)
         ///   Iterate `Challenges` operation: O(C)
-        ///   DbWrites: `Challenges`: O(1)
+        ///   DbWrites: `Challenges`, `BlockNoLongerCanonicalChallenges`, `ChallengeEvidences`: O(1)
         ///   Emitted Event: `EventChallenged`: O(1)
         /// Total Complexity: O(1 + V + E + C)
         /// #</weight>
@@ -543,7 +766,14 @@ decl_module! {
                 .map(|(event, _counter, _) | event)
                 .last(); // returns the most recent occurrence of event_id (in the unexpected case there is more than one)
             ensure!(checked.is_some(), Error::<T>::InvalidEventToChallenge);
-            ensure!(checked.expect("Not None").checked_by != challenge.challenged_by, Error::<T>::ChallengingOwnEvent);
+            let checked = checked.expect("Not None");
+            ensure!(checked.checked_by != challenge.challenged_by, Error::<T>::ChallengingOwnEvent);
+            // Only an event that was found on ethereum can have its block reorged out
+            ensure!(
+                challenge.challenge_reason != ChallengeReason::BlockNoLongerCanonical || checked.result == CheckResult::Ok,
+                Error::<T>::InvalidEventToChallenge
+            );
+            ensure!(Self::evidence_contradicts_check(&challenge.challenge_reason, checked), Error::<T>::InvalidChallengeEvidence);
 
             // TODO [TYPE: business logic][PRI: medium][CRITICAL][JIRA: 349]: Make sure the challenge period has not passed
             // Note: the current block number can be different to the block_number the offchain worker was invoked in
@@ -564,6 +794,16 @@ decl_module! {
                 <Challenges<T>>::insert(challenge.event_id.clone(), vec![challenge.challenged_by.clone()]);
             }
 
+            if challenge.challenge_reason == ChallengeReason::BlockNoLongerCanonical {
+                <BlockNoLongerCanonicalChallenges<T>>::append(&challenge.event_id, challenge.challenged_by.clone());
+            }
+
+            if challenge.challenge_reason.has_evidence() {
+                <ChallengeEvidences<T>>::append(
+                    &challenge.event_id,
+                    (challenge.challenged_by.clone(), challenge.challenge_reason.clone()));
+            }
+
             Self::deposit_event(Event::<T>::EventChallenged(
                 challenge.event_id,
                 challenge.challenged_by,
@@ -577,13 +817,14 @@ decl_module! {
         // Keys: U - Number of unchecked events
         //       E - Number of pending challenge events
         //   DbReads: ContractAddress, `ProcessedEvents`, `TotalIngresses`: O(1)
-        //   DbWrites: `TotalIngresses`, `UncheckedEvents`: O(1)
+        //   DbWrites: `TotalIngresses`, `UncheckedEvents`, `ResubmittableEvents`: O(1)
         //   Iterate UncheckedEvents vector: O(U)
         //   Iterate EventsPendingChallenge vector: O(E)
         //   Emitted Event: `EthereumEventAdded`: O(1)
         // Total Complexity: O(1 + U + E)
         // # </weights>
-        /// Submits an ethereum transaction hash into the chain
+        /// Submits an ethereum transaction hash into the chain.
+        /// Only the first event of this type emitted by the transaction is added.
         #[weight = <T as Config>::WeightInfo::add_ethereum_log(
             MAX_NUMBER_OF_UNCHECKED_EVENTS,
             MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES
@@ -593,14 +834,14 @@ decl_module! {
             ensure!(&tx_hash != &H256::zero(), Error::<T>::MalformedHash);
 
             // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            return Self::add_event(event_type, tx_hash, account_id);
+            return Self::add_event(event_type, tx_hash, FIRST_LOG_INDEX, account_id);
         }
 
         // # <weight>
         // Keys: U - Number of unchecked events
         //       E - Number of pending challenge events
         //   DbReads: `ProxyNonces`, ContractAddress, `ProcessedEvents`, `TotalIngresses`: O(1)
-        //   DbWrites: `ProxyNonces`, `TotalIngresses`, `UncheckedEvents`: O(1)
+        //   DbWrites: `ProxyNonces`, `TotalIngresses`, `UncheckedEvents`, `ResubmittableEvents`: O(1)
         //   Iterate UncheckedEvents vector: O(U)
         //   Iterate EventsPendingChallenge vector: O(E)
         //   Emitted Event: `EthereumEventAdded`: O(1)
@@ -627,7 +868,7 @@ decl_module! {
             <ProxyNonces<T>>::mutate(&sender, |n| *n += 1);
 
             // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
-            return Self::add_event(event_type, tx_hash, sender);
+            return Self::add_event(event_type, tx_hash, FIRST_LOG_INDEX, sender);
         }
 
         // # <weight>
@@ -666,6 +907,174 @@ decl_module! {
             Ok(())
         }
 
+        /// # <weight>
+        /// Keys: L - number of discovered logs
+        ///       U - number of unchecked events
+        ///       E - number of events pending challenge
+        ///   avn pallet is_validator operation: O(1)
+        ///   DbReads: `NextEthBlockToScan`: O(1)
+        ///   For each discovered log:
+        ///     - DbReads: ContractAddress, `ProcessedEvents`, `TotalIngresses`: O(1)
+        ///     - DbWrites: `TotalIngresses`, `UncheckedEvents`, `ResubmittableEvents`: O(1)
+        ///     - Iterate UncheckedEvents and EventsPendingChallenge vectors: O(U + E)
+        ///     - Emitted Event: `EthereumEventAdded`: O(1)
+        ///   DbWrites: `NextEthBlockToScan`: O(1)
+        ///   Emitted Event: `EthereumLogsScanned`: O(1)
+        /// Total Complexity: O(1 + L * (U + E))
+        /// # </weight>
+        /// Adds the events found by scanning the logs of our tier1 contracts, and moves the scan on to the next block range
+        #[weight = <T as Config>::WeightInfo::submit_ethereum_logs(
+            scan_result.logs.len() as u32,
+            MAX_NUMBER_OF_UNCHECKED_EVENTS,
+            MAX_NUMBER_OF_EVENTS_PENDING_CHALLENGES
+        )]
+        fn submit_ethereum_logs(
+            origin,
+            scan_result: EthLogsScanResult,
+            validator: Validator<T::AuthorityId, T::AccountId>,
+            // Signature and structural validation is already done in validate unsigned so no need to do it here
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+            ensure!(Self::is_validator(&validator.account_id), Error::<T>::InvalidKey);
+            ensure!(Self::is_next_scan_range(&scan_result), Error::<T>::InvalidEthereumLogsScanRange);
+
+            let next_eth_block_to_scan = scan_result.to_block.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+            let number_of_events_added = Self::get_discovered_event_ids(&scan_result.logs)
+                .into_iter()
+                .filter(|event_id| Self::add_discovered_event(event_id.clone(), validator.account_id.clone()).is_ok())
+                .count() as u32;
+
+            <NextEthBlockToScan>::put(next_eth_block_to_scan);
+
+            Self::deposit_event(Event::<T>::EthereumLogsScanned(scan_result.from_block, scan_result.to_block, number_of_events_added));
+
+            Ok(())
+        }
+
+        // # <weight>
+        //   DbWrites: NextEthBlockToScan: O(1)
+        //   Emitted Event: NextEthBlockToScanUpdated: O(1)
+        //  - Total Complexity: O(1)
+        // # </weights>
+        /// Sets the Ethereum block the offchain workers scan for logs next. Use 0 to disable scanning.
+        #[weight = <T as Config>::WeightInfo::set_next_eth_block_to_scan()]
+        pub fn set_next_eth_block_to_scan(origin, eth_block: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            <NextEthBlockToScan>::put(eth_block);
+            Self::deposit_event(Event::<T>::NextEthBlockToScanUpdated(eth_block));
+            Ok(())
+        }
+
+        // # <weight>
+        //   DbReads: EventDescriptors: O(1)
+        //   DbWrites: EventDescriptors: O(1)
+        //   Emitted Event: EventDescriptorRegistered: O(1)
+        //  - Total Complexity: O(1)
+        // # </weights>
+        /// Registers (or replaces) the descriptor of a T1 event, so its logs can be added and decoded without a runtime upgrade
+        #[weight = <T as Config>::WeightInfo::register_event_descriptor()]
+        pub fn register_event_descriptor(origin, descriptor: EthEventDescriptor) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(descriptor.is_valid(), Error::<T>::InvalidEventDescriptor);
+            ensure!(ValidEvents::try_from(&descriptor.signature).is_none(), Error::<T>::CannotRegisterBuiltInEvent);
+
+            let (signature, contract_address) = (descriptor.signature, descriptor.contract_address);
+            <EventDescriptors>::insert(signature, descriptor);
+            Self::deposit_event(Event::<T>::EventDescriptorRegistered(signature, contract_address));
+            Ok(())
+        }
+
+        // # <weight>
+        //   DbReads: EventDescriptors: O(1)
+        //   DbWrites: EventDescriptors: O(1)
+        //   Emitted Event: EventDescriptorRemoved: O(1)
+        //  - Total Complexity: O(1)
+        // # </weights>
+        /// Removes the descriptor of a T1 event. Events of this type still waiting to be checked will be found invalid.
+        #[weight = <T as Config>::WeightInfo::remove_event_descriptor()]
+        pub fn remove_event_descriptor(origin, signature: H256) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(<EventDescriptors>::contains_key(&signature), Error::<T>::EventDescriptorNotFound);
+
+            <EventDescriptors>::remove(&signature);
+            Self::deposit_event(Event::<T>::EventDescriptorRemoved(signature));
+            Ok(())
+        }
+
+        // # <weight>
+        //   DbWrites: EventTypeValidationSettings: O(1)
+        //   Emitted Event: EventValidationSettingsUpdated: O(1)
+        //  - Total Complexity: O(1)
+        // # </weights>
+        /// Sets the ethereum block confirmations and challenge period required by an event type.
+        /// `None` restores `MinEthBlockConfirmation` and `EventChallengePeriod`.
+        #[weight = <T as Config>::WeightInfo::set_event_validation_settings()]
+        pub fn set_event_validation_settings(
+            origin,
+            event_type: ValidEvents,
+            settings: Option<EventValidationSettings<T::BlockNumber>>) -> DispatchResult
+        {
+            ensure_root(origin)?;
+
+            match &settings {
+                Some(event_type_settings) => {
+                    ensure!(Self::are_validation_settings_valid(event_type_settings), Error::<T>::InvalidEventValidationSettings);
+                    <EventTypeValidationSettings<T>>::insert(event_type.clone(), event_type_settings);
+                },
+                None => <EventTypeValidationSettings<T>>::remove(&event_type),
+            };
+
+            Self::deposit_event(Event::<T>::EventValidationSettingsUpdated(event_type, settings));
+            Ok(())
+        }
+
+        // # <weight>
+        // Keys: T - number of tiers
+        //   Iterate tiers: O(T)
+        //   DbWrites: LiftValidationTiers: O(1)
+        //   Emitted Event: LiftValidationTiersUpdated: O(1)
+        //  - Total Complexity: O(T)
+        // # </weights>
+        /// Replaces the validation settings of large lifts. Tiers must be sorted by increasing `min_amount`.
+        /// A lift uses the tier with the highest `min_amount` it reaches. Use an empty list to remove all the tiers.
+        #[weight = <T as Config>::WeightInfo::set_lift_validation_tiers(MAX_LIFT_VALIDATION_TIERS)]
+        pub fn set_lift_validation_tiers(origin, tiers: Vec<LiftValidationTier<T::BlockNumber>>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(tiers.len() as u32 <= MAX_LIFT_VALIDATION_TIERS, Error::<T>::InvalidLiftValidationTiers);
+            ensure!(
+                tiers.iter().all(|tier| Self::are_validation_settings_valid(&tier.settings)),
+                Error::<T>::InvalidLiftValidationTiers
+            );
+            ensure!(
+                tiers.windows(2).all(|pair| pair[0].min_amount < pair[1].min_amount),
+                Error::<T>::InvalidLiftValidationTiers
+            );
+
+            <LiftValidationTiers<T>>::put(&tiers);
+            Self::deposit_event(Event::<T>::LiftValidationTiersUpdated(tiers));
+            Ok(())
+        }
+
+        /// # <weight>
+        /// Keys: P - number of processed events of the archived era
+        ///   DbReads: `NextEraToArchive`, `LatestProcessedEthBlock`: O(1)
+        ///   If an era can be archived:
+        ///     - DbReads: `ProcessedEventsByEra`: O(1)
+        ///     - DbWrites: P * `ProcessedEvents`, `ProcessedEventsByEra`, `NextEraToArchive`: O(P)
+        ///     - Emitted event: `ProcessedEventsArchived`: O(1)
+        ///   DbReads: `ProcessedEventsMigrationCursor`: O(1)
+        ///   While processed event ids are being migrated:
+        ///     - DbReads: M * `ProcessedEvents`, where M is at most `MAX_PROCESSED_EVENTS_MIGRATED_PER_BLOCK`: O(M)
+        ///     - DbWrites: 2 * M * `ProcessedEvents`, `ProcessedEventsMigrationCursor`: O(M)
+        /// Total Complexity: O(P + M)
+        /// # </weight>
+        fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+            return Self::archive_processed_events_if_required()
+                .saturating_add(migrations::migrate_processed_event_ids_if_required::<T>());
+        }
+
         /// Offchain Worker entry point.
         fn offchain_worker(block_number: T::BlockNumber) {
 
@@ -683,7 +1092,7 @@ decl_module! {
             }
             let this_validator = setup_result.expect("We have a validator");
 
-            // Only primary validators can check and process events
+            // Every validator checks the unchecked events assigned to it, only primary validators can process events
             let is_primary = AVN::<T>::is_primary(block_number, &this_validator.account_id);
             if is_primary.is_err() {
                 debug::native::error!("Error checking if validator can check result");
@@ -691,23 +1100,39 @@ decl_module! {
             }
 
             // =============================== Main Logic ===========================
+            Self::try_check_event(block_number, &this_validator);
+
             if is_primary.expect("Already checked for error.") {
-                Self::try_check_event(block_number, &this_validator);
                 Self::try_process_event(block_number, &this_validator);
+                Self::try_scan_ethereum_logs(&this_validator);
             } else {
                 Self::try_validate_event(block_number, &this_validator);
+                Self::try_reverify_event(block_number, &this_validator);
             }
         }
 
          // Note: this "special" function will run during every runtime upgrade. Any complicated migration logic should be done in a
         // separate function so it can be tested properly.
         fn on_runtime_upgrade() -> Weight {
+            let mut consumed_weight: Weight = 0;
+
             if StorageVersion::get() == Releases::Unknown {
                 StorageVersion::put(Releases::V2_0_0);
-                return migrations::migrate_to_multi_nft_contract::<T>()
+                consumed_weight = consumed_weight.saturating_add(migrations::migrate_to_multi_nft_contract::<T>());
+            }
+
+            if StorageVersion::get() == Releases::V2_0_0 {
+                // This migration writes check results with the ethereum block fields, so it also covers V3
+                StorageVersion::put(Releases::V4_0_0);
+                consumed_weight = consumed_weight.saturating_add(migrations::migrate_to_indexed_event_ids::<T>());
+            }
+
+            if StorageVersion::get() == Releases::V3_0_0 {
+                StorageVersion::put(Releases::V4_0_0);
+                consumed_weight = consumed_weight.saturating_add(migrations::migrate_to_eth_block_check_results::<T>());
             }
 
-            return 0;
+            return consumed_weight;
         }
     }
 }
@@ -715,18 +1140,16 @@ decl_module! {
 // implement offchain worker sub-functions
 impl<T: Config> Module<T> {
     fn try_check_event(block_number: T::BlockNumber, validator: &Validator<T::AuthorityId, T::AccountId>) {
-        let event_to_check = Self::get_events_to_check_if_required();
+        let events_to_check = Self::get_events_to_check_if_required(block_number, &validator.account_id);
 
-        if let Some(event_to_check) = event_to_check {
-            debug::native::info!("** Checking events");
+        if !events_to_check.is_empty() {
+            debug::native::info!("** Checking {} events", events_to_check.len());
+        }
 
-            let result = Self::check_event_and_submit_result(
-                block_number,
-                &event_to_check.0,
-                event_to_check.1,
-                validator);
+        for (event_id, ingress_counter, _) in events_to_check {
+            let result = Self::check_event_and_submit_result(block_number, &event_id, ingress_counter, validator);
             if let Err(e) = result {
-                debug::native::error!("Error checking for events: {:#?}", e);
+                debug::native::error!("Error checking event {:?}: {:#?}", event_id, e);
             }
         }
     }
@@ -742,6 +1165,20 @@ impl<T: Config> Module<T> {
         }
     }
 
+    fn try_scan_ethereum_logs(validator: &Validator<T::AuthorityId, T::AccountId>) {
+        let next_eth_block_to_scan = Self::next_eth_block_to_scan();
+        if next_eth_block_to_scan == 0 {
+            return;
+        }
+
+        debug::native::info!("** Scanning ethereum logs");
+
+        let result = Self::scan_ethereum_logs_and_submit(next_eth_block_to_scan, validator);
+        if let Err(e) = result {
+            debug::native::error!("Error scanning ethereum logs: {:#?}", e);
+        }
+    }
+
     fn try_validate_event(block_number: T::BlockNumber, validator: &Validator<T::AuthorityId, T::AccountId>) {
         if let Some((event_to_validate, ingress_counter, _)) = Self::get_next_event_to_validate(&validator.account_id) {
             debug::native::info!("** Validating events");
@@ -752,15 +1189,238 @@ impl<T: Config> Module<T> {
             }
         }
     }
+
+    fn try_reverify_event(block_number: T::BlockNumber, validator: &Validator<T::AuthorityId, T::AccountId>) {
+        if let Some((event_to_reverify, ingress_counter, _)) = Self::get_next_event_to_reverify(block_number, &validator.account_id) {
+            debug::native::info!("** Reverifying events");
+
+            let result = Self::reverify_event(event_to_reverify, ingress_counter, validator);
+            if let Err(e) = result {
+                debug::native::error!("Error reverifying events: {:#?}", e);
+            }
+        }
+    }
 }
 
 impl<T: Config> Module<T> {
 
-    fn is_challenge_successful(validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> bool {
+    fn required_challenge_votes(validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> u32 {
         let required_challenge_votes = (AVN::<T>::active_validators().len() as u32) / Self::quorum_factor();
-        let total_num_of_challenges = Self::challenges(validated.event.event_id.clone()).len() as u32;
+        return cmp::max(validated.min_challenge_votes, required_challenge_votes);
+    }
+
+    // Challenges that carry evidence only count if their evidence agrees with what the majority of them found.
+    // When no evidence has a majority, they all count.
+    fn is_challenge_successful(
+        validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>,
+        outvoted_challengers: &Vec<T::AccountId>) -> bool
+    {
+        let total_num_of_challenges = Self::challenges(validated.event.event_id.clone())
+            .iter()
+            .filter(|challenger| !outvoted_challengers.contains(*challenger))
+            .count() as u32;
+
+        return total_num_of_challenges > Self::required_challenge_votes(validated);
+    }
+
+    // Returns the evidence found by the most challengers, or nothing when different evidences are found by as many challengers
+    fn get_majority_evidence(evidences: &Vec<(T::AccountId, ChallengeReason)>) -> Option<ChallengeReason> {
+        let num_of_supporters = |evidence: &ChallengeReason| evidences.iter().filter(|(_, other)| other == evidence).count();
+        let majority_supporters = evidences.iter().map(|(_, evidence)| num_of_supporters(evidence)).max()?;
+
+        let mut majority_evidences = evidences
+            .iter()
+            .map(|(_, evidence)| evidence)
+            .filter(|evidence| num_of_supporters(*evidence) == majority_supporters);
+        let majority_evidence = majority_evidences.next()?;
+        if majority_evidences.any(|evidence| evidence != majority_evidence) {
+            return None;
+        }
+
+        return Some(majority_evidence.clone());
+    }
+
+    // The challengers whose evidence differs from what the majority of the challengers with evidence found.
+    // Without a majority there is no telling which challengers were wrong, so none of them are outvoted.
+    fn get_outvoted_challengers(event_id: &EthEventId) -> Vec<T::AccountId> {
+        let evidences = Self::challenge_evidences(event_id);
+        let majority_evidence = match Self::get_majority_evidence(&evidences) {
+            Some(majority_evidence) => majority_evidence,
+            None => return vec![],
+        };
+
+        return evidences
+            .into_iter()
+            .filter(|(_, evidence)| *evidence != majority_evidence)
+            .map(|(challenger, _)| challenger)
+            .collect();
+    }
+
+    // A challenge without evidence cannot contradict the check, so it is always accepted
+    fn evidence_contradicts_check(
+        challenge_reason: &ChallengeReason,
+        checked: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> bool
+    {
+        return match challenge_reason {
+            ChallengeReason::IncorrectResultWithEvidence(result) =>
+                (*result == CheckResult::Ok || *result == CheckResult::Invalid) && *result != checked.result,
+            ChallengeReason::IncorrectEventDataWithEvidence(event_data) =>
+                checked.result == CheckResult::Ok && *event_data != checked.event.event_data,
+            _ => true
+        };
+    }
+
+    fn is_block_no_longer_canonical(validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> bool {
+        let num_of_challenges = Self::block_no_longer_canonical_challenges(validated.event.event_id.clone()).len() as u32;
 
-        return total_num_of_challenges > cmp::max(validated.min_challenge_votes, required_challenge_votes);
+        return num_of_challenges > Self::required_challenge_votes(validated);
+    }
+
+    fn get_era(eth_block_number: u64) -> u64 {
+        return eth_block_number / cmp::max(T::ProcessedEventsEthBlockHorizon::get(), 1);
+    }
+
+    // A zero block number means the ethereum block of the event is unknown
+    fn is_in_archived_era(validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> bool {
+        return validated.eth_block_number != 0 && Self::get_era(validated.eth_block_number) < Self::next_era_to_archive();
+    }
+
+    /// Returns whether a processed event was accepted, including the events that are still stored with their old id
+    /// while the migration to indexed event ids is running
+    fn get_processed_event(event_id: &EthEventId) -> Option<bool> {
+        if <ProcessedEvents>::contains_key(event_id) {
+            return Some(Self::processed_events(event_id));
+        }
+
+        return migrations::get_unmigrated_processed_event(event_id);
+    }
+
+    fn add_to_era_if_required(event_id: &EthEventId, eth_block_number: u64) {
+        let era = Self::get_era(eth_block_number);
+        if eth_block_number == 0 || era < Self::next_era_to_archive() {
+            return;
+        }
+
+        <ProcessedEventsByEra>::append(era, event_id.clone());
+        if eth_block_number > Self::latest_processed_eth_block() {
+            <LatestProcessedEthBlock>::put(eth_block_number);
+        }
+    }
+
+    // Archives at most one era per block. An era is archived once the era after it has ended too, so all its events are
+    // older than `ProcessedEventsEthBlockHorizon` and are rejected by the checks.
+    fn archive_processed_events_if_required() -> Weight {
+        let mut consumed_weight = T::DbWeight::get().reads(2);
+
+        let era = Self::next_era_to_archive();
+        let current_era = Self::get_era(Self::latest_processed_eth_block());
+        if era.saturating_add(1) >= current_era {
+            return consumed_weight;
+        }
+
+        let event_ids = <ProcessedEventsByEra>::take(era);
+        for event_id in event_ids.iter() {
+            <ProcessedEvents>::remove(event_id);
+        }
+
+        if !event_ids.is_empty() {
+            Self::deposit_event(Event::<T>::ProcessedEventsArchived(era, event_ids.len() as u32));
+        }
+        <NextEraToArchive>::put(era + 1);
+
+        consumed_weight = consumed_weight.saturating_add(
+            T::DbWeight::get().reads_writes(1, (event_ids.len() as Weight).saturating_add(2)));
+        return consumed_weight;
+    }
+
+    fn are_validation_settings_valid(settings: &EventValidationSettings<T::BlockNumber>) -> bool {
+        return settings.min_eth_block_confirmations >= T::MinEthBlockConfirmation::get() &&
+            settings.challenge_period >= MINIMUM_EVENT_CHALLENGE_PERIOD.into();
+    }
+
+    pub fn get_validation_settings(event_id: &EthEventId, event_data: &EventData) -> EventValidationSettings<T::BlockNumber> {
+        if let EventData::LogLifted(lifted_data) = event_data {
+            let lift_tier = Self::lift_validation_tiers()
+                .into_iter()
+                .rev()
+                .find(|tier| lifted_data.amount >= tier.min_amount);
+            if let Some(lift_tier) = lift_tier {
+                return lift_tier.settings;
+            }
+        }
+
+        return ValidEvents::try_from(&event_id.signature)
+            .and_then(|event_type| Self::event_type_validation_settings(event_type))
+            .unwrap_or_else(|| EventValidationSettings {
+                min_eth_block_confirmations: T::MinEthBlockConfirmation::get(),
+                challenge_period: Self::event_challenge_period(),
+            });
+    }
+
+    // An http error or too few confirmations can be resolved by checking the event again later
+    fn is_inconclusive(result: &CheckResult) -> bool {
+        return *result == CheckResult::HttpErrorCheckingEvent || *result == CheckResult::InsufficientConfirmations;
+    }
+
+    // Returns false, without scheduling anything, once the event has used all its check attempts.
+    // Too few confirmations does not use an attempt: the depth some events need, like large lifts, can take longer to
+    // reach than all the attempts, so the event is checked again until the depth is reached.
+    fn schedule_check_retry(result: &EthEventCheckResult<T::BlockNumber, T::AccountId>, current_block: T::BlockNumber) -> bool {
+        let event_id = &result.event.event_id;
+        let previous_attempts = Self::event_check_retries(event_id).map_or(0, |retry| retry.attempts);
+
+        let (attempts, delay) = if result.result == CheckResult::InsufficientConfirmations {
+            (previous_attempts, T::EventCheckRetryDelay::get())
+        } else {
+            let attempts = previous_attempts.saturating_add(1);
+            if attempts >= T::MaxEventCheckAttempts::get() {
+                return false;
+            }
+
+            (attempts, T::EventCheckRetryDelay::get().saturating_mul(2u32.saturating_pow(attempts - 1).into()))
+        };
+
+        let retry_after_block = current_block.saturating_add(delay);
+        <EventCheckRetries<T>>::insert(event_id, EventCheckRetry { attempts, retry_after_block });
+
+        Self::deposit_event(Event::<T>::EventCheckRetryScheduled(
+            event_id.clone(),
+            result.result.clone(),
+            attempts,
+            retry_after_block
+        ));
+        return true;
+    }
+
+    /// Status of an ethereum event, for the `EthereumEventsApi` runtime api
+    pub fn get_event_status(event_id: &EthEventId) -> EthEventStatusInfo<T::AccountId, T::BlockNumber> {
+        let challenges = Self::challenges(event_id);
+
+        if let Some(position) = Self::unchecked_events().iter().position(|(event, _, _)| event == event_id) {
+            return EthEventStatusInfo { status: EthEventStatus::Unchecked(position as u32), checked_by: None, challenges };
+        }
+
+        let pending_event = Self::events_pending_challenge()
+            .into_iter()
+            .rev()
+            .find(|(checked, _, _)| checked.event.event_id == *event_id);
+        if let Some((checked, _, _)) = pending_event {
+            return EthEventStatusInfo {
+                status: EthEventStatus::PendingChallenge(checked.result, checked.ready_for_processing_after_block),
+                checked_by: Some(checked.checked_by),
+                challenges,
+            };
+        }
+
+        let status = if let Some(event_accepted) = Self::get_processed_event(event_id) {
+            EthEventStatus::Processed(event_accepted)
+        } else if <ResubmittableEvents>::contains_key(event_id) {
+            EthEventStatus::Rejected
+        } else {
+            EthEventStatus::Unknown
+        };
+
+        return EthEventStatusInfo { status, checked_by: None, challenges };
     }
 
     fn get_pending_event_index(event_id: &EthEventId) -> Result<usize, Error<T>> {
@@ -818,20 +1478,64 @@ impl<T: Config> Module<T> {
                     Error::<T>::EventParsingFailed
                 })?;
             return Ok( EventData::LogNftEndBatchListing(event_data) );
+        } else if let Some(descriptor) = Self::event_descriptors(&event_id.signature) {
+            let event_data = descriptor.decode_log(data, topics)
+                .map_err(|e| {
+                    debug::native::warn!("Error decoding T1 Event {:?}: {:#?}", event_id.signature, e);
+                    Error::<T>::EventParsingFailed
+                })?;
+            return Ok( EventData::LogDecoded(event_data) );
         } else {
             return Err(Error::<T>::UnrecognizedEventSignature);
         }
     }
 
-    fn get_events_to_check_if_required() -> Option<(EthEventId, IngressCounter, T::BlockNumber)> {
+    // Returns the events this validator should check in this block. The oldest finalised unchecked events, up to
+    // `MaxEventsCheckedPerBlock`, are shared between the validators so that each one is checked by a single validator.
+    fn get_events_to_check_if_required(block_number: T::BlockNumber, validator_account_id: &T::AccountId)
+        -> Vec<(EthEventId, IngressCounter, T::BlockNumber)>
+    {
         if Self::unchecked_events().is_empty() {
-            return None;
+            return vec![];
         }
 
         return Self::unchecked_events()
             .into_iter()
-            .filter(|e| AVN::<T>::is_block_finalised(e.2))
-            .nth(0);
+            .filter(|(event_id, _, added_at_block)|
+                AVN::<T>::is_block_finalised(*added_at_block) && Self::can_check_event_at(event_id, block_number))
+            .take(T::MaxEventsCheckedPerBlock::get() as usize)
+            .filter(|(_, ingress_counter, _)|
+                Self::is_event_checker(block_number, *ingress_counter, validator_account_id).unwrap_or(false))
+            .collect();
+    }
+
+    fn can_check_event_at(event_id: &EthEventId, block_number: T::BlockNumber) -> bool {
+        return match Self::event_check_retries(event_id) {
+            Some(retry) => block_number > retry.retry_after_block,
+            None => true
+        };
+    }
+
+    // The checker rotates with the block number, so an event is not stuck behind a validator that fails to check it.
+    pub fn get_event_checker(block_number: T::BlockNumber, ingress_counter: IngressCounter)
+        -> Result<T::AccountId, avn_error<T>>
+    {
+        let validators = AVN::<T>::validators();
+        if validators.is_empty() {
+            return Err(avn_error::<T>::NoValidatorsFound);
+        }
+
+        let block_number: u64 = TryInto::<u64>::try_into(block_number)
+            .map_err(|_| avn_error::<T>::ErrorConvertingBlockNumber)?;
+
+        let index = block_number.wrapping_add(ingress_counter) % validators.len() as u64;
+        return Ok(validators[index as usize].account_id.clone());
+    }
+
+    pub fn is_event_checker(block_number: T::BlockNumber, ingress_counter: IngressCounter, account_id: &T::AccountId)
+        -> Result<bool, avn_error<T>>
+    {
+        return Ok(Self::get_event_checker(block_number, ingress_counter)? == *account_id);
     }
 
     fn get_next_event_to_validate(validator_account_id: &T::AccountId) ->
@@ -906,14 +1610,8 @@ impl<T: Config> Module<T> {
         validator: &Validator<T::AuthorityId, T::AccountId>) -> Result<(), Error<T>>
     {
         let result = Self::check_event(block_number, event_id, validator);
-        if result.result == CheckResult::HttpErrorCheckingEvent {
-            debug::native::info!("Http error checking event, skipping check");
-            return Ok(());
-        }
-
-        if result.result == CheckResult::InsufficientConfirmations {
-            debug::native::info!("Event is not old enough, skipping check");
-            return Ok(());
+        if Self::is_inconclusive(&result.result) {
+            debug::native::info!("Inconclusive check ({:?}), the event will be checked again later", result.result);
         }
 
         let signature = validator.key
@@ -924,6 +1622,7 @@ impl<T: Config> Module<T> {
         ).map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
 
         debug::native::info!("Check result submitted successfully");
+        ocw_metrics::increment_counter(OCW_EVENTS_CHECKED_COUNTER_KEY);
         Ok(())
     }
 
@@ -940,24 +1639,111 @@ impl<T: Config> Module<T> {
         }
 
         Self::save_validated_event_in_local_storage(checked.event.event_id.clone())?;
+        ocw_metrics::increment_counter(OCW_EVENTS_CHECKED_COUNTER_KEY);
 
         // Note: Any errors after saving to local storage will mean the event will not be validated again
         let challenge = Self::get_challenge_if_required(checked, validated, validator.account_id.clone());
         if let Some(challenge) = challenge {
-            let signature = validator.key
-                .sign(&(CHALLENGE_EVENT_CONTEXT, &challenge, ingress_counter).encode())
-                .ok_or(Error::<T>::ErrorSigning)?;
             // TODO [TYPE: business logic][PRI: medium][CRITICAL][JIRA: 349]: Allow for this event to be resubmitted if it fails here
-            SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
-                Call::challenge_event(challenge, ingress_counter, signature, validator.clone()).into()
-            ).map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
-
+            Self::submit_challenge(challenge, ingress_counter, validator)?;
             debug::native::info!("Validation result submitted successfully");
         }
 
         Ok(())
     }
 
+    fn submit_challenge(
+        challenge: Challenge<T::AccountId>,
+        ingress_counter: IngressCounter,
+        validator: &Validator<T::AuthorityId, T::AccountId>) -> Result<(), Error<T>>
+    {
+        let signature = validator.key
+            .sign(&(CHALLENGE_EVENT_CONTEXT, &challenge, ingress_counter).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::challenge_event(challenge, ingress_counter, signature, validator.clone()).into()
+        ).map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    fn get_next_event_to_reverify(block_number: T::BlockNumber, validator_account_id: &T::AccountId) ->
+        Option<(EthEventCheckResult<T::BlockNumber, T::AccountId>, IngressCounter, T::BlockNumber)> {
+
+        let reverified_events = StorageValueRef::persistent(REVERIFIED_EVENT_LOCAL_STORAGE)
+            .get::<Vec<EthEventId>>()
+            .unwrap_or(None)
+            .unwrap_or_default();
+
+        return Self::events_pending_challenge()
+            .into_iter()
+            .filter(|(checked, _counter, submitted_at_block)|
+                Self::can_reverify_this_event(block_number, checked, validator_account_id, &reverified_events) &&
+                AVN::<T>::is_block_finalised(*submitted_at_block)
+            )
+            .nth(0);
+    }
+
+    fn can_reverify_this_event(
+        block_number: T::BlockNumber,
+        checked: &EthEventCheckResult<T::BlockNumber, T::AccountId>,
+        validator_account_id: &T::AccountId,
+        reverified_events: &Vec<EthEventId>) -> bool
+    {
+        // Results recorded before the ethereum block was part of the check cannot be reverified
+        if checked.result != CheckResult::Ok || checked.eth_block_hash == H256::zero() { return false; }
+        if checked.checked_by == *validator_account_id { return false; }
+
+        let reverification_window_start =
+            checked.ready_for_processing_after_block.saturating_sub(EVENT_REVERIFICATION_WINDOW.into());
+        if block_number < reverification_window_start || block_number > checked.ready_for_processing_after_block {
+            return false;
+        }
+
+        let event_id = &checked.event.event_id;
+        return !reverified_events.contains(event_id) &&
+            !Self::challenges(event_id).contains(validator_account_id);
+    }
+
+    // Checks, close to the end of the challenge period, that the ethereum block which included the event has not been
+    // reorged out since the event was checked.
+    fn reverify_event(
+        checked: EthEventCheckResult<T::BlockNumber, T::AccountId>,
+        ingress_counter: IngressCounter,
+        validator: &Validator<T::AuthorityId, T::AccountId>) -> Result<(), Error<T>>
+    {
+        let event_id = checked.event.event_id.clone();
+        let block_is_canonical = Self::is_eth_block_canonical(Self::fetch_event(&event_id), &checked.eth_block_hash);
+        if block_is_canonical.is_none() {
+            debug::native::info!("Unable to reverify event, will try again later");
+            return Ok(());
+        }
+
+        Self::save_event_in_local_storage(REVERIFIED_EVENT_LOCAL_STORAGE, event_id.clone())?;
+
+        if block_is_canonical == Some(false) {
+            debug::native::warn!("💔 Ethereum block {:?} of event {:?} is no longer canonical", checked.eth_block_hash, event_id);
+            let challenge = Challenge::new(event_id, ChallengeReason::BlockNoLongerCanonical, validator.account_id.clone());
+            Self::submit_challenge(challenge, ingress_counter, validator)?;
+            debug::native::info!("Reverification result submitted successfully");
+        }
+
+        Ok(())
+    }
+
+    // Returns None if we cannot tell, for example because the ethereum node is unreachable
+    fn is_eth_block_canonical(response_body: Result<Vec<u8>, DispatchError>, eth_block_hash: &H256) -> Option<bool> {
+        match response_body {
+            // The transaction is no longer part of the chain
+            Err(e) if e == avn_error::<T>::EthereumReceiptNotFound.into() => Some(false),
+            Err(_) => None,
+            Ok(body) => core::str::from_utf8(&body).ok()
+                .and_then(|body| json::parse_json(body).ok())
+                .and_then(|response| get_block_hash(&response).ok())
+                .map(|block_hash| block_hash == *eth_block_hash),
+        }
+    }
+
     fn get_challenge_if_required(
         checked: EthEventCheckResult<T::BlockNumber, T::AccountId>,
         validated: EthEventCheckResult<T::BlockNumber, T::AccountId>,
@@ -968,22 +1754,28 @@ impl<T: Config> Module<T> {
             return None;
         }
 
+        if Self::eth_block_has_changed(&checked, &validated) {
+            debug::native::info!("Event was included in a different ethereum block, challenging");
+            return Some(Challenge::new(checked.event.event_id, ChallengeReason::BlockNoLongerCanonical, validator_account_id));
+        }
+
         if (validated.result == checked.result && validated.event.event_data == checked.event.event_data) ||
            (validated.result == CheckResult::Invalid && checked.result == CheckResult::Invalid) {
             debug::native::info!("Validation matches original check, not challenging");
             return None;
         }
 
+        // The challenge carries what this validator found, so it can be compared with what the other challengers found
         let challenge_reason = match validated {
             EthEventCheckResult { result: CheckResult::Ok, .. } => {
                 if checked.result == CheckResult::Ok {
-                    ChallengeReason::IncorrectEventData
+                    ChallengeReason::IncorrectEventDataWithEvidence(validated.event.event_data)
                 } else {
-                    ChallengeReason::IncorrectResult
+                    ChallengeReason::IncorrectResultWithEvidence(CheckResult::Ok)
                 }
             },
             EthEventCheckResult { result: CheckResult::Invalid, .. } if checked.result == CheckResult::Ok =>
-                ChallengeReason::IncorrectResult,
+                ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid),
             _ => ChallengeReason::Unknown // We shouldn't get here but in case we do, set it to Unknown
         };
 
@@ -994,16 +1786,32 @@ impl<T: Config> Module<T> {
         return Some(Challenge::new(checked.event.event_id, challenge_reason, validator_account_id));
     }
 
+    fn eth_block_has_changed(
+        checked: &EthEventCheckResult<T::BlockNumber, T::AccountId>,
+        validated: &EthEventCheckResult<T::BlockNumber, T::AccountId>) -> bool
+    {
+        // A zero hash means the block is unknown, for example because the result was recorded before the block was tracked
+        return checked.result == CheckResult::Ok &&
+            validated.result == CheckResult::Ok &&
+            checked.eth_block_hash != H256::zero() &&
+            validated.eth_block_hash != H256::zero() &&
+            checked.eth_block_hash != validated.eth_block_hash;
+    }
+
     fn save_validated_event_in_local_storage(event_id: EthEventId) -> Result<(), Error<T>> {
-        let storage = StorageValueRef::persistent(VALIDATED_EVENT_LOCAL_STORAGE);
-        let result = storage.mutate(|events: Option<Option<Vec<EthEventId>>>| {
+        return Self::save_event_in_local_storage(VALIDATED_EVENT_LOCAL_STORAGE, event_id);
+    }
+
+    fn save_event_in_local_storage(storage_key: &[u8], event_id: EthEventId) -> Result<(), Error<T>> {
+        let storage = StorageValueRef::persistent(storage_key);
+        let result = storage.mutate(|events: Option<Option<Vec<EthEventId>>>| -> Result<Vec<EthEventId>, ()> {
             match events {
                 Some(Some(mut events)) => {
                     events.push(event_id);
                     Ok(events)
                 },
-                None => Ok(vec![event_id]),
-                _ => Err(()),
+                // Nothing saved yet, or data saved before events were identified by their log index
+                None | Some(None) => Ok(vec![event_id]),
             }
         });
 
@@ -1031,7 +1839,7 @@ impl<T: Config> Module<T> {
     // The outcome of the check must be reported back, even if the check fails
     fn compute_result(
         block_number: T::BlockNumber,
-        response_body: Result<Vec<u8>, http::Error>,
+        response_body: Result<Vec<u8>, DispatchError>,
         event_id: &EthEventId,
         validator_account_id: &T::AccountId) -> EthEventCheckResult<T::BlockNumber, T::AccountId>
     {
@@ -1047,6 +1855,12 @@ impl<T: Config> Module<T> {
 
         // check if the body has been received successfully
         if let Err(e) = response_body {
+            if e == avn_error::<T>::EthereumReceiptNotFound.into() {
+                debug::native::error!("❌ Transaction does not exist on ethereum");
+                return invalid_result;
+            }
+
+            // Anything else (for example an unreachable ethereum node) can succeed if we check again later
             debug::native::error!("Http error fetching event: {:?}", e);
             return EthEventCheckResult::new(
                 ready_after_block,
@@ -1090,15 +1904,14 @@ impl<T: Config> Module<T> {
             return invalid_result;
         }
 
-        let (event, contract_address) = find_event(&events.expect("Checked for error."), event_id.signature)
-            .map_or_else(|| (&JsonValue::Null, H160::zero()), |(e, c)| (e, c));
+        let (event, contract_address) = find_nth_event(
+            &events.expect("Checked for error."),
+            event_id.signature,
+            event_id.log_index,
+            |contract_address| Self::is_event_contract_valid(contract_address, event_id)
+        ).map_or_else(|| (&JsonValue::Null, H160::zero()), |(e, c)| (e, c));
         if event.is_null() || contract_address == H160::zero() {
-            debug::native::error!("❌ Unable to find event");
-            return invalid_result;
-        }
-
-        if Self::is_event_contract_valid(&contract_address, event_id) == false {
-            debug::native::error!("❌ Event contract address {:?} is not recognised", contract_address);
+            debug::native::error!("❌ Unable to find event emitted by a recognised contract");
             return invalid_result;
         }
 
@@ -1131,7 +1944,9 @@ impl<T: Config> Module<T> {
         }
 
         let num_confirmations = num_confirmations.expect("Checked already");
-        if num_confirmations < <T as Config>::MinEthBlockConfirmation::get() {
+        let event_data = event_data.expect("Checked for error.");
+        let min_eth_block_confirmations = Self::get_validation_settings(event_id, &event_data).min_eth_block_confirmations;
+        if num_confirmations < min_eth_block_confirmations {
             debug::native::error!("❌ There aren't enough confirmations for this event. Current confirmations: {:?}", num_confirmations);
             return EthEventCheckResult::new(
                 ready_after_block,
@@ -1143,57 +1958,193 @@ impl<T: Config> Module<T> {
                 Default::default());
         }
 
-        return EthEventCheckResult::new(
+        // We cannot tell if an event this old has been processed already, because its era may have been archived
+        if num_confirmations > T::ProcessedEventsEthBlockHorizon::get() {
+            debug::native::error!("❌ This event is too old to be added. Current confirmations: {:?}", num_confirmations);
+            return invalid_result;
+        }
+
+        let eth_block_hash = get_block_hash(&response);
+        if let Err(e) = eth_block_hash {
+            debug::native::error!("❌ Unable to extract block hash from response: {:?}", e);
+            return invalid_result;
+        }
+
+        let eth_block_number = get_block_number(&response);
+        if let Err(e) = eth_block_number {
+            debug::native::error!("❌ Unable to extract block number from response: {:?}", e);
+            return invalid_result;
+        }
+
+        let mut result = EthEventCheckResult::new(
             ready_after_block,
             CheckResult::Ok,
             event_id,
-            &event_data.expect("Checked for error."),
+            &event_data,
             validator_account_id.clone(),
             block_number,
             Default::default());
+        result.eth_block_hash = eth_block_hash.expect("Checked for error.");
+        result.eth_block_number = eth_block_number.expect("Checked for error.");
+
+        return result;
     }
 
-    fn fetch_event(event_id: &EthEventId) -> Result<Vec<u8>, http::Error> {
+    fn fetch_event(event_id: &EthEventId) -> Result<Vec<u8>, DispatchError> {
         let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
         let external_service_port_number = AVN::<T>::get_external_service_port_number();
+        let auth_secret = AVN::<T>::get_external_service_auth_secret();
 
         let mut url = String::from("http://127.0.0.1:");
         url.push_str(&external_service_port_number);
         url.push_str(&"/eth/events/0x".to_string());
         url.push_str(&hex::encode(&event_id.transaction_hash.as_bytes()));
 
-        let request = http::Request::get(&url);
+        let request = AVN::<T>::with_external_service_auth(http::Request::get(&url), &auth_secret);
         let pending = request
             .deadline(deadline)
             .send()
-            .map_err(|_| http::Error::IoError)?;
+            .map_err(|_| avn_error::<T>::IoError)?;
 
-        let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+        let response = pending.try_wait(deadline)
+            .map_err(|_| avn_error::<T>::DeadlineReached)?
+            .map_err(|_| avn_error::<T>::IoError)?;
 
+        let body = response.body().collect::<Vec<u8>>();
         if response.code != 200 {
-            debug::native::error!("Unexpected status code: {}", response.code);
-            return Err(http::Error::Unknown);
+            return Err(AVN::<T>::get_external_service_error(response.code, &body))?;
+        }
+
+        Ok(body)
+    }
+
+    fn scan_ethereum_logs_and_submit(
+        from_block: u64,
+        validator: &Validator<T::AuthorityId, T::AccountId>) -> Result<(), DispatchError>
+    {
+        let query = EthLogsQuery {
+            addresses: Self::get_contracts_to_scan(),
+            signatures: Self::get_event_signatures_to_scan(),
+            from_block,
+            max_block_range: MAX_ETH_BLOCKS_TO_SCAN,
+            // Logs are only added once they have enough confirmations for any event type
+            min_confirmations: Self::get_max_eth_block_confirmations(),
+        };
+
+        let scan_result = Self::fetch_ethereum_logs(&query)?;
+        if !scan_result.has_scanned_blocks() {
+            // There are no new blocks with enough confirmations yet
+            return Ok(());
+        }
+        ensure!(scan_result.from_block == from_block, Error::<T>::InvalidEthereumLogsScanRange);
+
+        let signature = validator.key
+            .sign(&(SUBMIT_ETHEREUM_LOGS_CONTEXT, &scan_result).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::submit_ethereum_logs(scan_result, validator.clone(), signature).into()
+        ).map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    fn fetch_ethereum_logs(query: &EthLogsQuery) -> Result<EthLogsScanResult, DispatchError> {
+        let response = AVN::<T>::post_data_to_service(String::from("eth/logs"), query.encode())?;
+
+        let scan_result_bytes = hex::decode(&response).map_err(|_| Error::<T>::ErrorDecodingEthereumLogs)?;
+        let scan_result = EthLogsScanResult::decode(&mut &scan_result_bytes[..])
+            .map_err(|_| Error::<T>::ErrorDecodingEthereumLogs)?;
+
+        Ok(scan_result)
+    }
+
+    fn get_contracts_to_scan() -> Vec<H160> {
+        let mut contracts: Vec<H160> = vec![Self::lifting_contract_address(), Self::validator_manager_contract_address()];
+        contracts.extend(<NftT1Contracts>::iter().map(|(contract_address, _)| contract_address));
+        contracts.extend(<EventDescriptors>::iter().map(|(_, descriptor)| descriptor.contract_address));
+        contracts.retain(|contract_address| !contract_address.is_zero());
+        contracts.sort();
+        contracts.dedup();
+
+        return contracts;
+    }
+
+    fn get_max_eth_block_confirmations() -> u64 {
+        let event_type_confirmations = <EventTypeValidationSettings<T>>::iter()
+            .map(|(_, settings)| settings.min_eth_block_confirmations);
+        let lift_tier_confirmations = Self::lift_validation_tiers().into_iter()
+            .map(|tier| tier.settings.min_eth_block_confirmations);
+
+        return event_type_confirmations
+            .chain(lift_tier_confirmations)
+            .fold(T::MinEthBlockConfirmation::get(), cmp::max);
+    }
+
+    fn get_event_signatures_to_scan() -> Vec<H256> {
+        let mut signatures: Vec<H256> = ValidEvents::all().iter().map(|event_type| event_type.signature()).collect();
+        signatures.extend(<EventDescriptors>::iter().map(|(signature, _)| signature));
+
+        return signatures;
+    }
+
+    fn is_next_scan_range(scan_result: &EthLogsScanResult) -> bool {
+        let next_eth_block_to_scan = Self::next_eth_block_to_scan();
+        return next_eth_block_to_scan != 0 && scan_result.from_block == next_eth_block_to_scan && scan_result.has_scanned_blocks();
+    }
+
+    /// Works out the ids of the events of our tier1 contracts found by a scan. All the logs of a transaction are in the same
+    /// block, so they are always part of the same scan and we can number the events of each transaction here.
+    fn get_discovered_event_ids(logs: &Vec<DiscoveredEthLog>) -> Vec<EthEventId> {
+        let mut logs = logs.iter()
+            .filter(|log| !log.transaction_hash.is_zero())
+            .filter(|log| Self::is_contract_valid_for_signature(&log.contract_address, &log.signature))
+            .collect::<Vec<&DiscoveredEthLog>>();
+        logs.sort_by_key(|log| (log.block_number, log.block_log_index));
+        logs.dedup_by_key(|log| (log.block_number, log.block_log_index));
+
+        let mut event_ids: Vec<EthEventId> = Vec::new();
+        for log in logs {
+            let log_index = event_ids.iter()
+                .filter(|event_id| event_id.signature == log.signature && event_id.transaction_hash == log.transaction_hash)
+                .count() as u64;
+
+            event_ids.push(EthEventId {
+                signature: log.signature,
+                transaction_hash: log.transaction_hash,
+                log_index,
+            });
         }
 
-        Ok(response.body().collect::<Vec<u8>>())
+        return event_ids;
+    }
+
+    fn add_discovered_event(event_id: EthEventId, sender: T::AccountId) -> DispatchResult {
+        if let Some(event_type) = ValidEvents::try_from(&event_id.signature) {
+            return Self::add_event(event_type, event_id.transaction_hash, event_id.log_index, sender);
+        }
+
+        let descriptor = Self::event_descriptors(&event_id.signature).ok_or(Error::<T>::UnrecognizedEventSignature)?;
+        Self::add_unchecked_event(&event_id)?;
+        Self::deposit_event(Event::<T>::EthereumEventAdded(event_id, sender, descriptor.contract_address));
+
+        Ok(())
     }
 
     fn event_exists_in_system(event_id: &EthEventId) -> bool {
-        return <ProcessedEvents>::contains_key(&event_id) ||
+        return Self::get_processed_event(&event_id).is_some() ||
                 Self::unchecked_events().iter().any(|(event, _, _)| event == event_id) ||
                 Self::events_pending_challenge().iter().any(|(event, _counter, _)| &event.event.event_id == event_id);
     }
     /// Adds an event: tx_hash must be a nonzero hash
-    fn add_event(event_type: ValidEvents, tx_hash: H256, sender: T::AccountId) -> DispatchResult {
+    fn add_event(event_type: ValidEvents, tx_hash: H256, log_index: u64, sender: T::AccountId) -> DispatchResult {
         let event_id = EthEventId {
             signature: event_type.signature(),
             transaction_hash: tx_hash,
+            log_index,
         };
 
-        ensure!(!Self::event_exists_in_system(&event_id), Error::<T>::DuplicateEvent);
-
-        let ingress_counter = Self::get_next_ingress_counter();
-        <UncheckedEvents<T>>::append((event_id.clone(), ingress_counter, <frame_system::Module<T>>::block_number()));
+        Self::add_unchecked_event(&event_id)?;
 
         if event_type.is_nft_event() {
             Self::deposit_event(Event::<T>::NftEthereumEventAdded(event_id, sender));
@@ -1206,6 +2157,16 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn add_unchecked_event(event_id: &EthEventId) -> DispatchResult {
+        ensure!(!Self::event_exists_in_system(event_id), Error::<T>::DuplicateEvent);
+
+        let ingress_counter = Self::get_next_ingress_counter();
+        <UncheckedEvents<T>>::append((event_id.clone(), ingress_counter, <frame_system::Module<T>>::block_number()));
+        <ResubmittableEvents>::remove(event_id);
+
+        Ok(())
+    }
+
     fn get_contract_address_for_non_nft_event(event_type: &ValidEvents) -> Option<H160> {
         match event_type {
             ValidEvents::AddedValidator => Some(Self::validator_manager_contract_address()),
@@ -1215,20 +2176,32 @@ impl<T: Config> Module<T> {
     }
 
     fn is_event_contract_valid(contract_address: &H160, event_id: &EthEventId) -> bool {
-        let event_type = ValidEvents::try_from(&event_id.signature);
+        return Self::is_contract_valid_for_signature(contract_address, &event_id.signature);
+    }
+
+    fn is_contract_valid_for_signature(contract_address: &H160, signature: &H256) -> bool {
+        let event_type = ValidEvents::try_from(signature);
         if let Some(event_type) = event_type {
-            if event_type.is_nft_event() {
-                return <NftT1Contracts>::contains_key(contract_address);
-            }
+            return Self::is_contract_valid_for_event_type(contract_address, &event_type);
+        }
 
-            let non_nft_contract_address = Self::get_contract_address_for_non_nft_event(&event_type);
-            return non_nft_contract_address.is_some()
-                && non_nft_contract_address.expect("checked for none") == *contract_address;
+        if let Some(descriptor) = Self::event_descriptors(signature) {
+            return descriptor.contract_address == *contract_address;
         }
 
         return false;
     }
 
+    fn is_contract_valid_for_event_type(contract_address: &H160, event_type: &ValidEvents) -> bool {
+        if event_type.is_nft_event() {
+            return <NftT1Contracts>::contains_key(contract_address);
+        }
+
+        let non_nft_contract_address = Self::get_contract_address_for_non_nft_event(event_type);
+        return non_nft_contract_address.is_some()
+            && non_nft_contract_address.expect("checked for none") == *contract_address;
+    }
+
     fn data_signature_is_valid<D: Encode>(
         data: &D,
         validator: &Validator<T::AuthorityId, T::AccountId>,
@@ -1312,14 +2285,19 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
                 return InvalidTransaction::Custom(ERROR_CODE_EVENT_NOT_IN_UNCHECKED).into();
             }
 
-            if !result.event.event_data.is_valid() {
+            let descriptor = Self::event_descriptors(&result.event.event_id.signature);
+            if !result.event.event_data.is_valid(descriptor.as_ref()) {
                 return InvalidTransaction::Custom(ERROR_CODE_INVALID_EVENT_DATA).into();
             }
 
-            if AVN::<T>::is_primary(result.checked_at_block, &result.checked_by)
-                .map_err(|_| InvalidTransaction::Custom(ERROR_CODE_IS_PRIMARY_HAS_ERROR))? == false
+            if Self::is_event_checker(result.checked_at_block, *ingress_counter, &result.checked_by)
+                .map_err(|_| InvalidTransaction::Custom(ERROR_CODE_EVENT_CHECKER_HAS_ERROR))? == false
             {
-                return InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_NOT_PRIMARY).into();
+                return InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_NOT_ASSIGNED_CHECKER).into();
+            }
+
+            if !Self::can_check_event_at(&result.event.event_id, result.checked_at_block) {
+                return InvalidTransaction::Custom(ERROR_CODE_EVENT_WAITING_FOR_RETRY).into();
             }
 
             if validator.account_id != result.checked_by {
@@ -1389,6 +2367,21 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
                 .longevity(64_u64)
                 .propagate(true)
                 .build()
+        } else if let Call::submit_ethereum_logs(scan_result, validator, signature) = call {
+            if !Self::is_next_scan_range(scan_result) {
+                return InvalidTransaction::Custom(ERROR_CODE_INVALID_SCAN_RANGE).into();
+            }
+
+            if !Self::data_signature_is_valid(&(SUBMIT_ETHEREUM_LOGS_CONTEXT, scan_result), &validator, signature) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumEvents")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![("scan", scan_result.from_block).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
         } else {
             return InvalidTransaction::Call.into();
         }
@@ -1397,7 +2390,7 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
 
 impl<T: Config> ProcessedEventsChecker for Module<T> {
     fn check_event(event_id: &EthEventId) -> bool {
-        return <ProcessedEvents>::contains_key(event_id);
+        return Self::get_processed_event(event_id).is_some();
     }
 }
 
@@ -1426,6 +2419,7 @@ enum Releases {
     Unknown,
     V2_0_0,
     V3_0_0,
+    V4_0_0,
 }
 
 impl Default for Releases {
@@ -1436,7 +2430,10 @@ impl Default for Releases {
 
 pub mod migrations {
     use super::*;
-    use frame_support::{Blake2_128Concat, migration::StorageKeyIterator};
+    use frame_support::{
+        Blake2_128Concat, StorageHasher, migration::StorageKeyIterator, storage::{StoragePrefixedMap, unhashed}
+    };
+    use sp_avn_common::event_types::EthEvent;
     pub type MarketplaceId = u32;
 
     pub fn migrate_to_multi_nft_contract<T: Config>() -> frame_support::weights::Weight {
@@ -1458,4 +2455,202 @@ pub mod migrations {
         frame_support::debug::info!("ℹ️  Migrated Ethereum event's NFT contract addresses successfully");
         return consumed_weight;
     }
+
+    /// Bounds the weight the migration of `ProcessedEvents` adds to a block
+    pub const MAX_PROCESSED_EVENTS_MIGRATED_PER_BLOCK: u32 = 500;
+
+    #[derive(Encode, Decode)]
+    struct OldEthEventId {
+        pub signature: H256,
+        pub transaction_hash: H256,
+    }
+
+    impl OldEthEventId {
+        fn upgraded(self) -> EthEventId {
+            // Before this migration only the first event of each type in a transaction could be added
+            EthEventId {
+                signature: self.signature,
+                transaction_hash: self.transaction_hash,
+                log_index: FIRST_LOG_INDEX,
+            }
+        }
+    }
+
+    #[derive(Decode)]
+    struct OldEthEvent {
+        pub event_id: OldEthEventId,
+        pub event_data: EventData,
+    }
+
+    #[derive(Decode)]
+    struct OldEthEventCheckResult<BlockNumber: Member, AccountId: Member> {
+        pub event: OldEthEvent,
+        pub result: CheckResult,
+        pub checked_by: AccountId,
+        pub checked_at_block: BlockNumber,
+        pub ready_for_processing_after_block: BlockNumber,
+        pub min_challenge_votes: u32,
+    }
+
+    impl<BlockNumber: Member, AccountId: Member> OldEthEventCheckResult<BlockNumber, AccountId> {
+        fn upgraded(self) -> EthEventCheckResult<BlockNumber, AccountId> {
+            EthEventCheckResult {
+                event: EthEvent {
+                    event_id: self.event.event_id.upgraded(),
+                    event_data: self.event.event_data,
+                },
+                result: self.result,
+                checked_by: self.checked_by,
+                checked_at_block: self.checked_at_block,
+                ready_for_processing_after_block: self.ready_for_processing_after_block,
+                min_challenge_votes: self.min_challenge_votes,
+                eth_block_number: 0,
+                eth_block_hash: H256::zero(),
+            }
+        }
+    }
+
+    pub fn migrate_to_indexed_event_ids<T: Config>() -> frame_support::weights::Weight {
+        frame_support::debug::RuntimeLogger::init();
+        frame_support::debug::info!("ℹ️  Ethereum events pallet event id migration invoked");
+
+        let mut consumed_weight = T::DbWeight::get().reads_writes(2, 3);
+
+        // `ProcessedEvents` holds every event ever processed, so it is migrated a few entries per block instead.
+        // Until then, the entries that are not migrated yet are still found by their old key.
+        <ProcessedEventsMigrationCursor>::put(<ProcessedEvents as StoragePrefixedMap<bool>>::final_prefix().to_vec());
+
+        // There are only challenges for the events pending challenge, so these few entries are migrated right away.
+        // Collect the old entries first so we don't insert into the map we are draining
+        let challenges = StorageKeyIterator::<OldEthEventId, Vec<T::AccountId>, Blake2_128Concat>::
+            new(b"EthereumEvents", b"Challenges").drain().collect::<Vec<(OldEthEventId, Vec<T::AccountId>)>>();
+        for (old_event_id, challengers) in challenges {
+            <Challenges<T>>::insert(old_event_id.upgraded(), challengers);
+            consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        let _ = <UncheckedEvents<T>>::translate::<Vec<(OldEthEventId, IngressCounter, T::BlockNumber)>, _>(|events| {
+            events.map(|events| events
+                .into_iter()
+                .map(|(event_id, ingress_counter, block_number)| (event_id.upgraded(), ingress_counter, block_number))
+                .collect())
+        });
+
+        let _ = <EventsPendingChallenge<T>>::translate::<
+            Vec<(OldEthEventCheckResult<T::BlockNumber, T::AccountId>, IngressCounter, T::BlockNumber)>, _
+        >(|events| {
+            events.map(|events| events
+                .into_iter()
+                .map(|(check_result, ingress_counter, block_number)| (check_result.upgraded(), ingress_counter, block_number))
+                .collect())
+        });
+
+        frame_support::debug::info!("ℹ️  Migrated Ethereum event ids to include the log index, processed events are migrated in the next blocks");
+        return consumed_weight;
+    }
+
+    /// Moves up to `MAX_PROCESSED_EVENTS_MIGRATED_PER_BLOCK` entries of `ProcessedEvents` to their indexed event id.
+    /// New entries are inserted in the same map while it is being walked, so only the keys of old event ids are moved.
+    pub fn migrate_processed_event_ids_if_required<T: Config>() -> frame_support::weights::Weight {
+        let mut cursor = match <ProcessedEventsMigrationCursor>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut consumed_weight = T::DbWeight::get().reads_writes(1, 1);
+        let prefix = <ProcessedEvents as StoragePrefixedMap<bool>>::final_prefix();
+
+        for _ in 0..MAX_PROCESSED_EVENTS_MIGRATED_PER_BLOCK {
+            let key = match sp_io::storage::next_key(&cursor).filter(|key| key.starts_with(&prefix)) {
+                Some(key) => key,
+                None => {
+                    <ProcessedEventsMigrationCursor>::kill();
+                    frame_support::debug::info!("ℹ️  Migrated processed Ethereum event ids to include the log index successfully");
+                    return consumed_weight;
+                }
+            };
+
+            consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+            if let Some(old_event_id) = old_event_id_from_hashed_key(&key[prefix.len()..]) {
+                if let Some(event_accepted) = unhashed::take::<bool>(&key) {
+                    <ProcessedEvents>::insert(old_event_id.upgraded(), event_accepted);
+                    consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().writes(2));
+                }
+            }
+
+            cursor = key;
+        }
+
+        <ProcessedEventsMigrationCursor>::put(cursor);
+        return consumed_weight;
+    }
+
+    /// Returns whether an event that is not migrated yet was accepted. Old event ids are the indexed ids of the first log.
+    pub fn get_unmigrated_processed_event(event_id: &EthEventId) -> Option<bool> {
+        if event_id.log_index != FIRST_LOG_INDEX || !<ProcessedEventsMigrationCursor>::exists() {
+            return None;
+        }
+
+        let old_event_id = OldEthEventId { signature: event_id.signature, transaction_hash: event_id.transaction_hash };
+        let mut key = <ProcessedEvents as StoragePrefixedMap<bool>>::final_prefix().to_vec();
+        key.extend(Blake2_128Concat::hash(&old_event_id.encode()));
+
+        return unhashed::get::<bool>(&key);
+    }
+
+    // A `Blake2_128Concat` hashed key is the 16 bytes of the hash followed by the encoded key.
+    // Old event ids are 2 hashes long, so they cannot be confused with indexed ids that also encode the log index.
+    fn old_event_id_from_hashed_key(hashed_key: &[u8]) -> Option<OldEthEventId> {
+        const HASH_LENGTH: usize = 16;
+        const OLD_EVENT_ID_LENGTH: usize = 64;
+
+        if hashed_key.len() != HASH_LENGTH + OLD_EVENT_ID_LENGTH {
+            return None;
+        }
+
+        return OldEthEventId::decode(&mut &hashed_key[HASH_LENGTH..]).ok();
+    }
+
+    #[derive(Decode)]
+    struct V3EthEventCheckResult<BlockNumber: Member, AccountId: Member> {
+        pub event: EthEvent,
+        pub result: CheckResult,
+        pub checked_by: AccountId,
+        pub checked_at_block: BlockNumber,
+        pub ready_for_processing_after_block: BlockNumber,
+        pub min_challenge_votes: u32,
+    }
+
+    impl<BlockNumber: Member, AccountId: Member> V3EthEventCheckResult<BlockNumber, AccountId> {
+        fn upgraded(self) -> EthEventCheckResult<BlockNumber, AccountId> {
+            // The ethereum block was not recorded, so these events cannot be reverified
+            EthEventCheckResult {
+                event: self.event,
+                result: self.result,
+                checked_by: self.checked_by,
+                checked_at_block: self.checked_at_block,
+                ready_for_processing_after_block: self.ready_for_processing_after_block,
+                min_challenge_votes: self.min_challenge_votes,
+                eth_block_number: 0,
+                eth_block_hash: H256::zero(),
+            }
+        }
+    }
+
+    pub fn migrate_to_eth_block_check_results<T: Config>() -> frame_support::weights::Weight {
+        frame_support::debug::RuntimeLogger::init();
+        frame_support::debug::info!("ℹ️  Ethereum events pallet check result migration invoked");
+
+        let _ = <EventsPendingChallenge<T>>::translate::<
+            Vec<(V3EthEventCheckResult<T::BlockNumber, T::AccountId>, IngressCounter, T::BlockNumber)>, _
+        >(|events| {
+            events.map(|events| events
+                .into_iter()
+                .map(|(check_result, ingress_counter, block_number)| (check_result.upgraded(), ingress_counter, block_number))
+                .collect())
+        });
+
+        frame_support::debug::info!("ℹ️  Migrated Ethereum event check results to include the ethereum block successfully");
+        return T::DbWeight::get().reads_writes(2, 2);
+    }
 }
\ No newline at end of file
@@ -110,6 +110,10 @@ impl Config for TestRuntime {
     type Event = Event;
     type ProcessedEventHandler = Self;
     type MinEthBlockConfirmation = MinEthBlockConfirmation;
+    type MaxEventsCheckedPerBlock = MaxEventsCheckedPerBlock;
+    type MaxEventCheckAttempts = MaxEventCheckAttempts;
+    type EventCheckRetryDelay = EventCheckRetryDelay;
+    type ProcessedEventsEthBlockHorizon = ProcessedEventsEthBlockHorizon;
     type ReportInvalidEthereumLog = OffenceHandler;
     type Public = AccountId;
     type Signature = Signature;
@@ -130,6 +134,10 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
     pub const MinEthBlockConfirmation: u64 = 2;
+    pub const MaxEventsCheckedPerBlock: u32 = 5;
+    pub const MaxEventCheckAttempts: u32 = 3;
+    pub const EventCheckRetryDelay: u64 = 2;
+    pub const ProcessedEventsEthBlockHorizon: u64 = 100;
 }
 
 impl system::Config for TestRuntime {
@@ -277,6 +285,10 @@ pub static CUSTOM_LIFTING_CONTRACT: H160 = H160(hex!("33333CCCCC44444DDDDD33333C
 #[allow(dead_code)]
 pub const INDEX_RESULT: usize = 2;
 #[allow(dead_code)]
+pub const INDEX_RESULT_BLOCK_HASH: usize = 2;
+#[allow(dead_code)]
+pub const INDEX_RESULT_BLOCK_NUMBER: usize = 3;
+#[allow(dead_code)]
 pub const INDEX_RESULT_LOGS: usize = 9;
 #[allow(dead_code)]
 pub const INDEX_RESULT_STATUS: usize = 10;
@@ -371,6 +383,7 @@ impl EthereumEvents {
         return EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([seed; 32]),
+            log_index: 0,
         };
     }
 
@@ -602,6 +615,7 @@ pub fn create_initial_processed_events() -> Vec<(EthEventId, bool)> {
             EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: H256::from(x),
+                log_index: 0,
             },
             true
         )
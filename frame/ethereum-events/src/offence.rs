@@ -17,6 +17,7 @@ use codec::{Encode, Decode};
 pub enum EthereumLogOffenceType {
     IncorrectValidationResultSubmitted,
     ChallengeAttemptedOnValidResult,
+    IncorrectChallengeEvidenceSubmitted,
 }
 use crate::Event;
 
@@ -0,0 +1,14 @@
+//! Runtime api to query the status of ethereum events, used by the `ethEvents_status` rpc of the node.
+
+use codec::Codec;
+use sp_avn_common::event_types::{EthEventId, EthEventStatusInfo};
+
+sp_api::decl_runtime_apis! {
+    pub trait EthereumEventsApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Status of the ethereum event `event_id`, whether its type is one of the `ValidEvents` or has an event descriptor
+        fn event_status(event_id: EthEventId) -> EthEventStatusInfo<AccountId, BlockNumber>;
+    }
+}
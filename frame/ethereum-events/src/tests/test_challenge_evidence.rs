@@ -0,0 +1,256 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H512, U256};
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const READY_FOR_PROCESSING_AFTER_BLOCK: u64 = 5;
+const MIN_CHALLENGE_VOTES: u32 = 1;
+
+fn event_id() -> EthEventId {
+    return EthereumEvents::get_event_id(1);
+}
+
+fn other_event_data() -> EventData {
+    return EventData::LogAddedValidator(AddedValidatorData {
+        eth_public_key: H512::repeat_byte(1),
+        t2_address: H256::repeat_byte(2),
+        validator_account_id: U256::one(),
+    });
+}
+
+fn add_pending_event(result: CheckResult) {
+    EthereumEvents::insert_to_events_pending_challenge(
+        READY_FOR_PROCESSING_AFTER_BLOCK,
+        result,
+        &event_id(),
+        DEFAULT_INGRESS_COUNTER,
+        &EventData::EmptyEvent,
+        checked_by(),
+        READY_FOR_PROCESSING_AFTER_BLOCK - 1,
+        MIN_CHALLENGE_VOTES
+    );
+}
+
+fn add_challenges(evidences: Vec<(AccountId, ChallengeReason)>) {
+    <Challenges<TestRuntime>>::insert(event_id(), evidences.iter().map(|(challenger, _)| challenger.clone()).collect::<Vec<_>>());
+    <ChallengeEvidences<TestRuntime>>::insert(event_id(), evidences);
+}
+
+fn challenge(challenge_reason: ChallengeReason, challenger: &Validator<AuthorityId, AccountId>) -> DispatchResult {
+    return EthereumEvents::challenge_event(
+        RawOrigin::None.into(),
+        Challenge::new(event_id(), challenge_reason, challenger.account_id.clone()),
+        DEFAULT_INGRESS_COUNTER,
+        TestSignature(0, vec![]),
+        challenger.clone()
+    );
+}
+
+fn process_event() {
+    System::set_block_number(READY_FOR_PROCESSING_AFTER_BLOCK + 1);
+    assert_ok!(EthereumEvents::process_event(
+        RawOrigin::None.into(),
+        event_id(),
+        DEFAULT_INGRESS_COUNTER,
+        EthereumEvents::validators()[0].clone(),
+        TestSignature(0, vec![])
+    ));
+}
+
+fn offence_reported(offence_type: EthereumLogOffenceType, offenders: Vec<AccountId>) -> bool {
+    return EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+        crate::Event::<TestRuntime>::OffenceReported(
+            offence_type,
+            offenders.into_iter().map(|offender| (offender.clone(), offender)).collect()
+        )
+    ));
+}
+
+mod challenge_event {
+    use super::*;
+
+    #[test]
+    fn records_the_evidence_of_the_challenger() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            let challenger = EthereumEvents::validators()[0].clone();
+            let evidence = ChallengeReason::IncorrectEventDataWithEvidence(other_event_data());
+
+            assert_ok!(challenge(evidence.clone(), &challenger));
+
+            assert_eq!(EthereumEvents::challenges(event_id()), vec![challenger.account_id.clone()]);
+            assert_eq!(EthereumEvents::challenge_evidences(event_id()), vec![(challenger.account_id, evidence)]);
+        });
+    }
+
+    #[test]
+    fn does_not_record_evidence_for_challenges_without_evidence() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+
+            assert_ok!(challenge(ChallengeReason::IncorrectResult, &EthereumEvents::validators()[0]));
+
+            assert!(EthereumEvents::challenge_evidences(event_id()).is_empty());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_evidence_matches_the_check() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            let challenger = EthereumEvents::validators()[0].clone();
+
+            assert_noop!(
+                challenge(ChallengeReason::IncorrectResultWithEvidence(CheckResult::Ok), &challenger),
+                Error::<TestRuntime>::InvalidChallengeEvidence
+            );
+            assert_noop!(
+                challenge(ChallengeReason::IncorrectEventDataWithEvidence(EventData::EmptyEvent), &challenger),
+                Error::<TestRuntime>::InvalidChallengeEvidence
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_evidence_is_inconclusive() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+
+            assert_noop!(
+                challenge(
+                    ChallengeReason::IncorrectResultWithEvidence(CheckResult::HttpErrorCheckingEvent),
+                    &EthereumEvents::validators()[0]
+                ),
+                Error::<TestRuntime>::InvalidChallengeEvidence
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_event_data_of_an_invalid_event_is_challenged() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Invalid);
+
+            assert_noop!(
+                challenge(
+                    ChallengeReason::IncorrectEventDataWithEvidence(other_event_data()),
+                    &EthereumEvents::validators()[0]
+                ),
+                Error::<TestRuntime>::InvalidChallengeEvidence
+            );
+        });
+    }
+}
+
+mod process_event {
+    use super::*;
+
+    #[test]
+    fn reports_the_checker_and_the_outvoted_challengers_when_the_majority_disagrees_with_the_check() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            add_challenges(vec![
+                (validator_id_1(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid)),
+                (validator_id_2(), ChallengeReason::IncorrectEventDataWithEvidence(other_event_data())),
+                (validator_id_3(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid)),
+            ]);
+
+            process_event();
+
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::ChallengeSucceeded(event_id(), CheckResult::Ok)
+            )));
+            assert!(offence_reported(EthereumLogOffenceType::IncorrectValidationResultSubmitted, vec![checked_by()]));
+            assert!(offence_reported(EthereumLogOffenceType::IncorrectChallengeEvidenceSubmitted, vec![validator_id_2()]));
+        });
+    }
+
+    #[test]
+    fn does_not_count_outvoted_challengers_towards_a_successful_challenge() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::insert_to_events_pending_challenge(
+                READY_FOR_PROCESSING_AFTER_BLOCK,
+                CheckResult::Ok,
+                &event_id(),
+                DEFAULT_INGRESS_COUNTER,
+                &EventData::EmptyEvent,
+                checked_by(),
+                READY_FOR_PROCESSING_AFTER_BLOCK - 1,
+                2
+            );
+            add_challenges(vec![
+                (validator_id_1(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid)),
+                (validator_id_2(), ChallengeReason::IncorrectEventDataWithEvidence(other_event_data())),
+                (validator_id_3(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid)),
+            ]);
+
+            process_event();
+
+            assert_eq!(EthereumEvents::processed_events(event_id()), true);
+            assert!(offence_reported(
+                EthereumLogOffenceType::ChallengeAttemptedOnValidResult,
+                vec![validator_id_1(), validator_id_2(), validator_id_3()]
+            ));
+        });
+    }
+
+    #[test]
+    fn reports_no_challenger_when_the_evidence_is_tied() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            add_challenges(vec![
+                (validator_id_1(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid)),
+                (validator_id_2(), ChallengeReason::IncorrectEventDataWithEvidence(other_event_data())),
+            ]);
+
+            process_event();
+
+            assert_eq!(EthereumEvents::processed_events(event_id()), false);
+            assert!(offence_reported(EthereumLogOffenceType::IncorrectValidationResultSubmitted, vec![checked_by()]));
+            assert!(!offence_reported(EthereumLogOffenceType::IncorrectChallengeEvidenceSubmitted, vec![validator_id_1()]));
+            assert!(!offence_reported(EthereumLogOffenceType::IncorrectChallengeEvidenceSubmitted, vec![validator_id_2()]));
+        });
+    }
+
+    #[test]
+    fn counts_challenges_without_evidence_with_the_majority() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            add_challenges(vec![(validator_id_1(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid))]);
+            <Challenges<TestRuntime>>::append(event_id(), validator_id_2());
+
+            process_event();
+
+            assert!(offence_reported(EthereumLogOffenceType::IncorrectValidationResultSubmitted, vec![checked_by()]));
+            assert_eq!(EthereumEvents::processed_events(event_id()), false);
+        });
+    }
+
+    #[test]
+    fn removes_the_evidence_of_the_event() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            add_pending_event(CheckResult::Ok);
+            add_challenges(vec![(validator_id_1(), ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid))]);
+
+            process_event();
+
+            assert!(!<ChallengeEvidences<TestRuntime>>::contains_key(event_id()));
+        });
+    }
+}
@@ -188,6 +188,7 @@ fn test_get_event_to_validate_first_good_event() {
         let expected_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([0; 32]), //0 is the first item of the vector
+            log_index: 0,
         };
 
         assert!(expected_event_id == next_event_to_validate.event.event_id);
@@ -211,6 +212,7 @@ fn test_get_event_to_validate_third_good_event() {
         let expected_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([2; 32]), //2 is the zero based index of the vector
+            log_index: 0,
         };
 
         assert!(expected_event_id == next_event_to_validate.event.event_id);
@@ -237,6 +239,7 @@ fn test_get_event_to_validate_mixed() {
         let expected_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([1; 32]),
+            log_index: 0,
         };
         assert_eq!(expected_event_id, next_event_to_validate.event.event_id);
         assert_eq!(counter, 2);
@@ -245,6 +248,7 @@ fn test_get_event_to_validate_mixed() {
         let expected_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([0; 32]),
+            log_index: 0,
         };
         assert!(expected_event_id == next_event_to_validate.event.event_id);
         assert_eq!(counter, 1);
@@ -256,6 +260,7 @@ fn test_get_event_to_validate_mixed() {
         let expected_event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([3; 32]),
+            log_index: 0,
         };
         assert!(expected_event_id == next_event_to_validate.event.event_id);
         assert_eq!(counter, 4);
@@ -311,7 +316,7 @@ fn test_challenge_ok_different_event_data() {
         let challenge = EthereumEvents::get_challenge_if_required(checked, validated, validator_account_id);
 
         assert!(challenge.is_some());
-        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectEventData);
+        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectEventDataWithEvidence(add_validator_data));
     });
 }
 
@@ -365,7 +370,7 @@ fn test_challenge_validation_invalid_check_ok() {
         let challenge = EthereumEvents::get_challenge_if_required(checked, validated, validator_account_id);
 
         assert!(challenge.is_some());
-        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectResult);
+        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid));
     });
 }
 
@@ -383,7 +388,7 @@ fn test_challenge_validation_ok_check_invalid() {
         let challenge = EthereumEvents::get_challenge_if_required(checked, validated, validator_account_id);
 
         assert!(challenge.is_some());
-        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectResult);
+        assert_eq!(challenge.unwrap().challenge_reason, ChallengeReason::IncorrectResultWithEvidence(CheckResult::Ok));
     });
 }
 
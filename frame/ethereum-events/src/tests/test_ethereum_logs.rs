@@ -28,6 +28,7 @@ mod test_add_validator_log {
             let validator_event = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_hash,
+                log_index: 0,
             };
             assert_eq!(EthereumEvents::unchecked_events().len(), 1);
             assert!(EthereumEvents::unchecked_events().contains(&(validator_event.clone(), FIRST_INGRESS_COUNTER, 1)));
@@ -54,12 +55,14 @@ mod test_add_validator_log {
             let validator_event_1 = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_hash,
+                log_index: 0,
             };
 
             assert_ok!(EthereumEvents::add_validator_log(Origin::signed(signer), second_tx_hash));
             let validator_event_2 = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: second_tx_hash,
+                log_index: 0,
             };
 
             return (validator_event_1, validator_event_2);
@@ -127,6 +130,7 @@ mod test_add_validator_log {
                 &EthEventId{
                     signature: ValidEvents::AddedValidator.signature(),
                     transaction_hash: tx_hash.clone(),
+                    log_index: 0,
                 }
                 , DEFAULT_INGRESS_COUNTER
             );
@@ -167,6 +171,7 @@ mod test_add_lift_log {
             let lift_event = EthEventId {
                 signature: ValidEvents::Lifted.signature(),
                 transaction_hash: tx_hash,
+                log_index: 0,
             };
             assert_eq!(EthereumEvents::unchecked_events().len(), 1);
             assert!(EthereumEvents::unchecked_events().contains(&(lift_event.clone(), FIRST_INGRESS_COUNTER, 1)));
@@ -201,6 +206,7 @@ mod test_add_lift_log {
             EthereumEvents::insert_to_unchecked_events( &EthEventId{
                 signature: ValidEvents::Lifted.signature(),
                 transaction_hash: tx_hash.clone(),
+                log_index: 0,
             }
             ,DEFAULT_INGRESS_COUNTER);
             assert_noop!(EthereumEvents::add_lift_log(Origin::signed(Default::default()), tx_hash), Error::<TestRuntime>::DuplicateEvent);
@@ -252,6 +258,7 @@ mod test_add_ethereum_log {
             return EthEventId {
                 signature: self.nft_event_type.signature(),
                 transaction_hash: self.tx_hash,
+                log_index: 0,
             };
         }
 
@@ -260,6 +267,7 @@ mod test_add_ethereum_log {
                 &EthEventId{
                     signature: self.nft_event_type.signature(),
                     transaction_hash: self.tx_hash.clone(),
+                    log_index: 0,
                 }
                 , self.current_ingress_counter
             );
@@ -352,12 +360,14 @@ mod test_add_ethereum_log {
             let validator_event_1 = EthEventId {
                 signature: first_event_type.signature(),
                 transaction_hash: first_tx_hash,
+                log_index: 0,
             };
 
             assert_ok!(EthereumEvents::add_ethereum_log(Origin::signed(signer), second_event_type.clone(), second_tx_hash));
             let validator_event_2 = EthEventId {
                 signature: second_event_type.signature(),
                 transaction_hash: second_tx_hash,
+                log_index: 0,
             };
 
             return (validator_event_1, validator_event_2);
@@ -468,6 +478,7 @@ mod add_event {
             let lift_event = EthEventId {
                 signature: ValidEvents::Lifted.signature(),
                 transaction_hash: tx_lift_hash,
+                log_index: 0,
             };
             // Check that the event is added to unchecked queue
             assert_eq!(EthereumEvents::unchecked_events().len(), 1);
@@ -495,6 +506,7 @@ mod add_event {
             let validator_event = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_validator_hash,
+                log_index: 0,
             };
             // Check that the event is added to unchecked queue
             assert_eq!(EthereumEvents::unchecked_events().len(), 1);
@@ -519,6 +531,7 @@ mod add_event {
             let event_id = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: H256::random(),
+                log_index: 0,
             };
             EthereumEvents::insert_to_unchecked_events(&event_id, DEFAULT_INGRESS_COUNTER);
             assert_noop!(EthereumEvents::add_event(ValidEvents::AddedValidator, event_id.transaction_hash, account_id), Error::<TestRuntime>::DuplicateEvent);
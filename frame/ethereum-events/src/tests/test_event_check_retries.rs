@@ -0,0 +1,231 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use crate::Call;
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned};
+use sp_runtime::{testing::TestSignature, transaction_validity::TransactionValidityError};
+use system::RawOrigin;
+
+const CURRENT_BLOCK: BlockNumber = 10;
+
+fn event_id() -> EthEventId {
+    return EthereumEvents::get_event_id(1);
+}
+
+fn check_result(result: CheckResult, checked_at_block: BlockNumber) -> EthEventCheckResult<BlockNumber, AccountId> {
+    let checked_by = EthereumEvents::get_event_checker(checked_at_block, DEFAULT_INGRESS_COUNTER).unwrap();
+    return EthEventCheckResult::new(0, result, &event_id(), &EventData::EmptyEvent, checked_by, checked_at_block, 0);
+}
+
+fn submit_check_result(result: CheckResult) {
+    let check_result = check_result(result, System::block_number());
+    let validator = EthereumEvents::validators()
+        .into_iter()
+        .find(|validator| validator.account_id == check_result.checked_by)
+        .unwrap();
+
+    assert_ok!(EthereumEvents::submit_checkevent_result(
+        RawOrigin::None.into(),
+        check_result,
+        DEFAULT_INGRESS_COUNTER,
+        TestSignature(0, vec![]),
+        validator
+    ));
+}
+
+fn setup() {
+    System::set_block_number(CURRENT_BLOCK);
+    EthereumEvents::insert_to_unchecked_events(&event_id(), DEFAULT_INGRESS_COUNTER);
+}
+
+mod inconclusive_check_results {
+    use super::*;
+
+    #[test]
+    fn keep_the_event_unchecked_until_it_is_checked_again() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+
+            submit_check_result(CheckResult::HttpErrorCheckingEvent);
+
+            let retry_after_block = CURRENT_BLOCK + EventCheckRetryDelay::get();
+            assert_eq!(EthereumEvents::unchecked_events(), vec![(event_id(), DEFAULT_INGRESS_COUNTER, 0)]);
+            assert!(EthereumEvents::events_pending_challenge().is_empty());
+            assert_eq!(
+                EthereumEvents::event_check_retries(event_id()),
+                Some(EventCheckRetry { attempts: 1, retry_after_block })
+            );
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventCheckRetryScheduled(
+                    event_id(),
+                    CheckResult::HttpErrorCheckingEvent,
+                    1,
+                    retry_after_block
+                )
+            )));
+        });
+    }
+
+    #[test]
+    fn double_the_delay_after_every_attempt() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            submit_check_result(CheckResult::HttpErrorCheckingEvent);
+
+            let next_block = CURRENT_BLOCK + EventCheckRetryDelay::get() + 1;
+            System::set_block_number(next_block);
+            submit_check_result(CheckResult::HttpErrorCheckingEvent);
+
+            assert_eq!(
+                EthereumEvents::event_check_retries(event_id()),
+                Some(EventCheckRetry { attempts: 2, retry_after_block: next_block + 2 * EventCheckRetryDelay::get() })
+            );
+        });
+    }
+
+    #[test]
+    fn reject_the_event_once_all_attempts_are_used() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: MaxEventCheckAttempts::get() - 1, retry_after_block: CURRENT_BLOCK - 1 }
+            );
+
+            submit_check_result(CheckResult::HttpErrorCheckingEvent);
+
+            assert!(EthereumEvents::unchecked_events().is_empty());
+            assert_eq!(EthereumEvents::events_pending_challenge()[0].0.result, CheckResult::HttpErrorCheckingEvent);
+            assert_eq!(EthereumEvents::event_check_retries(event_id()), None);
+        });
+    }
+}
+
+mod insufficient_confirmations {
+    use super::*;
+
+    #[test]
+    fn do_not_use_a_check_attempt() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: 1, retry_after_block: CURRENT_BLOCK - 1 }
+            );
+
+            submit_check_result(CheckResult::InsufficientConfirmations);
+
+            let retry_after_block = CURRENT_BLOCK + EventCheckRetryDelay::get();
+            assert_eq!(
+                EthereumEvents::event_check_retries(event_id()),
+                Some(EventCheckRetry { attempts: 1, retry_after_block })
+            );
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventCheckRetryScheduled(
+                    event_id(),
+                    CheckResult::InsufficientConfirmations,
+                    1,
+                    retry_after_block
+                )
+            )));
+        });
+    }
+
+    #[test]
+    fn keep_the_event_unchecked_after_all_attempts_are_used() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: MaxEventCheckAttempts::get() - 1, retry_after_block: CURRENT_BLOCK - 1 }
+            );
+
+            submit_check_result(CheckResult::InsufficientConfirmations);
+
+            assert_eq!(EthereumEvents::unchecked_events(), vec![(event_id(), DEFAULT_INGRESS_COUNTER, 0)]);
+            assert!(EthereumEvents::events_pending_challenge().is_empty());
+        });
+    }
+}
+
+mod conclusive_check_results {
+    use super::*;
+
+    #[test]
+    fn clear_the_previous_attempts() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: 1, retry_after_block: CURRENT_BLOCK - 1 }
+            );
+
+            submit_check_result(CheckResult::Ok);
+
+            assert!(EthereumEvents::unchecked_events().is_empty());
+            assert_eq!(EthereumEvents::events_pending_challenge()[0].0.result, CheckResult::Ok);
+            assert_eq!(EthereumEvents::event_check_retries(event_id()), None);
+        });
+    }
+}
+
+mod events_waiting_for_a_retry {
+    use super::*;
+
+    #[test]
+    fn are_only_checked_after_their_retry_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: 1, retry_after_block: CURRENT_BLOCK }
+            );
+            let checker = EthereumEvents::get_event_checker(CURRENT_BLOCK, DEFAULT_INGRESS_COUNTER).unwrap();
+            let next_checker = EthereumEvents::get_event_checker(CURRENT_BLOCK + 1, DEFAULT_INGRESS_COUNTER).unwrap();
+
+            assert!(EthereumEvents::get_events_to_check_if_required(CURRENT_BLOCK, &checker).is_empty());
+            assert_eq!(
+                EthereumEvents::get_events_to_check_if_required(CURRENT_BLOCK + 1, &next_checker),
+                vec![(event_id(), DEFAULT_INGRESS_COUNTER, 0)]
+            );
+        });
+    }
+
+    #[test]
+    fn reject_check_results_from_before_their_retry_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            setup();
+            <EventCheckRetries<TestRuntime>>::insert(
+                event_id(),
+                EventCheckRetry { attempts: 1, retry_after_block: CURRENT_BLOCK }
+            );
+            let check_result = check_result(CheckResult::HttpErrorCheckingEvent, CURRENT_BLOCK);
+            let validator = EthereumEvents::validators()
+                .into_iter()
+                .find(|validator| validator.account_id == check_result.checked_by)
+                .unwrap();
+            let transaction_call = Call::submit_checkevent_result(
+                check_result,
+                DEFAULT_INGRESS_COUNTER,
+                TestSignature(0, vec![]),
+                validator
+            );
+
+            assert_noop!(
+                EthereumEvents::validate_unsigned(TransactionSource::Local, &transaction_call),
+                TransactionValidityError::Invalid(InvalidTransaction::Custom(ERROR_CODE_EVENT_WAITING_FOR_RETRY))
+            );
+        });
+    }
+}
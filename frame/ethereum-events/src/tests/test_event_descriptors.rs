@@ -0,0 +1,212 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_avn_common::event_types::{EthAbiParamType, EthAbiToken, DecodedEventData};
+use sp_core::U256;
+use system::RawOrigin;
+
+const DESCRIPTOR_CONTRACT: [u8; 20] = [11; 20];
+const FIRST_ETH_BLOCK_TO_SCAN: u64 = 1000;
+
+// event LogStaked(address indexed staker, uint256 amount);
+fn staked_descriptor() -> EthEventDescriptor {
+    return EthEventDescriptor {
+        signature: H256::from([12; 32]),
+        indexed_params: vec![EthAbiParamType::Address],
+        non_indexed_params: vec![EthAbiParamType::Uint(256)],
+        contract_address: H160::from(DESCRIPTOR_CONTRACT),
+    };
+}
+
+fn staked_event_id() -> EthEventId {
+    return EthEventId {
+        signature: staked_descriptor().signature,
+        transaction_hash: H256::from([13; 32]),
+        log_index: 0,
+    };
+}
+
+fn register_staked_descriptor() {
+    assert_ok!(EthereumEvents::register_event_descriptor(RawOrigin::Root.into(), staked_descriptor()));
+}
+
+mod register_event_descriptor {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_origin_is_root() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+
+            assert_eq!(EthereumEvents::event_descriptors(staked_descriptor().signature), Some(staked_descriptor()));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventDescriptorRegistered(
+                    staked_descriptor().signature,
+                    H160::from(DESCRIPTOR_CONTRACT)
+                )
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_root() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::register_event_descriptor(RawOrigin::Signed(account_id_0()).into(), staked_descriptor()),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_descriptor_is_invalid() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let descriptor = EthEventDescriptor { contract_address: H160::zero(), ..staked_descriptor() };
+
+            assert_noop!(
+                EthereumEvents::register_event_descriptor(RawOrigin::Root.into(), descriptor),
+                Error::<TestRuntime>::InvalidEventDescriptor
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_signature_is_a_built_in_event() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let descriptor = EthEventDescriptor { signature: ValidEvents::Lifted.signature(), ..staked_descriptor() };
+
+            assert_noop!(
+                EthereumEvents::register_event_descriptor(RawOrigin::Root.into(), descriptor),
+                Error::<TestRuntime>::CannotRegisterBuiltInEvent
+            );
+        });
+    }
+}
+
+mod remove_event_descriptor {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_descriptor_is_registered() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+
+            assert_ok!(EthereumEvents::remove_event_descriptor(RawOrigin::Root.into(), staked_descriptor().signature));
+
+            assert_eq!(EthereumEvents::event_descriptors(staked_descriptor().signature), None);
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventDescriptorRemoved(staked_descriptor().signature)
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_descriptor_is_not_registered() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::remove_event_descriptor(RawOrigin::Root.into(), staked_descriptor().signature),
+                Error::<TestRuntime>::EventDescriptorNotFound
+            );
+        });
+    }
+}
+
+mod registered_events {
+    use super::*;
+
+    #[test]
+    fn are_decoded_with_their_descriptor() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+            let mut staker = vec![0; 12];
+            staker.extend(vec![1; 20]);
+            let topics = vec![staked_descriptor().signature.as_bytes().to_vec(), staker];
+            let mut amount = vec![0; 31];
+            amount.push(50);
+
+            let result = EthereumEvents::parse_tier1_event(staked_event_id(), Some(amount), topics);
+
+            assert_eq!(result.ok(), Some(EventData::LogDecoded(DecodedEventData {
+                tokens: vec![EthAbiToken::Address(H160::from([1; 20])), EthAbiToken::Uint(U256::from(50))]
+            })));
+        });
+    }
+
+    #[test]
+    fn are_not_recognised_once_their_descriptor_is_removed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let topics = vec![staked_descriptor().signature.as_bytes().to_vec(), vec![1; 32]];
+
+            let result = EthereumEvents::parse_tier1_event(staked_event_id(), Some(vec![0; 32]), topics);
+
+            assert!(matches!(result, Err(Error::<TestRuntime>::UnrecognizedEventSignature)));
+        });
+    }
+
+    #[test]
+    fn are_only_accepted_from_the_descriptor_contract() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+
+            assert!(EthereumEvents::is_event_contract_valid(&H160::from(DESCRIPTOR_CONTRACT), &staked_event_id()));
+            assert!(!EthereumEvents::is_event_contract_valid(&H160::from(LIFTING_CONTRACT), &staked_event_id()));
+        });
+    }
+
+    #[test]
+    fn are_added_when_found_by_a_log_scan() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = EthereumEvents::validators()[0].clone();
+            let scan_result = EthLogsScanResult {
+                from_block: FIRST_ETH_BLOCK_TO_SCAN,
+                to_block: FIRST_ETH_BLOCK_TO_SCAN,
+                logs: vec![DiscoveredEthLog {
+                    signature: staked_descriptor().signature,
+                    transaction_hash: staked_event_id().transaction_hash,
+                    contract_address: H160::from(DESCRIPTOR_CONTRACT),
+                    block_number: FIRST_ETH_BLOCK_TO_SCAN,
+                    block_log_index: 0,
+                }],
+            };
+            let signature = validator.key.sign(&(SUBMIT_ETHEREUM_LOGS_CONTEXT, &scan_result).encode()).unwrap();
+
+            assert_ok!(EthereumEvents::submit_ethereum_logs(RawOrigin::None.into(), scan_result, validator.clone(), signature));
+
+            assert!(EthereumEvents::unchecked_events().iter().any(|(event_id, _, _)| *event_id == staked_event_id()));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EthereumEventAdded(
+                    staked_event_id(),
+                    validator.account_id,
+                    H160::from(DESCRIPTOR_CONTRACT)
+                )
+            )));
+        });
+    }
+
+    #[test]
+    fn are_included_in_the_log_scan_query() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            register_staked_descriptor();
+
+            assert!(EthereumEvents::get_contracts_to_scan().contains(&H160::from(DESCRIPTOR_CONTRACT)));
+            assert!(EthereumEvents::get_event_signatures_to_scan().contains(&staked_descriptor().signature));
+        });
+    }
+}
@@ -1,8 +1,12 @@
 use hex_literal::hex;
 use simple_json2::json::{JsonValue, NumberValue};
 use sp_core::hash::H256;
-use super::{get_data, get_events, get_topics, find_event, get_value_of, get_status};
-use crate::mock::{INDEX_RESULT, INDEX_RESULT_LOGS, INDEX_RESULT_STATUS, INDEX_EVENT_ADDRESS, INDEX_DATA, INDEX_TOPICS};
+use sp_core::H160;
+use super::{get_data, get_events, get_topics, find_event, find_nth_event, get_value_of, get_status, get_block_hash, get_block_number};
+use crate::mock::{
+    INDEX_RESULT, INDEX_RESULT_BLOCK_HASH, INDEX_RESULT_BLOCK_NUMBER, INDEX_RESULT_LOGS, INDEX_RESULT_STATUS,
+    INDEX_EVENT_ADDRESS, INDEX_DATA, INDEX_TOPICS
+};
 
 struct MockEthEventsResponse {
     pub valid_events_response: Vec<(Vec<char>, JsonValue)>,
@@ -225,6 +229,52 @@ fn get_status_should_return_error_when_status_field_is_invalid() {
     assert!(get_status(&response_with_invalid_result_status).is_err());
 }
 
+#[test]
+fn get_block_hash_should_return_expected_result_when_input_is_valid() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let valid_events_response = JsonValue::Object(mock_events_response.valid_events_response);
+
+    let block_hash = get_block_hash(&valid_events_response);
+
+    assert!(block_hash.is_ok());
+    assert_eq!(block_hash.unwrap(), H256(hex!("5536c9e671fe581fe4ef4631112038297dcdecae163e8724c281ece8ad94c8c3")));
+}
+
+#[test]
+fn get_block_hash_should_return_error_when_block_hash_field_is_invalid() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let mut valid_events_response = mock_events_response.valid_events_response.clone();
+    let mut valid_result_field = mock_events_response.valid_result_field.clone();
+    valid_result_field[INDEX_RESULT_BLOCK_HASH].1 = JsonValue::String("0x5536c9e6".chars().collect());
+    valid_events_response[INDEX_RESULT].1 = JsonValue::Object(valid_result_field);
+    let response_with_short_block_hash = JsonValue::Object(valid_events_response);
+
+    assert!(get_block_hash(&response_with_short_block_hash).is_err());
+}
+
+#[test]
+fn get_block_number_should_return_expected_result_when_input_is_valid() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let valid_events_response = JsonValue::Object(mock_events_response.valid_events_response);
+
+    let block_number = get_block_number(&valid_events_response);
+
+    assert!(block_number.is_ok());
+    assert_eq!(block_number.unwrap(), 46);
+}
+
+#[test]
+fn get_block_number_should_return_error_when_block_number_field_is_invalid() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let mut valid_events_response = mock_events_response.valid_events_response.clone();
+    let mut valid_result_field = mock_events_response.valid_result_field.clone();
+    valid_result_field[INDEX_RESULT_BLOCK_NUMBER].1 = JsonValue::Null;
+    valid_events_response[INDEX_RESULT].1 = JsonValue::Object(valid_result_field);
+    let response_without_block_number = JsonValue::Object(valid_events_response);
+
+    assert!(get_block_number(&response_without_block_number).is_err());
+}
+
 #[test]
 pub fn find_event_should_return_expected_result_event_when_event_values_are_in_lowercase() {
     let mock_events_response = MockEthEventsResponse::setup();
@@ -360,6 +410,66 @@ pub fn find_event_should_return_none_when_event_topics_are_empty_strings() {
     assert!(find_event(&invalid_events, valid_topic).is_none());
 }
 
+fn events_with_repeated_topic(mock_events_response: &MockEthEventsResponse) -> (Vec<JsonValue>, H160) {
+    let other_contract_address = "0x0000000000000000000000000000000000000099";
+    let mut other_contract_event = mock_events_response.valid_event_1.clone();
+    other_contract_event[INDEX_EVENT_ADDRESS].1 = JsonValue::String(other_contract_address.chars().collect());
+
+    let events = vec![
+        JsonValue::Object(mock_events_response.valid_event_1.clone()),
+        JsonValue::Object(mock_events_response.valid_event_2.clone()),
+        JsonValue::Object(other_contract_event),
+        JsonValue::Object(mock_events_response.valid_event_1.clone()),
+    ];
+
+    return (events, H160(hex!("0000000000000000000000000000000000000099")));
+}
+
+#[test]
+pub fn find_nth_event_should_return_the_first_matching_event_when_index_is_zero() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let (events, _) = events_with_repeated_topic(&mock_events_response);
+
+    let (find_event_result, _) = find_nth_event(&events, mock_events_response.valid_topic_1, 0, |_| true).unwrap();
+
+    assert!(std::ptr::eq(find_event_result, &events[0]));
+}
+
+#[test]
+pub fn find_nth_event_should_only_count_events_with_a_matching_topic() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let (events, _) = events_with_repeated_topic(&mock_events_response);
+
+    let (find_event_result, _) = find_nth_event(&events, mock_events_response.valid_topic_1, 1, |_| true).unwrap();
+
+    assert!(std::ptr::eq(find_event_result, &events[2]));
+}
+
+#[test]
+pub fn find_nth_event_should_skip_events_emitted_by_invalid_contracts() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let (events, other_contract_address) = events_with_repeated_topic(&mock_events_response);
+
+    let (find_event_result, contract_address) = find_nth_event(
+        &events,
+        mock_events_response.valid_topic_1,
+        1,
+        |contract_address| *contract_address != other_contract_address
+    ).unwrap();
+
+    assert!(std::ptr::eq(find_event_result, &events[3]));
+    assert_ne!(contract_address, other_contract_address);
+}
+
+#[test]
+pub fn find_nth_event_should_return_none_when_there_are_not_enough_matching_events() {
+    let mock_events_response = MockEthEventsResponse::setup();
+    let (events, _) = events_with_repeated_topic(&mock_events_response);
+
+    assert!(find_nth_event(&events, mock_events_response.valid_topic_1, 3, |_| true).is_none());
+    assert!(find_nth_event(&events, mock_events_response.valid_topic_2, 1, |_| true).is_none());
+}
+
 #[test]
 pub fn get_data_should_return_expected_result_when_input_is_valid() {
     let mock_events_response = MockEthEventsResponse::setup();
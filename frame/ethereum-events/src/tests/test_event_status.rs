@@ -0,0 +1,137 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::assert_ok;
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const READY_FOR_PROCESSING_AFTER_BLOCK: u64 = 5;
+
+fn status_of(seed: u8) -> EthEventStatusInfo<AccountId, BlockNumber> {
+    return EthereumEvents::get_event_status(&EthereumEvents::get_event_id(seed));
+}
+
+fn add_pending_event(seed: u8, result: CheckResult, checked_by: &AccountId) {
+    EthereumEvents::insert_to_events_pending_challenge(
+        READY_FOR_PROCESSING_AFTER_BLOCK,
+        result,
+        &EthereumEvents::get_event_id(seed),
+        DEFAULT_INGRESS_COUNTER,
+        &EventData::EmptyEvent,
+        checked_by.clone(),
+        READY_FOR_PROCESSING_AFTER_BLOCK - 1,
+        0
+    );
+}
+
+fn process_event(seed: u8) {
+    System::set_block_number(READY_FOR_PROCESSING_AFTER_BLOCK + 1);
+    assert_ok!(EthereumEvents::process_event(
+        RawOrigin::None.into(),
+        EthereumEvents::get_event_id(seed),
+        DEFAULT_INGRESS_COUNTER,
+        EthereumEvents::validators()[0].clone(),
+        TestSignature(0, vec![])
+    ));
+}
+
+#[test]
+fn is_unknown_when_the_event_has_not_been_added() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        assert_eq!(
+            status_of(1),
+            EthEventStatusInfo { status: EthEventStatus::Unknown, checked_by: None, challenges: vec![] }
+        );
+    });
+}
+
+#[test]
+fn gives_the_queue_position_of_an_unchecked_event() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        for seed in 1..=3 {
+            EthereumEvents::insert_to_unchecked_events(&EthereumEvents::get_event_id(seed), seed as IngressCounter);
+        }
+
+        assert_eq!(status_of(2).status, EthEventStatus::Unchecked(1));
+    });
+}
+
+#[test]
+fn gives_the_check_result_of_an_event_pending_challenge() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+    ext.execute_with(|| {
+        let checked_by = EthereumEvents::validators()[0].account_id.clone();
+        let challenger = EthereumEvents::validators()[1].account_id.clone();
+        add_pending_event(1, CheckResult::Ok, &checked_by);
+        <Challenges<TestRuntime>>::insert(EthereumEvents::get_event_id(1), vec![challenger.clone()]);
+
+        assert_eq!(
+            status_of(1),
+            EthEventStatusInfo {
+                status: EthEventStatus::PendingChallenge(CheckResult::Ok, READY_FOR_PROCESSING_AFTER_BLOCK),
+                checked_by: Some(checked_by),
+                challenges: vec![challenger],
+            }
+        );
+    });
+}
+
+#[test]
+fn gives_the_outcome_of_a_processed_event() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+    ext.execute_with(|| {
+        let checked_by = EthereumEvents::validators()[0].account_id.clone();
+        add_pending_event(1, CheckResult::Invalid, &checked_by);
+
+        process_event(1);
+
+        assert_eq!(status_of(1).status, EthEventStatus::Processed(false));
+        assert_eq!(EthereumEvents::processed_events(EthereumEvents::get_event_id(1)), false);
+    });
+}
+
+#[test]
+fn is_rejected_until_a_resubmittable_event_is_added_again() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+    ext.execute_with(|| {
+        let checked_by = EthereumEvents::validators()[0].account_id.clone();
+        let challenger = EthereumEvents::validators()[1].account_id.clone();
+        add_pending_event(1, CheckResult::Invalid, &checked_by);
+        <Challenges<TestRuntime>>::insert(EthereumEvents::get_event_id(1), vec![challenger]);
+
+        process_event(1);
+        assert_eq!(status_of(1).status, EthEventStatus::Rejected);
+
+        assert_ok!(EthereumEvents::add_event(ValidEvents::AddedValidator, H256::from([1; 32]), 0, account_id_0()));
+        assert!(matches!(status_of(1).status, EthEventStatus::Unchecked(_)));
+        assert!(!<ResubmittableEvents>::contains_key(EthereumEvents::get_event_id(1)));
+    });
+}
+
+#[test]
+fn tells_apart_the_events_of_a_transaction_by_their_log_index() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        let second_event_id = EthEventId { log_index: 1, ..EthereumEvents::get_event_id(1) };
+        EthereumEvents::insert_to_unchecked_events(&second_event_id, DEFAULT_INGRESS_COUNTER);
+
+        assert_eq!(status_of(1).status, EthEventStatus::Unknown);
+        assert_eq!(EthereumEvents::get_event_status(&second_event_id).status, EthEventStatus::Unchecked(0));
+    });
+}
+
+#[test]
+fn is_given_for_events_with_a_descriptor() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        let event_id = EthEventId { signature: H256::from([7; 32]), ..EthereumEvents::get_event_id(1) };
+        EthereumEvents::insert_to_unchecked_events(&event_id, DEFAULT_INGRESS_COUNTER);
+
+        assert_eq!(EthereumEvents::get_event_status(&event_id).status, EthEventStatus::Unchecked(0));
+    });
+}
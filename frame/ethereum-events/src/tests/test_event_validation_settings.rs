@@ -0,0 +1,348 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::U256;
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const LIFT_CHALLENGE_PERIOD: BlockNumber = 120;
+const LARGE_LIFT_AMOUNT: u128 = 1_000_000;
+
+fn settings(min_eth_block_confirmations: u64, challenge_period: BlockNumber) -> EventValidationSettings<BlockNumber> {
+    return EventValidationSettings { min_eth_block_confirmations, challenge_period };
+}
+
+fn lift_settings() -> EventValidationSettings<BlockNumber> {
+    return settings(12, LIFT_CHALLENGE_PERIOD);
+}
+
+fn large_lift_tier() -> LiftValidationTier<BlockNumber> {
+    return LiftValidationTier { min_amount: LARGE_LIFT_AMOUNT, settings: settings(40, 600) };
+}
+
+fn lift_event_id() -> EthEventId {
+    return EthEventId {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from([5; 32]),
+        log_index: 0,
+    };
+}
+
+fn lift_data(amount: u128) -> EventData {
+    return EventData::LogLifted(LiftedData {
+        token_contract: H160::from([1; 20]),
+        sender_address: H160::from([2; 20]),
+        receiver_address: H256::from([3; 32]),
+        amount,
+        nonce: U256::zero(),
+    });
+}
+
+fn default_settings() -> EventValidationSettings<BlockNumber> {
+    return settings(MinEthBlockConfirmation::get(), EthereumEvents::event_challenge_period());
+}
+
+mod set_event_validation_settings {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_origin_is_root() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(EthereumEvents::set_event_validation_settings(
+                RawOrigin::Root.into(),
+                ValidEvents::Lifted,
+                Some(lift_settings())
+            ));
+
+            assert_eq!(EthereumEvents::event_type_validation_settings(ValidEvents::Lifted), Some(lift_settings()));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventValidationSettingsUpdated(ValidEvents::Lifted, Some(lift_settings()))
+            )));
+        });
+    }
+
+    #[test]
+    fn removes_the_settings_when_none_is_given() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            <EventTypeValidationSettings<TestRuntime>>::insert(ValidEvents::Lifted, lift_settings());
+
+            assert_ok!(EthereumEvents::set_event_validation_settings(RawOrigin::Root.into(), ValidEvents::Lifted, None));
+
+            assert_eq!(EthereumEvents::event_type_validation_settings(ValidEvents::Lifted), None);
+        });
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_root() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::set_event_validation_settings(
+                    RawOrigin::Signed(account_id_0()).into(),
+                    ValidEvents::Lifted,
+                    Some(lift_settings())
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_confirmations_are_below_the_minimum() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::set_event_validation_settings(
+                    RawOrigin::Root.into(),
+                    ValidEvents::Lifted,
+                    Some(settings(MinEthBlockConfirmation::get() - 1, LIFT_CHALLENGE_PERIOD))
+                ),
+                Error::<TestRuntime>::InvalidEventValidationSettings
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_challenge_period_is_below_the_minimum() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::set_event_validation_settings(
+                    RawOrigin::Root.into(),
+                    ValidEvents::Lifted,
+                    Some(settings(12, (MINIMUM_EVENT_CHALLENGE_PERIOD - 1).into()))
+                ),
+                Error::<TestRuntime>::InvalidEventValidationSettings
+            );
+        });
+    }
+}
+
+mod set_lift_validation_tiers {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_tiers_are_sorted_by_amount() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let tiers = vec![
+                LiftValidationTier { min_amount: 1_000, settings: lift_settings() },
+                large_lift_tier(),
+            ];
+
+            assert_ok!(EthereumEvents::set_lift_validation_tiers(RawOrigin::Root.into(), tiers.clone()));
+
+            assert_eq!(EthereumEvents::lift_validation_tiers(), tiers.clone());
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::LiftValidationTiersUpdated(tiers)
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_tiers_are_not_sorted_by_amount() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let tiers = vec![large_lift_tier(), LiftValidationTier { min_amount: 1_000, settings: lift_settings() }];
+
+            assert_noop!(
+                EthereumEvents::set_lift_validation_tiers(RawOrigin::Root.into(), tiers),
+                Error::<TestRuntime>::InvalidLiftValidationTiers
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_there_are_too_many_tiers() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let tiers = (0..=MAX_LIFT_VALIDATION_TIERS)
+                .map(|i| LiftValidationTier { min_amount: i as u128, settings: lift_settings() })
+                .collect();
+
+            assert_noop!(
+                EthereumEvents::set_lift_validation_tiers(RawOrigin::Root.into(), tiers),
+                Error::<TestRuntime>::InvalidLiftValidationTiers
+            );
+        });
+    }
+}
+
+mod get_validation_settings {
+    use super::*;
+
+    #[test]
+    fn uses_the_defaults_when_the_event_type_has_no_settings() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_eq!(EthereumEvents::get_validation_settings(&lift_event_id(), &lift_data(10)), default_settings());
+        });
+    }
+
+    #[test]
+    fn uses_the_settings_of_the_event_type() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            <EventTypeValidationSettings<TestRuntime>>::insert(ValidEvents::Lifted, lift_settings());
+
+            assert_eq!(EthereumEvents::get_validation_settings(&lift_event_id(), &lift_data(10)), lift_settings());
+            assert_eq!(
+                EthereumEvents::get_validation_settings(&EthereumEvents::get_event_id(1), &EventData::EmptyEvent),
+                default_settings()
+            );
+        });
+    }
+
+    #[test]
+    fn uses_the_highest_tier_reached_by_a_lift() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            <EventTypeValidationSettings<TestRuntime>>::insert(ValidEvents::Lifted, lift_settings());
+            <LiftValidationTiers<TestRuntime>>::put(vec![
+                LiftValidationTier { min_amount: 1_000, settings: settings(20, 200) },
+                large_lift_tier(),
+            ]);
+
+            assert_eq!(EthereumEvents::get_validation_settings(&lift_event_id(), &lift_data(999)), lift_settings());
+            assert_eq!(EthereumEvents::get_validation_settings(&lift_event_id(), &lift_data(1_000)), settings(20, 200));
+            assert_eq!(
+                EthereumEvents::get_validation_settings(&lift_event_id(), &lift_data(LARGE_LIFT_AMOUNT + 1)),
+                large_lift_tier().settings
+            );
+        });
+    }
+}
+
+mod event_checks {
+    use super::*;
+
+    #[test]
+    fn need_the_confirmations_of_the_event_type() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::setup_mock_ethereum_contracts_address();
+            let event_id = EthereumEvents::get_event_id(1);
+            <EventTypeValidationSettings<TestRuntime>>::insert(
+                ValidEvents::AddedValidator,
+                settings(GOOD_BLOCK_CONFIRMATIONS + 1, LIFT_CHALLENGE_PERIOD)
+            );
+            let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
+            let event_topics = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
+                              \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303\",
+                              \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303";
+            let json = test_json(
+                &event_id.transaction_hash,
+                &event_id.signature,
+                &EthereumEvents::validator_manager_contract_address(),
+                log_data,
+                event_topics,
+                GOOD_STATUS,
+                GOOD_BLOCK_CONFIRMATIONS
+            );
+
+            let result = EthereumEvents::compute_result(1, Ok(json), &event_id, &account_id_1());
+
+            assert_eq!(result.result, CheckResult::InsufficientConfirmations);
+        });
+    }
+
+    #[test]
+    fn wait_for_the_challenge_period_of_the_event_type() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <EventTypeValidationSettings<TestRuntime>>::insert(ValidEvents::Lifted, lift_settings());
+            let validator = EthereumEvents::validators()[0].clone();
+            let check_result = EthEventCheckResult::new(
+                0,
+                CheckResult::Ok,
+                &lift_event_id(),
+                &lift_data(10),
+                validator.account_id.clone(),
+                1,
+                0
+            );
+            <UncheckedEvents<TestRuntime>>::append(&(lift_event_id(), DEFAULT_INGRESS_COUNTER, 0));
+
+            assert_ok!(EthereumEvents::submit_checkevent_result(
+                RawOrigin::None.into(),
+                check_result,
+                DEFAULT_INGRESS_COUNTER,
+                TestSignature(0, vec![]),
+                validator
+            ));
+
+            let current_block = System::block_number();
+            assert_eq!(
+                EthereumEvents::events_pending_challenge()[0].0.ready_for_processing_after_block,
+                current_block + LIFT_CHALLENGE_PERIOD
+            );
+        });
+    }
+
+    #[test]
+    fn wait_for_the_confirmations_of_a_large_lift_beyond_the_check_attempts() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <LiftValidationTiers<TestRuntime>>::put(vec![large_lift_tier()]);
+            <UncheckedEvents<TestRuntime>>::append(&(lift_event_id(), DEFAULT_INGRESS_COUNTER, 0));
+            let validator = EthereumEvents::validators()[0].clone();
+            let submit_check_result = |result: CheckResult| {
+                let check_result = EthEventCheckResult::new(
+                    0,
+                    result,
+                    &lift_event_id(),
+                    &lift_data(LARGE_LIFT_AMOUNT),
+                    validator.account_id.clone(),
+                    System::block_number(),
+                    0
+                );
+                assert_ok!(EthereumEvents::submit_checkevent_result(
+                    RawOrigin::None.into(),
+                    check_result,
+                    DEFAULT_INGRESS_COUNTER,
+                    TestSignature(0, vec![]),
+                    validator.clone()
+                ));
+            };
+
+            for _ in 0..MaxEventCheckAttempts::get() * 2 {
+                submit_check_result(CheckResult::InsufficientConfirmations);
+                System::set_block_number(System::block_number() + EventCheckRetryDelay::get() + 1);
+            }
+
+            assert_eq!(EthereumEvents::unchecked_events(), vec![(lift_event_id(), DEFAULT_INGRESS_COUNTER, 0)]);
+            assert!(EthereumEvents::events_pending_challenge().is_empty());
+
+            submit_check_result(CheckResult::Ok);
+
+            assert!(EthereumEvents::unchecked_events().is_empty());
+            assert_eq!(EthereumEvents::events_pending_challenge()[0].0.result, CheckResult::Ok);
+            assert_eq!(
+                EthereumEvents::events_pending_challenge()[0].0.ready_for_processing_after_block,
+                System::block_number() + large_lift_tier().settings.challenge_period
+            );
+        });
+    }
+
+    #[test]
+    fn are_only_scanned_once_every_event_type_has_enough_confirmations() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_eq!(EthereumEvents::get_max_eth_block_confirmations(), MinEthBlockConfirmation::get());
+
+            <EventTypeValidationSettings<TestRuntime>>::insert(ValidEvents::Lifted, lift_settings());
+            <LiftValidationTiers<TestRuntime>>::put(vec![large_lift_tier()]);
+
+            assert_eq!(
+                EthereumEvents::get_max_eth_block_confirmations(),
+                large_lift_tier().settings.min_eth_block_confirmations
+            );
+        });
+    }
+}
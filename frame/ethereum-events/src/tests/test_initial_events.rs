@@ -22,6 +22,7 @@ mod initial_lifts {
                     EthEventId {
                         signature: ValidEvents::Lifted.signature(),
                         transaction_hash: H256::from(x),
+                        log_index: 0,
                     },
                     ingress_counter,
                     0
@@ -0,0 +1,420 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use crate::Call;
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned};
+use sp_core::offchain::testing::{OffchainState, PendingRequest};
+use sp_runtime::{testing::{UintAuthorityId, TestSignature}, transaction_validity::TransactionValidityError};
+use system::RawOrigin;
+
+const FIRST_ETH_BLOCK_TO_SCAN: u64 = 1000;
+
+fn lift_log(tx_hash_seed: u8, contract_address: H160, block_log_index: u64) -> DiscoveredEthLog {
+    return DiscoveredEthLog {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from([tx_hash_seed; 32]),
+        contract_address,
+        block_number: FIRST_ETH_BLOCK_TO_SCAN,
+        block_log_index,
+    };
+}
+
+fn lift_event_id(tx_hash_seed: u8, log_index: u64) -> EthEventId {
+    return EthEventId {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from([tx_hash_seed; 32]),
+        log_index,
+    };
+}
+
+fn scan_result(logs: Vec<DiscoveredEthLog>) -> EthLogsScanResult {
+    return EthLogsScanResult {
+        from_block: FIRST_ETH_BLOCK_TO_SCAN,
+        to_block: FIRST_ETH_BLOCK_TO_SCAN + 9,
+        logs,
+    };
+}
+
+fn expected_query() -> EthLogsQuery {
+    let mut addresses = vec![H160::from(VALIDATORS_MANAGER_CONTRACT), H160::from(LIFTING_CONTRACT), H160::from(NFT_CONTRACT)];
+    addresses.sort();
+
+    return EthLogsQuery {
+        addresses,
+        signatures: ValidEvents::all().iter().map(|event_type| event_type.signature()).collect(),
+        from_block: FIRST_ETH_BLOCK_TO_SCAN,
+        max_block_range: MAX_ETH_BLOCKS_TO_SCAN,
+        min_confirmations: MinEthBlockConfirmation::get(),
+    };
+}
+
+fn mock_response_of_get_ethereum_logs(state: &mut OffchainState, query: &EthLogsQuery, scan_result: &EthLogsScanResult) {
+    state.expect_request(PendingRequest {
+        method: "POST".into(),
+        uri: "http://127.0.0.1:2020/eth/logs".into(),
+        response: Some(hex::encode(scan_result.encode()).into_bytes()),
+        headers: vec![],
+        body: query.encode(),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+fn sign_scan_result(validator: &Validator<UintAuthorityId, AccountId>, scan_result: &EthLogsScanResult) -> TestSignature {
+    return validator.key.sign(&(SUBMIT_ETHEREUM_LOGS_CONTEXT, scan_result).encode()).unwrap();
+}
+
+fn validator() -> Validator<UintAuthorityId, AccountId> {
+    return EthereumEvents::validators()[0].clone();
+}
+
+mod scan_ethereum_logs_and_submit {
+    use super::*;
+
+    #[test]
+    fn submits_the_logs_returned_by_the_external_service() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let validator = validator();
+            let scan_result = scan_result(vec![lift_log(1, H160::from(LIFTING_CONTRACT))]);
+            mock_response_of_get_ethereum_logs(&mut offchain_state.write(), &expected_query(), &scan_result);
+
+            assert_ok!(EthereumEvents::scan_ethereum_logs_and_submit(FIRST_ETH_BLOCK_TO_SCAN, &validator));
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(tx.signature, None);
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumEvents(
+                    crate::Call::submit_ethereum_logs(scan_result.clone(), validator.clone(), sign_scan_result(&validator, &scan_result))
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_submit_when_there_are_no_confirmed_blocks_to_scan() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let scan_result = EthLogsScanResult {
+                from_block: FIRST_ETH_BLOCK_TO_SCAN,
+                to_block: FIRST_ETH_BLOCK_TO_SCAN - 1,
+                logs: vec![],
+            };
+            mock_response_of_get_ethereum_logs(&mut offchain_state.write(), &expected_query(), &scan_result);
+
+            assert_ok!(EthereumEvents::scan_ethereum_logs_and_submit(FIRST_ETH_BLOCK_TO_SCAN, &validator()));
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_external_service_scanned_a_different_range() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let mut scan_result = scan_result(vec![]);
+            scan_result.from_block = FIRST_ETH_BLOCK_TO_SCAN + 1;
+            mock_response_of_get_ethereum_logs(&mut offchain_state.write(), &expected_query(), &scan_result);
+
+            assert_eq!(
+                EthereumEvents::scan_ethereum_logs_and_submit(FIRST_ETH_BLOCK_TO_SCAN, &validator()),
+                Err(Error::<TestRuntime>::InvalidEthereumLogsScanRange.into())
+            );
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn does_nothing_when_scanning_is_disabled() {
+        let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            assert_eq!(EthereumEvents::next_eth_block_to_scan(), 0);
+
+            EthereumEvents::try_scan_ethereum_logs(&validator());
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
+
+mod submit_ethereum_logs {
+    use super::*;
+
+    #[test]
+    fn adds_the_new_events_and_moves_the_scan_on() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            let new_lift = lift_event_id(1, 0);
+            let scan_result = scan_result(vec![lift_log(1, H160::from(LIFTING_CONTRACT), 0)]);
+
+            assert_ok!(EthereumEvents::submit_ethereum_logs(
+                RawOrigin::None.into(),
+                scan_result.clone(),
+                validator.clone(),
+                sign_scan_result(&validator, &scan_result)
+            ));
+
+            assert_eq!(EthereumEvents::next_eth_block_to_scan(), scan_result.to_block + 1);
+            assert!(EthereumEvents::unchecked_events().iter().any(|(event_id, _, _)| *event_id == new_lift));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EthereumEventAdded(new_lift, validator.account_id, H160::from(LIFTING_CONTRACT))
+            )));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EthereumLogsScanned(scan_result.from_block, scan_result.to_block, 1)
+            )));
+        });
+    }
+
+    #[test]
+    fn skips_events_already_in_the_system() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            EthereumEvents::insert_to_unchecked_events(&lift_event_id(1, 0), DEFAULT_INGRESS_COUNTER);
+            EthereumEvents::insert_to_processed_events(&lift_event_id(2, 0));
+            let scan_result = scan_result(vec![
+                lift_log(1, H160::from(LIFTING_CONTRACT), 0),
+                lift_log(2, H160::from(LIFTING_CONTRACT), 1),
+            ]);
+
+            assert_ok!(EthereumEvents::submit_ethereum_logs(
+                RawOrigin::None.into(),
+                scan_result.clone(),
+                validator.clone(),
+                sign_scan_result(&validator, &scan_result)
+            ));
+
+            assert_eq!(EthereumEvents::unchecked_events().len(), 1);
+            assert_eq!(EthereumEvents::next_eth_block_to_scan(), scan_result.to_block + 1);
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EthereumLogsScanned(scan_result.from_block, scan_result.to_block, 0)
+            )));
+        });
+    }
+
+    #[test]
+    fn adds_each_event_of_a_transaction_with_its_own_log_index() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            // The logs are numbered in the order they appear in the block, regardless of the order they are submitted in
+            let scan_result = scan_result(vec![
+                lift_log(1, H160::from(LIFTING_CONTRACT), 7),
+                lift_log(2, H160::from(LIFTING_CONTRACT), 5),
+                lift_log(1, H160::from(LIFTING_CONTRACT), 3),
+                lift_log(1, H160::from(LIFTING_CONTRACT), 3),
+            ]);
+
+            assert_ok!(EthereumEvents::submit_ethereum_logs(
+                RawOrigin::None.into(),
+                scan_result.clone(),
+                validator.clone(),
+                sign_scan_result(&validator, &scan_result)
+            ));
+
+            let unchecked_event_ids = EthereumEvents::unchecked_events()
+                .into_iter()
+                .map(|(event_id, _, _)| event_id)
+                .collect::<Vec<EthEventId>>();
+            assert_eq!(unchecked_event_ids, vec![lift_event_id(1, 0), lift_event_id(2, 0), lift_event_id(1, 1)]);
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EthereumLogsScanned(scan_result.from_block, scan_result.to_block, 3)
+            )));
+        });
+    }
+
+    #[test]
+    fn skips_events_emitted_by_other_contracts() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            let scan_result = scan_result(vec![
+                lift_log(1, H160::from(VALIDATORS_MANAGER_CONTRACT), 0),
+                lift_log(2, H160::from([99u8; 20]), 1),
+            ]);
+
+            assert_ok!(EthereumEvents::submit_ethereum_logs(
+                RawOrigin::None.into(),
+                scan_result.clone(),
+                validator.clone(),
+                sign_scan_result(&validator, &scan_result)
+            ));
+
+            assert!(EthereumEvents::unchecked_events().is_empty());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_range_does_not_start_at_the_next_block_to_scan() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN + 1);
+            let validator = validator();
+            let scan_result = scan_result(vec![]);
+
+            assert_noop!(
+                EthereumEvents::submit_ethereum_logs(
+                    RawOrigin::None.into(),
+                    scan_result.clone(),
+                    validator.clone(),
+                    sign_scan_result(&validator, &scan_result)
+                ),
+                Error::<TestRuntime>::InvalidEthereumLogsScanRange
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_scanning_is_disabled() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validator = validator();
+            let mut scan_result = scan_result(vec![]);
+            scan_result.from_block = 0;
+
+            assert_noop!(
+                EthereumEvents::submit_ethereum_logs(
+                    RawOrigin::None.into(),
+                    scan_result.clone(),
+                    validator.clone(),
+                    sign_scan_result(&validator, &scan_result)
+                ),
+                Error::<TestRuntime>::InvalidEthereumLogsScanRange
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_origin_is_signed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            let scan_result = scan_result(vec![]);
+
+            assert_noop!(
+                EthereumEvents::submit_ethereum_logs(
+                    RawOrigin::Signed(validator.account_id.clone()).into(),
+                    scan_result.clone(),
+                    validator.clone(),
+                    sign_scan_result(&validator, &scan_result)
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
+
+mod validate_unsigned_submit_ethereum_logs {
+    use super::*;
+
+    #[test]
+    fn accepts_a_signed_scan_of_the_next_range() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            let scan_result = scan_result(vec![]);
+            let signature = sign_scan_result(&validator, &scan_result);
+
+            assert!(EthereumEvents::validate_unsigned(
+                TransactionSource::Local,
+                &Call::submit_ethereum_logs(scan_result, validator, signature)
+            ).is_ok());
+        });
+    }
+
+    #[test]
+    fn rejects_a_scan_of_another_range() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN + 1);
+            let validator = validator();
+            let scan_result = scan_result(vec![]);
+            let signature = sign_scan_result(&validator, &scan_result);
+
+            assert_noop!(
+                EthereumEvents::validate_unsigned(TransactionSource::Local, &Call::submit_ethereum_logs(scan_result, validator, signature)),
+                TransactionValidityError::Invalid(InvalidTransaction::Custom(ERROR_CODE_INVALID_SCAN_RANGE))
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_an_invalid_signature() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEthBlockToScan>::put(FIRST_ETH_BLOCK_TO_SCAN);
+            let validator = validator();
+            let scan_result = scan_result(vec![]);
+
+            assert_noop!(
+                EthereumEvents::validate_unsigned(
+                    TransactionSource::Local,
+                    &Call::submit_ethereum_logs(scan_result, validator, TestSignature(0, vec![]))
+                ),
+                TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+            );
+        });
+    }
+}
+
+mod set_next_eth_block_to_scan {
+    use super::*;
+
+    #[test]
+    fn root_can_set_the_next_block_to_scan() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_ok!(EthereumEvents::set_next_eth_block_to_scan(RawOrigin::Root.into(), FIRST_ETH_BLOCK_TO_SCAN));
+
+            assert_eq!(EthereumEvents::next_eth_block_to_scan(), FIRST_ETH_BLOCK_TO_SCAN);
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::NextEthBlockToScanUpdated(FIRST_ETH_BLOCK_TO_SCAN)
+            )));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_origin_is_not_root() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(
+                EthereumEvents::set_next_eth_block_to_scan(RawOrigin::Signed(account_id_0()).into(), FIRST_ETH_BLOCK_TO_SCAN),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
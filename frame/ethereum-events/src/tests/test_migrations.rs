@@ -0,0 +1,217 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::{
+    Blake2_128Concat, StorageHasher, storage::migration::put_storage_value, traits::{OnInitialize, OnRuntimeUpgrade}
+};
+use sp_avn_common::event_types::EthEvent;
+
+#[derive(Encode)]
+struct OldEthEventId {
+    pub signature: H256,
+    pub transaction_hash: H256,
+}
+
+fn old_event_id(seed: u8) -> OldEthEventId {
+    return OldEthEventId {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from([seed; 32]),
+    };
+}
+
+fn upgraded_event_id(seed: u8) -> EthEventId {
+    return EthEventId {
+        signature: ValidEvents::Lifted.signature(),
+        transaction_hash: H256::from([seed; 32]),
+        log_index: 0,
+    };
+}
+
+#[derive(Encode)]
+struct V3EthEventCheckResult {
+    pub event: EthEvent,
+    pub result: CheckResult,
+    pub checked_by: AccountId,
+    pub checked_at_block: u64,
+    pub ready_for_processing_after_block: u64,
+    pub min_challenge_votes: u32,
+}
+
+fn put_old_map_entry<V: Encode>(item: &[u8], key: &OldEthEventId, value: V) {
+    put_storage_value(b"EthereumEvents", item, &Blake2_128Concat::hash(&key.encode()), value);
+}
+
+#[test]
+fn genesis_storage_uses_the_latest_version() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        assert!(StorageVersion::get() == Releases::V4_0_0);
+    });
+}
+
+#[test]
+fn runtime_upgrade_adds_the_log_index_to_stored_event_ids() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        StorageVersion::put(Releases::V2_0_0);
+        put_old_map_entry(b"ProcessedEvents", &old_event_id(1), true);
+        put_old_map_entry(b"Challenges", &old_event_id(2), vec![account_id_0()]);
+        put_storage_value(
+            b"EthereumEvents",
+            b"UncheckedEvents",
+            &[],
+            vec![(old_event_id(3), DEFAULT_INGRESS_COUNTER, 0u64)]
+        );
+
+        EthereumEvents::on_runtime_upgrade();
+        EthereumEvents::on_initialize(1);
+
+        assert!(StorageVersion::get() == Releases::V4_0_0);
+        assert_eq!(EthereumEvents::processed_events(upgraded_event_id(1)), true);
+        assert_eq!(EthereumEvents::challenges(upgraded_event_id(2)), vec![account_id_0()]);
+        assert_eq!(EthereumEvents::unchecked_events(), vec![(upgraded_event_id(3), DEFAULT_INGRESS_COUNTER, 0)]);
+        assert_eq!(<ProcessedEvents>::iter().count(), 1);
+        assert_eq!(<Challenges<TestRuntime>>::iter().count(), 1);
+    });
+}
+
+mod processed_events_migration {
+    use super::*;
+
+    fn put_old_processed_events(count: u32) {
+        for seed in 0..count {
+            let old_event_id = OldEthEventId {
+                signature: ValidEvents::Lifted.signature(),
+                transaction_hash: H256::from_low_u64_be(seed as u64 + 1),
+            };
+            put_old_map_entry(b"ProcessedEvents", &old_event_id, true);
+        }
+    }
+
+    #[test]
+    fn does_not_move_processed_events_during_the_runtime_upgrade() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::put(Releases::V2_0_0);
+            put_old_map_entry(b"ProcessedEvents", &old_event_id(1), false);
+
+            EthereumEvents::on_runtime_upgrade();
+
+            assert!(EthereumEvents::processed_events_migration_cursor().is_some());
+            assert!(!<ProcessedEvents>::contains_key(upgraded_event_id(1)));
+        });
+    }
+
+    #[test]
+    fn still_finds_the_processed_events_that_are_not_migrated_yet() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::put(Releases::V2_0_0);
+            put_old_map_entry(b"ProcessedEvents", &old_event_id(1), false);
+
+            EthereumEvents::on_runtime_upgrade();
+
+            assert!(<EthereumEvents as ProcessedEventsChecker>::check_event(&upgraded_event_id(1)));
+            assert!(!<EthereumEvents as ProcessedEventsChecker>::check_event(
+                &EthEventId { log_index: 1, ..upgraded_event_id(1) }
+            ));
+            assert_eq!(
+                EthereumEvents::get_event_status(&upgraded_event_id(1)).status,
+                EthEventStatus::Processed(false)
+            );
+        });
+    }
+
+    #[test]
+    fn moves_a_bounded_number_of_processed_events_per_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let number_of_events = migrations::MAX_PROCESSED_EVENTS_MIGRATED_PER_BLOCK + 1;
+            let initial_processed_events = <ProcessedEvents>::iter().count() as u32;
+            StorageVersion::put(Releases::V2_0_0);
+            put_old_processed_events(number_of_events);
+            EthereumEvents::on_runtime_upgrade();
+
+            EthereumEvents::on_initialize(1);
+            assert!(EthereumEvents::processed_events_migration_cursor().is_some());
+
+            EthereumEvents::on_initialize(2);
+            assert!(EthereumEvents::processed_events_migration_cursor().is_none());
+            assert_eq!(<ProcessedEvents>::iter_keys().count() as u32, initial_processed_events + number_of_events);
+            assert!(<ProcessedEvents>::iter_keys().all(|event_id| event_id.log_index == FIRST_LOG_INDEX));
+        });
+    }
+
+    #[test]
+    fn keeps_the_events_processed_while_it_runs() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            StorageVersion::put(Releases::V2_0_0);
+            put_old_map_entry(b"ProcessedEvents", &old_event_id(1), true);
+            EthereumEvents::on_runtime_upgrade();
+
+            let new_event_id = EthEventId { log_index: 3, ..upgraded_event_id(2) };
+            EthereumEvents::insert_to_processed_events(&new_event_id);
+            EthereumEvents::on_initialize(1);
+
+            assert!(EthereumEvents::processed_events_migration_cursor().is_none());
+            assert_eq!(EthereumEvents::processed_events(upgraded_event_id(1)), true);
+            assert_eq!(EthereumEvents::processed_events(new_event_id), true);
+        });
+    }
+}
+
+#[test]
+fn runtime_upgrade_does_not_migrate_event_ids_twice() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        let event_id = EthEventId { log_index: 1, ..upgraded_event_id(1) };
+        EthereumEvents::insert_to_processed_events(&event_id);
+
+        EthereumEvents::on_runtime_upgrade();
+
+        assert_eq!(EthereumEvents::processed_events(event_id), true);
+        assert_eq!(<ProcessedEvents>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn runtime_upgrade_adds_an_unknown_ethereum_block_to_pending_check_results() {
+    let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+    ext.execute_with(|| {
+        StorageVersion::put(Releases::V3_0_0);
+        let event = EthEvent { event_id: upgraded_event_id(1), event_data: EventData::EmptyEvent };
+        let v3_check_result = V3EthEventCheckResult {
+            event: event.clone(),
+            result: CheckResult::Ok,
+            checked_by: account_id_0(),
+            checked_at_block: 5,
+            ready_for_processing_after_block: 10,
+            min_challenge_votes: 1,
+        };
+        put_storage_value(
+            b"EthereumEvents",
+            b"EventsPendingChallenge",
+            &[],
+            vec![(v3_check_result, DEFAULT_INGRESS_COUNTER, 0u64)]
+        );
+
+        EthereumEvents::on_runtime_upgrade();
+
+        let expected_check_result = EthEventCheckResult::new(
+            10,
+            CheckResult::Ok,
+            &event.event_id,
+            &event.event_data,
+            account_id_0(),
+            5,
+            1
+        );
+        assert!(StorageVersion::get() == Releases::V4_0_0);
+        assert_eq!(EthereumEvents::events_pending_challenge(), vec![(expected_check_result, DEFAULT_INGRESS_COUNTER, 0)]);
+        assert_eq!(EthereumEvents::events_pending_challenge()[0].0.eth_block_hash, H256::zero());
+    });
+}
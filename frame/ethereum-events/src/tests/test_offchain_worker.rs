@@ -70,6 +70,7 @@ pub fn get_valid_added_validator_data() -> AddedValidatorData {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([1; 32]),
+            log_index: 0,
         };
         let event_data = EventData::LogAddedValidator(Self::get_valid_added_validator_data());
         let checked_by = validator.account_id.clone();
@@ -210,6 +211,7 @@ fn submit_checkevent_result_should_return_error_when_event_log_never_been_added(
         let event_id_not_in_unchecked = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::from([9; 32]),
+            log_index: 0,
         };
         let event_check_result_not_in_unchecked = EthEventCheckResult::new(
             mock_data.block_number,
@@ -520,14 +522,14 @@ fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when
 }
 
 #[test]
-fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when_validator_is_not_primary() {
+fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when_validator_is_not_assigned_checker() {
     eth_events_test_with_validators().execute_with(||{
         let mock_data = MockData::setup();
         let block_number = EthereumEvents::validators().len().try_into().unwrap(); // 3 keys in total
         System::set_block_number(block_number);
         let checked_by = EthereumEvents::validators()[2].account_id.clone(); // the 3rd validator
-        let check_result_by_non_primary_validator = EthEventCheckResult::new(
-            block_number, // the 1st validator is primary
+        let check_result_by_non_assigned_validator = EthEventCheckResult::new(
+            block_number, // the 2nd validator is assigned to this ingress counter
             mock_data.check_result,
             &mock_data.event_id,
             &mock_data.event_data,
@@ -536,7 +538,7 @@ fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when
             mock_data.min_challenge_votes
         );
         let transaction_call = Call::submit_checkevent_result(
-            check_result_by_non_primary_validator,
+            check_result_by_non_assigned_validator,
             DEFAULT_INGRESS_COUNTER,
             mock_data.signature,
             mock_data.validator
@@ -545,7 +547,7 @@ fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when
 
         assert_noop!(
             EthereumEvents::validate_unsigned(TransactionSource::Local, &transaction_call),
-            TransactionValidityError::Invalid(InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_NOT_PRIMARY))
+            TransactionValidityError::Invalid(InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_NOT_ASSIGNED_CHECKER))
         );
     });
 }
@@ -554,8 +556,18 @@ fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when
 fn validate_unsigned_with_submit_checkevent_result_call_should_return_error_when_signature_is_invalid() {
     eth_events_test_with_validators().execute_with(||{
         let mock_data = MockData::setup();
+        let checked_at_block = 2; // the 1st validator is assigned to this ingress counter
+        let check_result = EthEventCheckResult::new(
+            mock_data.block_number,
+            mock_data.check_result,
+            &mock_data.event_id,
+            &mock_data.event_data,
+            mock_data.checked_by,
+            checked_at_block,
+            mock_data.min_challenge_votes
+        );
         let transaction_call = Call::submit_checkevent_result(
-            mock_data.eth_event_check_result.clone(),
+            check_result,
             DEFAULT_INGRESS_COUNTER,
             TestSignature(0, vec![]), // Invalid signature
             mock_data.validator
@@ -12,6 +12,7 @@ fn mock_event() -> EthEvent {
         event_id: EthEventId {
             signature: H256::zero(),
             transaction_hash: H256::zero(),
+            log_index: 0,
         },
         event_data: EventData::EmptyEvent,
     }
@@ -63,8 +64,13 @@ fn test_check_event_and_submit_result_ok() {
 }
 
 #[test]
-fn test_check_event_and_submit_result_ok_ignores_not_enough_confirmations() {
-    check_event_and_submit_result(GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS - 1, CheckResult::Unknown, DEFAULT_INGRESS_COUNTER);
+fn test_check_event_and_submit_result_ok_submits_not_enough_confirmations() {
+    check_event_and_submit_result(
+        GOOD_STATUS,
+        GOOD_BLOCK_CONFIRMATIONS - 1,
+        CheckResult::InsufficientConfirmations,
+        DEFAULT_INGRESS_COUNTER
+    );
 }
 
 fn check_event_and_submit_result(status: &str, confirmations: u64, expected_result: CheckResult, ingress_counter: u64) {
@@ -83,6 +89,7 @@ fn check_event_and_submit_result(status: &str, confirmations: u64, expected_resu
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         // TODO: Following line is only needed if we are calling try_check_event; if we call check_event_and_submit_result
         // directly, it is not needed. Which is the intent of this test?
@@ -149,6 +156,7 @@ fn test_check_event_and_submit_result_not_found() {
         let not_existing_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
 
         let expected_response = Some("{{
@@ -261,7 +269,10 @@ fn test_validate_event_ok() {
         let event_result = mock_event_result();
         let expected_counter = DEFAULT_INGRESS_COUNTER;
 
-        let expected_challenge = Challenge::new(event.event_id.clone(), ChallengeReason::IncorrectResult, validator.account_id.clone());
+        let expected_challenge = Challenge::new(
+            event.event_id.clone(),
+            ChallengeReason::IncorrectResultWithEvidence(CheckResult::Invalid),
+            validator.account_id.clone());
         let expected_signature = validator.key.sign(&(CHALLENGE_EVENT_CONTEXT, &expected_challenge, expected_counter).encode()).unwrap();
 
         let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
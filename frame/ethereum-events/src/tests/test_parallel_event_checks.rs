@@ -0,0 +1,155 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use codec::Decode;
+use frame_support::{assert_ok, unsigned::ValidateUnsigned};
+use sp_runtime::transaction_validity::TransactionSource;
+
+const BLOCK_NUMBER: u64 = 2;
+const NUMBER_OF_UNCHECKED_EVENTS: u64 = 7;
+
+fn populate_unchecked_events() {
+    for ingress_counter in 1..=NUMBER_OF_UNCHECKED_EVENTS {
+        EthereumEvents::insert_to_unchecked_events(&EthereumEvents::get_event_id(ingress_counter as u8), ingress_counter);
+    }
+}
+
+fn ingress_counters_to_check(block_number: u64, account_id: &AccountId) -> Vec<IngressCounter> {
+    return EthereumEvents::get_events_to_check_if_required(block_number, account_id)
+        .into_iter()
+        .map(|(_, ingress_counter, _)| ingress_counter)
+        .collect();
+}
+
+mod get_event_checker {
+    use super::*;
+
+    #[test]
+    fn assigns_consecutive_events_to_different_validators() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = EthereumEvents::validators();
+
+            for (index, validator) in validators.iter().enumerate() {
+                let ingress_counter = (validators.len() - BLOCK_NUMBER as usize + index) as IngressCounter;
+                assert_eq!(EthereumEvents::get_event_checker(BLOCK_NUMBER, ingress_counter).ok(), Some(validator.account_id.clone()));
+            }
+        });
+    }
+
+    #[test]
+    fn rotates_every_block() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validators = EthereumEvents::validators();
+            let ingress_counter = DEFAULT_INGRESS_COUNTER;
+
+            let checkers: Vec<AccountId> = (0..validators.len() as u64)
+                .map(|block_number| EthereumEvents::get_event_checker(block_number, ingress_counter).unwrap())
+                .collect();
+
+            for validator in validators {
+                assert!(checkers.contains(&validator.account_id));
+            }
+        });
+    }
+
+    #[test]
+    fn fails_when_there_are_no_validators() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let result = EthereumEvents::get_event_checker(BLOCK_NUMBER, DEFAULT_INGRESS_COUNTER);
+
+            assert!(matches!(result, Err(avn_error::<TestRuntime>::NoValidatorsFound)));
+        });
+    }
+}
+
+mod get_events_to_check_if_required {
+    use super::*;
+
+    #[test]
+    fn gives_each_validator_a_disjoint_share_of_the_events() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            populate_unchecked_events();
+
+            let mut all_ingress_counters: Vec<IngressCounter> = EthereumEvents::validators()
+                .iter()
+                .flat_map(|validator| ingress_counters_to_check(BLOCK_NUMBER, &validator.account_id))
+                .collect();
+            all_ingress_counters.sort();
+
+            let max_events_checked = MaxEventsCheckedPerBlock::get() as u64;
+            assert_eq!(all_ingress_counters, (1..=max_events_checked).collect::<Vec<IngressCounter>>());
+        });
+    }
+
+    #[test]
+    fn only_includes_the_oldest_events_up_to_the_cap() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            populate_unchecked_events();
+            let validator = &EthereumEvents::validators()[0];
+
+            // Events 1, 4 and 7 are assigned to the 1st validator, but 7 is over the cap
+            assert_eq!(ingress_counters_to_check(BLOCK_NUMBER, &validator.account_id), vec![1, 4]);
+        });
+    }
+
+    #[test]
+    fn is_empty_when_there_are_no_validators() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            populate_unchecked_events();
+
+            assert!(EthereumEvents::get_events_to_check_if_required(BLOCK_NUMBER, &account_id_0()).is_empty());
+        });
+    }
+}
+
+mod try_check_event {
+    use super::*;
+
+    #[test]
+    fn submits_a_valid_result_for_every_assigned_event() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let validator = keys_setup_return_good_validator();
+            populate_unchecked_events();
+            let assigned_ingress_counters = ingress_counters_to_check(BLOCK_NUMBER, &validator.account_id);
+            for ingress_counter in assigned_ingress_counters.iter() {
+                let event_id = EthereumEvents::get_event_id(*ingress_counter as u8);
+                simulate_http_response(&offchain_state, &event_id, GOOD_STATUS, GOOD_BLOCK_CONFIRMATIONS);
+            }
+
+            EthereumEvents::try_check_event(BLOCK_NUMBER, &validator);
+
+            let submitted_ingress_counters: Vec<IngressCounter> = pool_state.write().transactions
+                .drain(..)
+                .map(|tx| {
+                    let tx = Extrinsic::decode(&mut &*tx).unwrap();
+                    match tx.call {
+                        mock::Call::EthereumEvents(inner_tx) => {
+                            assert_ok!(EthereumEvents::validate_unsigned(TransactionSource::Local, &inner_tx));
+                            match inner_tx {
+                                crate::Call::submit_checkevent_result(_, ingress_counter, _, _) => ingress_counter,
+                                _ => unreachable!()
+                            }
+                        },
+                        _ => unreachable!()
+                    }
+                })
+                .collect();
+            assert_eq!(submitted_ingress_counters, assigned_ingress_counters);
+        });
+    }
+}
@@ -22,6 +22,7 @@ fn test_parse_tier1_event_valid_case_added_validator(){
         let validator_event_to_parse = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         let ok_result = EthereumEvents::parse_tier1_event(validator_event_to_parse, Some(data), topics);
         assert!(ok_result.is_ok(), "Parse of valid tier1 event failed");
@@ -43,6 +44,7 @@ fn test_parse_tier1_event_valid_case_lifted(){
         let validator_event_to_parse = EthEventId {
             signature: ValidEvents::Lifted.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
 
         let ok_result = EthereumEvents::parse_tier1_event(validator_event_to_parse, Some(data), topics);
@@ -58,6 +60,7 @@ fn test_parse_tier1_event_invalid_signature(){
         let lift_event_to_parse = EthEventId {
             signature: H256::zero(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         // TODO [TYPE: test][PRI: medium]: Error::<TestRuntime>::UnrecognizedEventSignature
         assert!(EthereumEvents::parse_tier1_event(lift_event_to_parse, None, Vec::<Vec<u8>>::new()).is_err(),
@@ -78,6 +81,7 @@ mod parse_nft_mint_log {
             EthEventId {
                 signature: ValidEvents::NftMint.signature(),
                 transaction_hash: H256::from([5u8;32]),
+                log_index: 0,
             }
         }
 
@@ -175,6 +179,7 @@ mod parse_nft_transfer_to_log {
             EthEventId {
                 signature: ValidEvents::NftTransferTo.signature(),
                 transaction_hash: H256::from([5u8;32]),
+                log_index: 0,
             }
         }
 
@@ -44,6 +44,7 @@ mod process_event {
             let event_id = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: H256::from([1; 32]),
+                log_index: 0,
             };
             let validator = EthereumEvents::validators()[0].clone();
             let checked_by = validator.account_id.clone();
@@ -1238,7 +1239,7 @@ mod process_event {
 
         setup_preconditions(&context);
         add_challenge(&context);
-        assert_eq!(true, EthereumEvents::is_challenge_successful(&context.check_result));
+        assert_eq!(true, EthereumEvents::is_challenge_successful(&context.check_result, &vec![]));
 
         return context;
     }
@@ -1248,7 +1249,7 @@ mod process_event {
 
         setup_preconditions(&context);
         add_challenge(&context);
-        assert_eq!(false, EthereumEvents::is_challenge_successful(&context.check_result));
+        assert_eq!(false, EthereumEvents::is_challenge_successful(&context.check_result, &vec![]));
 
         return context;
     }
@@ -1257,7 +1258,7 @@ mod process_event {
         let context = Context::custom_event_check_result(1, check_result);
 
         setup_preconditions(&context);
-        assert!(!EthereumEvents::is_challenge_successful(&context.check_result));
+        assert!(!EthereumEvents::is_challenge_successful(&context.check_result, &vec![]));
 
         return context;
     }
@@ -0,0 +1,190 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use frame_support::{assert_ok, traits::OnInitialize};
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const READY_FOR_PROCESSING_AFTER_BLOCK: u64 = 5;
+
+fn horizon() -> u64 {
+    return ProcessedEventsEthBlockHorizon::get();
+}
+
+fn add_processed_events(era: u64, seeds: Vec<u8>) -> Vec<EthEventId> {
+    let event_ids: Vec<EthEventId> = seeds.into_iter().map(|seed| EthereumEvents::get_event_id(seed)).collect();
+    for event_id in event_ids.iter() {
+        <ProcessedEvents>::insert(event_id, true);
+    }
+    <ProcessedEventsByEra>::insert(era, event_ids.clone());
+
+    return event_ids;
+}
+
+fn process_event_checked_at_eth_block(seed: u8, eth_block_number: u64) {
+    let mut check_result = EthEventCheckResult::new(
+        READY_FOR_PROCESSING_AFTER_BLOCK,
+        CheckResult::Ok,
+        &EthereumEvents::get_event_id(seed),
+        &EventData::EmptyEvent,
+        checked_by(),
+        READY_FOR_PROCESSING_AFTER_BLOCK - 1,
+        0);
+    check_result.eth_block_number = eth_block_number;
+    <EventsPendingChallenge<TestRuntime>>::append((check_result, DEFAULT_INGRESS_COUNTER, 0));
+
+    System::set_block_number(READY_FOR_PROCESSING_AFTER_BLOCK + 1);
+    assert_ok!(EthereumEvents::process_event(
+        RawOrigin::None.into(),
+        EthereumEvents::get_event_id(seed),
+        DEFAULT_INGRESS_COUNTER,
+        EthereumEvents::validators()[0].clone(),
+        TestSignature(0, vec![])
+    ));
+}
+
+mod process_event {
+    use super::*;
+
+    #[test]
+    fn adds_the_event_to_the_era_of_its_ethereum_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let eth_block_number = 2 * horizon() + 1;
+
+            process_event_checked_at_eth_block(1, eth_block_number);
+
+            assert_eq!(EthereumEvents::processed_events_by_era(2), vec![EthereumEvents::get_event_id(1)]);
+            assert_eq!(EthereumEvents::latest_processed_eth_block(), eth_block_number);
+        });
+    }
+
+    #[test]
+    fn does_not_archive_events_whose_ethereum_block_is_unknown() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            process_event_checked_at_eth_block(1, 0);
+
+            assert!(EthereumEvents::processed_events_by_era(0).is_empty());
+            assert_eq!(EthereumEvents::processed_events(EthereumEvents::get_event_id(1)), true);
+        });
+    }
+
+    #[test]
+    fn rejects_an_event_of_an_archived_era_without_recording_it() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            <NextEraToArchive>::put(2);
+
+            process_event_checked_at_eth_block(1, horizon() + 1);
+
+            let event_id = EthereumEvents::get_event_id(1);
+            let validator = EthereumEvents::validators()[0].account_id.clone();
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventProcessed(event_id, validator, true)
+            )));
+            assert!(EthereumEvents::events_pending_challenge().is_empty());
+            assert!(!<ProcessedEvents>::contains_key(EthereumEvents::get_event_id(1)));
+            assert!(EthereumEvents::processed_events_by_era(1).is_empty());
+        });
+    }
+}
+
+mod on_initialize {
+    use super::*;
+
+    #[test]
+    fn archives_an_era_once_the_era_after_it_has_ended() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            let event_ids = add_processed_events(0, vec![1, 2, 3]);
+            <LatestProcessedEthBlock>::put(2 * horizon());
+
+            EthereumEvents::on_initialize(1);
+
+            assert!(event_ids.iter().all(|event_id| !<ProcessedEvents>::contains_key(event_id)));
+            assert!(!<ProcessedEventsByEra>::contains_key(0));
+            assert_eq!(EthereumEvents::next_era_to_archive(), 1);
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::ProcessedEventsArchived(0, 3)
+            )));
+        });
+    }
+
+    #[test]
+    fn does_not_archive_an_era_while_the_era_after_it_has_not_ended() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let event_ids = add_processed_events(0, vec![1]);
+            <LatestProcessedEthBlock>::put(2 * horizon() - 1);
+
+            EthereumEvents::on_initialize(1);
+
+            assert!(<ProcessedEvents>::contains_key(&event_ids[0]));
+            assert_eq!(EthereumEvents::processed_events_by_era(0), event_ids);
+            assert_eq!(EthereumEvents::next_era_to_archive(), 0);
+        });
+    }
+
+    #[test]
+    fn archives_one_era_per_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            add_processed_events(0, vec![1]);
+            let era_1_event_ids = add_processed_events(1, vec![2]);
+            <LatestProcessedEthBlock>::put(3 * horizon());
+
+            EthereumEvents::on_initialize(1);
+            assert_eq!(EthereumEvents::next_era_to_archive(), 1);
+            assert!(<ProcessedEvents>::contains_key(&era_1_event_ids[0]));
+
+            EthereumEvents::on_initialize(2);
+            assert_eq!(EthereumEvents::next_era_to_archive(), 2);
+            assert!(!<ProcessedEvents>::contains_key(&era_1_event_ids[0]));
+        });
+    }
+
+    #[test]
+    fn skips_eras_without_events() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            <LatestProcessedEthBlock>::put(2 * horizon());
+
+            EthereumEvents::on_initialize(1);
+
+            assert_eq!(EthereumEvents::next_era_to_archive(), 1);
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                mock::Event::pallet_ethereum_events(crate::Event::<TestRuntime>::ProcessedEventsArchived(_, _))
+            )));
+        });
+    }
+}
+
+mod check_event {
+    use super::*;
+
+    #[test]
+    fn rejects_an_event_older_than_the_horizon() {
+        let (mut ext, _pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let validator = keys_setup_return_good_validator();
+            let event_id = EthereumEvents::get_event_id(1);
+            simulate_http_response(&offchain_state, &event_id, GOOD_STATUS, horizon() + 1);
+
+            let result = EthereumEvents::check_event(1, &event_id, &validator);
+
+            assert_eq!(result.result, CheckResult::Invalid);
+        });
+    }
+}
@@ -53,6 +53,7 @@ mod proxy_signed_add_ethereum_log {
             return EthEventId {
                 signature: self.event_type.signature(),
                 transaction_hash: self.tx_hash,
+                log_index: 0,
             };
         }
 
@@ -0,0 +1,442 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::*;
+use crate::mock::*;
+use crate::Call;
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const CHECKED_AT_BLOCK: u64 = 5;
+const READY_FOR_PROCESSING_AFTER_BLOCK: u64 = 100;
+const ETH_BLOCK_NUMBER: u64 = 46;
+const ETH_BLOCK_HASH: [u8; 32] = hex!("5536c9e671fe581fe4ef4631112038297dcdecae163e8724c281ece8ad94c8c3");
+const REORGED_ETH_BLOCK_HASH: [u8; 32] = [7; 32];
+
+const LOG_DATA: &str = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
+const EVENT_TOPICS: &str = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
+                          \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303\",
+                          \"0x689d5b000758030ea25304346869b002a345e7647ec5784b8af986e24e971303";
+
+fn checked_event(
+    checked_by: AccountId,
+    result: CheckResult,
+    eth_block_hash: H256) -> EthEventCheckResult<<TestRuntime as system::Config>::BlockNumber, AccountId>
+{
+    let mut checked = EthEventCheckResult::new(
+        READY_FOR_PROCESSING_AFTER_BLOCK,
+        result,
+        &EthereumEvents::get_event_id(1),
+        &EventData::EmptyEvent,
+        checked_by,
+        CHECKED_AT_BLOCK,
+        1
+    );
+    checked.eth_block_number = ETH_BLOCK_NUMBER;
+    checked.eth_block_hash = eth_block_hash;
+    return checked;
+}
+
+fn add_pending_event(checked: &EthEventCheckResult<<TestRuntime as system::Config>::BlockNumber, AccountId>) {
+    <EventsPendingChallenge<TestRuntime>>::append((checked.clone(), DEFAULT_INGRESS_COUNTER, 0));
+}
+
+fn receipt_json(event_id: &EthEventId, eth_block_hash: &H256) -> Vec<u8> {
+    let json = test_json(
+        &event_id.transaction_hash,
+        &event_id.signature,
+        &EthereumEvents::validator_manager_contract_address(),
+        LOG_DATA,
+        EVENT_TOPICS,
+        GOOD_STATUS,
+        GOOD_BLOCK_CONFIRMATIONS
+    );
+    let json = String::from_utf8(json).unwrap()
+        .replace(&format!("{:?}", H256::from(ETH_BLOCK_HASH)), &format!("{:?}", eth_block_hash));
+    return json.into_bytes();
+}
+
+mod compute_result {
+    use super::*;
+
+    #[test]
+    fn records_the_ethereum_block_of_a_valid_event() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::setup_mock_ethereum_contracts_address();
+            let event_id = EthereumEvents::get_event_id(1);
+
+            let result = EthereumEvents::compute_result(
+                1,
+                Ok(receipt_json(&event_id, &H256::from(ETH_BLOCK_HASH))),
+                &event_id,
+                &account_id_1()
+            );
+
+            assert_eq!(result.result, CheckResult::Ok);
+            assert_eq!(result.eth_block_number, ETH_BLOCK_NUMBER);
+            assert_eq!(result.eth_block_hash, H256::from(ETH_BLOCK_HASH));
+        });
+    }
+
+    #[test]
+    fn does_not_record_a_block_for_an_invalid_event() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            EthereumEvents::setup_mock_ethereum_contracts_address();
+            let event_id = EthereumEvents::get_event_id(1);
+            let error = avn_error::<TestRuntime>::EthereumReceiptNotFound.into();
+
+            let result = EthereumEvents::compute_result(1, Err(error), &event_id, &account_id_1());
+
+            assert_eq!(result.result, CheckResult::Invalid);
+            assert_eq!(result.eth_block_number, 0);
+            assert_eq!(result.eth_block_hash, H256::zero());
+        });
+    }
+}
+
+mod get_challenge_if_required {
+    use super::*;
+
+    #[test]
+    fn challenges_when_the_event_is_now_in_a_different_block() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let checked = checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH));
+            let validated = checked_event(account_id_1(), CheckResult::Ok, H256::from(REORGED_ETH_BLOCK_HASH));
+
+            let challenge = EthereumEvents::get_challenge_if_required(checked, validated, account_id_1());
+
+            assert_eq!(
+                challenge,
+                Some(Challenge::new(EthereumEvents::get_event_id(1), ChallengeReason::BlockNoLongerCanonical, account_id_1()))
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_challenge_when_the_checked_block_is_unknown() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let checked = checked_event(account_id_0(), CheckResult::Ok, H256::zero());
+            let validated = checked_event(account_id_1(), CheckResult::Ok, H256::from(REORGED_ETH_BLOCK_HASH));
+
+            assert_eq!(EthereumEvents::get_challenge_if_required(checked, validated, account_id_1()), None);
+        });
+    }
+}
+
+mod challenge_event {
+    use super::*;
+
+    fn challenge(validator: &Validator<AuthorityId, AccountId>) -> Challenge<AccountId> {
+        return Challenge::new(EthereumEvents::get_event_id(1), ChallengeReason::BlockNoLongerCanonical, validator.account_id.clone());
+    }
+
+    #[test]
+    fn records_the_challenger_when_the_block_is_no_longer_canonical() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validator = EthereumEvents::validators()[1].clone();
+            add_pending_event(&checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH)));
+
+            assert_ok!(EthereumEvents::challenge_event(
+                RawOrigin::None.into(),
+                challenge(&validator),
+                DEFAULT_INGRESS_COUNTER,
+                TestSignature(0, vec![]),
+                validator.clone()
+            ));
+
+            assert_eq!(EthereumEvents::challenges(EthereumEvents::get_event_id(1)), vec![validator.account_id.clone()]);
+            assert_eq!(
+                EthereumEvents::block_no_longer_canonical_challenges(EthereumEvents::get_event_id(1)),
+                vec![validator.account_id]
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_event_was_not_found_on_ethereum() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let validator = EthereumEvents::validators()[1].clone();
+            add_pending_event(&checked_event(account_id_0(), CheckResult::Invalid, H256::zero()));
+
+            assert_noop!(
+                EthereumEvents::challenge_event(
+                    RawOrigin::None.into(),
+                    challenge(&validator),
+                    DEFAULT_INGRESS_COUNTER,
+                    TestSignature(0, vec![]),
+                    validator
+                ),
+                Error::<TestRuntime>::InvalidEventToChallenge
+            );
+        });
+    }
+}
+
+mod process_event {
+    use super::*;
+
+    fn setup_block_no_longer_canonical_challenge() -> Validator<AuthorityId, AccountId> {
+        let validators = EthereumEvents::validators();
+        let checker = validators[0].clone();
+        add_pending_event(&checked_event(checker.account_id.clone(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH)));
+
+        let challengers = vec![validators[1].account_id.clone(), validators[2].account_id.clone()];
+        <Challenges<TestRuntime>>::insert(EthereumEvents::get_event_id(1), challengers.clone());
+        <BlockNoLongerCanonicalChallenges<TestRuntime>>::insert(EthereumEvents::get_event_id(1), challengers);
+
+        System::set_block_number(READY_FOR_PROCESSING_AFTER_BLOCK + 1);
+        return checker;
+    }
+
+    fn process_event(validator: Validator<AuthorityId, AccountId>) {
+        assert_ok!(EthereumEvents::process_event(
+            RawOrigin::None.into(),
+            EthereumEvents::get_event_id(1),
+            DEFAULT_INGRESS_COUNTER,
+            validator,
+            TestSignature(0, vec![])
+        ));
+    }
+
+    #[test]
+    fn rejects_an_event_whose_block_is_no_longer_canonical_so_it_can_be_resubmitted() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let checker = setup_block_no_longer_canonical_challenge();
+
+            process_event(checker);
+
+            assert!(EthereumEvents::events_pending_challenge().is_empty());
+            assert!(!<ProcessedEvents>::contains_key(EthereumEvents::get_event_id(1)));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventBlockNoLongerCanonical(
+                    EthereumEvents::get_event_id(1),
+                    ETH_BLOCK_NUMBER,
+                    H256::from(ETH_BLOCK_HASH)
+                )
+            )));
+            assert!(EthereumEvents::event_emitted(&mock::Event::pallet_ethereum_events(
+                crate::Event::<TestRuntime>::EventRejected(EthereumEvents::get_event_id(1), CheckResult::Ok, true)
+            )));
+        });
+    }
+
+    #[test]
+    fn does_not_report_the_checker() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let checker = setup_block_no_longer_canonical_challenge();
+
+            process_event(checker);
+
+            let offences = OFFENCES.with(|l| l.replace(vec![]));
+            assert_eq!(offences, vec![]);
+        });
+    }
+
+    #[test]
+    fn clears_the_challenges_of_the_event() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let checker = setup_block_no_longer_canonical_challenge();
+
+            process_event(checker);
+
+            assert!(!<Challenges<TestRuntime>>::contains_key(EthereumEvents::get_event_id(1)));
+            assert!(!<BlockNoLongerCanonicalChallenges<TestRuntime>>::contains_key(EthereumEvents::get_event_id(1)));
+        });
+    }
+
+    #[test]
+    fn reports_the_checker_when_too_few_challengers_found_the_block_no_longer_canonical() {
+        let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+        ext.execute_with(|| {
+            let checker = setup_block_no_longer_canonical_challenge();
+            <BlockNoLongerCanonicalChallenges<TestRuntime>>::insert(
+                EthereumEvents::get_event_id(1),
+                vec![EthereumEvents::validators()[1].account_id.clone()]
+            );
+
+            process_event(checker.clone());
+
+            assert!(<ProcessedEvents>::contains_key(EthereumEvents::get_event_id(1)));
+            let offences = OFFENCES.with(|l| l.replace(vec![]));
+            assert_eq!(offences.len(), 1);
+            assert_eq!(offences[0].1.offenders, vec![(checker.account_id.clone(), checker.account_id)]);
+        });
+    }
+}
+
+mod get_next_event_to_reverify {
+    use super::*;
+
+    const IN_WINDOW: u64 = READY_FOR_PROCESSING_AFTER_BLOCK - 1;
+
+    #[test]
+    fn returns_an_event_in_the_reverification_window() {
+        let (mut ext, _, _) = ExtBuilder::build_default().with_validators().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let checked = checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH));
+            add_pending_event(&checked);
+
+            assert_eq!(
+                EthereumEvents::get_next_event_to_reverify(IN_WINDOW, &account_id_1()),
+                Some((checked, DEFAULT_INGRESS_COUNTER, 0))
+            );
+        });
+    }
+
+    #[test]
+    fn returns_none_before_the_reverification_window() {
+        let (mut ext, _, _) = ExtBuilder::build_default().with_validators().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            add_pending_event(&checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH)));
+            let before_window = READY_FOR_PROCESSING_AFTER_BLOCK - EVENT_REVERIFICATION_WINDOW as u64 - 1;
+
+            assert_eq!(EthereumEvents::get_next_event_to_reverify(before_window, &account_id_1()), None);
+        });
+    }
+
+    #[test]
+    fn returns_none_when_the_ethereum_block_is_unknown() {
+        let (mut ext, _, _) = ExtBuilder::build_default().with_validators().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            add_pending_event(&checked_event(account_id_0(), CheckResult::Ok, H256::zero()));
+
+            assert_eq!(EthereumEvents::get_next_event_to_reverify(IN_WINDOW, &account_id_1()), None);
+        });
+    }
+
+    #[test]
+    fn returns_none_when_the_event_was_checked_by_this_validator() {
+        let (mut ext, _, _) = ExtBuilder::build_default().with_validators().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            add_pending_event(&checked_event(account_id_1(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH)));
+
+            assert_eq!(EthereumEvents::get_next_event_to_reverify(IN_WINDOW, &account_id_1()), None);
+        });
+    }
+
+    #[test]
+    fn returns_none_when_the_event_was_already_reverified() {
+        let (mut ext, _, _) = ExtBuilder::build_default().with_validators().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            add_pending_event(&checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH)));
+            assert_ok!(EthereumEvents::save_event_in_local_storage(
+                REVERIFIED_EVENT_LOCAL_STORAGE,
+                EthereumEvents::get_event_id(1)
+            ));
+
+            assert_eq!(EthereumEvents::get_next_event_to_reverify(IN_WINDOW, &account_id_1()), None);
+        });
+    }
+}
+
+mod reverify_event {
+    use super::*;
+
+    #[test]
+    fn challenges_when_the_receipt_is_in_a_different_block() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let validator = keys_setup_return_good_validator();
+            let checked = checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH));
+            let event_id = checked.event.event_id.clone();
+            inject_ethereum_node_response(
+                &mut offchain_state.write(),
+                &event_id.transaction_hash,
+                Some(receipt_json(&event_id, &H256::from(REORGED_ETH_BLOCK_HASH)))
+            );
+
+            assert_ok!(EthereumEvents::reverify_event(checked, DEFAULT_INGRESS_COUNTER, &validator));
+
+            let expected_challenge = Challenge::new(event_id, ChallengeReason::BlockNoLongerCanonical, validator.account_id.clone());
+            let expected_signature = validator.key
+                .sign(&(CHALLENGE_EVENT_CONTEXT, &expected_challenge, DEFAULT_INGRESS_COUNTER).encode())
+                .unwrap();
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumEvents(
+                    Call::challenge_event(expected_challenge, DEFAULT_INGRESS_COUNTER, expected_signature, validator)
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_challenge_when_the_block_is_still_canonical() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_validators()
+            .with_genesis_config()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let validator = keys_setup_return_good_validator();
+            let checked = checked_event(account_id_0(), CheckResult::Ok, H256::from(ETH_BLOCK_HASH));
+            let event_id = checked.event.event_id.clone();
+            add_pending_event(&checked);
+            inject_ethereum_node_response(
+                &mut offchain_state.write(),
+                &event_id.transaction_hash,
+                Some(receipt_json(&event_id, &H256::from(ETH_BLOCK_HASH)))
+            );
+
+            assert_ok!(EthereumEvents::reverify_event(checked, DEFAULT_INGRESS_COUNTER, &validator));
+
+            assert!(pool_state.read().transactions.is_empty());
+            // The event is only reverified once
+            assert_eq!(EthereumEvents::get_next_event_to_reverify(READY_FOR_PROCESSING_AFTER_BLOCK, &validator.account_id), None);
+        });
+    }
+}
+
+mod is_eth_block_canonical {
+    use super::*;
+
+    #[test]
+    fn is_false_when_the_receipt_is_no_longer_found() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let error = avn_error::<TestRuntime>::EthereumReceiptNotFound.into();
+
+            assert_eq!(EthereumEvents::is_eth_block_canonical(Err(error), &H256::from(ETH_BLOCK_HASH)), Some(false));
+        });
+    }
+
+    #[test]
+    fn is_unknown_when_the_ethereum_node_is_unreachable() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let error = avn_error::<TestRuntime>::EthereumNodeUnavailable.into();
+
+            assert_eq!(EthereumEvents::is_eth_block_canonical(Err(error), &H256::from(ETH_BLOCK_HASH)), None);
+        });
+    }
+
+    #[test]
+    fn is_true_when_the_receipt_is_in_the_same_block() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let response = receipt_json(&EthereumEvents::get_event_id(1), &H256::from(ETH_BLOCK_HASH));
+
+            assert_eq!(EthereumEvents::is_eth_block_canonical(Ok(response), &H256::from(ETH_BLOCK_HASH)), Some(true));
+        });
+    }
+}
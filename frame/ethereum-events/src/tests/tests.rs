@@ -177,6 +177,7 @@ fn test_event_exists_in_system_with_no_entries() {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         // test with an event that does not exist in any queue
         assert!(!EthereumEvents::event_exists_in_system(&event_id));
@@ -192,6 +193,7 @@ fn test_event_exists_in_system_entry_in_unchecked_queue() {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         EthereumEvents::insert_to_unchecked_events(&event_id, DEFAULT_INGRESS_COUNTER);
         // test with an event that exists in Unchecked Events only
@@ -236,6 +238,7 @@ fn test_event_exists_in_system_entry_in_pending_queue() {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         EthereumEvents::insert_to_events_pending_challenge(
             DEFAULT_BLOCK,
@@ -260,6 +263,7 @@ fn test_event_exists_in_system_entry_in_processed_queue() {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         EthereumEvents::insert_to_processed_events(&event_id);
         // Test with an event that exists in Processed Events only
@@ -279,10 +283,12 @@ fn test_multiple_events_in_system() {
         unchecked_events.push(EthEventId {
             signature: ValidEvents::Lifted.signature(),
             transaction_hash: H256::from([i; 32]),
+            log_index: 0,
         });
         pending_challenge_events.push(EthEventId {
             signature: ValidEvents::Lifted.signature(),
             transaction_hash: H256::from([i+10; 32]),
+            log_index: 0,
         });
     }
 
@@ -369,6 +375,7 @@ fn test_compute_result_ok() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
 
         let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
@@ -395,7 +402,7 @@ fn test_compute_result_ok() {
 
         assert_eq!(result.event.event_id.signature, expected_signature);
         assert_eq!(result.event.event_id.transaction_hash, expected_transaction_hash);
-        assert_eq!(result.event.event_data.is_valid(), expected_event_data_valid);
+        assert_eq!(result.event.event_data.is_valid(None), expected_event_data_valid);
         assert_eq!(result.result, expected_result);
         assert_eq!(result.checked_by, expected_authority_account_id);
         // ready_for_processing_at_block value should not be set here, so we skip the checks.event_topics
@@ -414,6 +421,7 @@ fn test_compute_result_invalid_event_signature() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
         let event_topics = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
@@ -449,6 +457,7 @@ fn test_compute_result_invalid_contract_address() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
         let event_topics = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
@@ -489,6 +498,7 @@ fn test_compute_result_invalid_log_data() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let invalid_log_data = "0xblah";
         let event_topics = "0x00000000000000000000000023aaf097c241897060c0a6b8aae61af5ea48cea3\",
@@ -522,6 +532,7 @@ fn test_compute_result_invalid_event_topics() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let log_data = "0x0000000000000000000000000000000000000000000000000000000005f5e100";
         let invalid_event_topics = "0xblah";
@@ -554,6 +565,7 @@ fn test_compute_result_empty_json() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let json = String::from("{}").into_bytes();
         let result = EthereumEvents::compute_result(block_number, Ok(json), unchecked_event, &account_id_1());
@@ -575,6 +587,7 @@ fn test_compute_result_invalid_json() {
         let unchecked_event = &EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: tx_validator_hash,
+            log_index: 0,
         };
         let json = String::from("bad").into_bytes();
         let result = EthereumEvents::compute_result(block_number, Ok(json), unchecked_event, &account_id_1());
@@ -586,8 +599,46 @@ fn test_compute_result_invalid_json() {
     });
 }
 
+#[test]
+fn test_compute_result_receipt_not_found() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(||{
+        EthereumEvents::setup_mock_ethereum_contracts_address();
+        let block_number = 1;
+        let unchecked_event = &EthEventId {
+            signature: ValidEvents::AddedValidator.signature(),
+            transaction_hash: H256::random(),
+            log_index: 0,
+        };
+        let error = avn_error::<TestRuntime>::EthereumReceiptNotFound.into();
+        let result = EthereumEvents::compute_result(block_number, Err(error), unchecked_event, &account_id_1());
+
+        assert_eq!(result.event.event_data, EventData::EmptyEvent);
+        assert_eq!(result.result, CheckResult::Invalid);
+    });
+}
+
+#[test]
+fn test_compute_result_ethereum_node_unavailable() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(||{
+        EthereumEvents::setup_mock_ethereum_contracts_address();
+        let block_number = 1;
+        let unchecked_event = &EthEventId {
+            signature: ValidEvents::AddedValidator.signature(),
+            transaction_hash: H256::random(),
+            log_index: 0,
+        };
+        let error = avn_error::<TestRuntime>::EthereumNodeUnavailable.into();
+        let result = EthereumEvents::compute_result(block_number, Err(error), unchecked_event, &account_id_1());
+
+        assert_eq!(result.event.event_data, EventData::EmptyEvent);
+        assert_eq!(result.result, CheckResult::HttpErrorCheckingEvent);
+    });
+}
+
 /*
-fn fetch_event(unchecked_event: &EthEventId) -> Result<Vec<u8>, http::Error> {
+fn fetch_event(unchecked_event: &EthEventId) -> Result<Vec<u8>, DispatchError> {
     test good cases:
         * check return type is Ok
         * check content of Ok is correct
@@ -647,6 +698,7 @@ mod signature_in {
             let event_id = EthEventId{
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_hash.clone(),
+                log_index: 0,
             };
 
             let validator = prepare_to_invoke_function(
@@ -670,8 +722,8 @@ mod signature_in {
             UintAuthorityId::set_all_keys(vec![1, 2, 3]);
 
             let val_length = EthereumEvents::validators().len();
-            let index_of_primary_validator = block_number % val_length;
-            let validator = &EthereumEvents::validators()[index_of_primary_validator];
+            let index_of_event_checker = (block_number + ingress_counter as usize) % val_length;
+            let validator = &EthereumEvents::validators()[index_of_event_checker];
 
             EthereumEvents::insert_to_unchecked_events(&event_id, ingress_counter);
             assert_eq!(EthereumEvents::unchecked_events().len(), 1);
@@ -771,6 +823,7 @@ mod signature_in {
             let event_id = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_hash.clone(),
+                log_index: 0,
             };
 
             let result = EthEventCheckResult::new(
@@ -805,8 +858,8 @@ mod signature_in {
             UintAuthorityId::set_all_keys(vec![1, 2, 3]);
 
             let val_length = EthereumEvents::validators().len();
-            let index_of_primary_validator = block_number % val_length;
-            let validator = &EthereumEvents::validators()[index_of_primary_validator];
+            let index_of_event_checker = (block_number + ingress_counter as usize) % val_length;
+            let validator = &EthereumEvents::validators()[index_of_event_checker];
 
             let other_validator_account_id = account_id_0();
 
@@ -919,6 +972,7 @@ mod signature_in {
             let event_id = EthEventId {
                 signature: ValidEvents::AddedValidator.signature(),
                 transaction_hash: tx_hash.clone(),
+                log_index: 0,
             };
 
             let result = EthEventCheckResult::new(
@@ -951,8 +1005,8 @@ mod signature_in {
             UintAuthorityId::set_all_keys(vec![1, 2, 3]);
 
             let val_length = EthereumEvents::validators().len();
-            let index_of_primary_validator = block_number % val_length;
-            let validator = &EthereumEvents::validators()[index_of_primary_validator];
+            let index_of_event_checker = (block_number + ingress_counter as usize) % val_length;
+            let validator = &EthereumEvents::validators()[index_of_event_checker];
 
             EthereumEvents::insert_to_events_pending_challenge(
                 DEFAULT_BLOCK,
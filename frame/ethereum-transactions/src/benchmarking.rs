@@ -9,7 +9,7 @@ use super::*;
 
 #[path = "ethereum_transaction.rs"]
 pub mod ethereum_transaction;
-use crate::ethereum_transaction::{EthTransactionType, PublishRootData, TransactionId};
+use crate::ethereum_transaction::{EthTransactionOutcome, EthTransactionType, PublishRootData, TransactionId};
 
 use frame_benchmarking::{account, benchmarks};
 use frame_system::{EventRecord, RawOrigin};
@@ -122,6 +122,71 @@ benchmarks! {
         assert_last_event::<T>(RawEvent::EthereumTransactionHashAdded(candidate_tx_id, eth_tx_hash).into());
     }
 
+    set_transaction_outcome {
+        let v in 1 .. MAX_VALIDATORS;
+        let t in 1 .. MAX_TXS_PER_ACCOUNT;
+        let (submitter, candidate_tx_id, eth_tx_hash, signature) = setup_eth_tx_and_dispatched_tx::<T>(v, t);
+        Repository::mutate(candidate_tx_id, |candidate| candidate.set_eth_tx_hash::<T>(eth_tx_hash)).expect("Hash is not set yet");
+        let outcome = EthTransactionOutcome::Succeeded(1);
+
+        // The other validators have already attested the outcome, so the submitter's attestation completes the quorum
+        let other_attestations: Vec<(T::AccountId, EthTransactionOutcome)> = avn::Validators::<T>::get()
+            .into_iter()
+            .skip(1)
+            .take(AVN::<T>::calculate_two_third_quorum() as usize - 1)
+            .map(|validator| (validator.account_id, outcome))
+            .collect();
+        OutcomeAttestations::<T>::insert(candidate_tx_id, other_attestations);
+    }: _(RawOrigin::None, submitter, candidate_tx_id, eth_tx_hash, outcome, signature)
+    verify {
+        assert_eq!(TransactionOutcomes::get(candidate_tx_id), Some(outcome));
+        assert_eq!(OutcomeAttestations::<T>::contains_key(candidate_tx_id), false);
+        assert_last_event::<T>(RawEvent::TransactionSucceeded(candidate_tx_id, eth_tx_hash, 1).into());
+    }
+
+    claim_transaction {
+        let v in 2 .. MAX_VALIDATORS;
+        let t in 1 .. MAX_TXS_PER_ACCOUNT;
+        let (submitter, candidate_tx_id, _, signature) = setup_eth_tx_and_dispatched_tx::<T>(v, t);
+        let claimant = avn::Validators::<T>::get()[1].clone();
+        frame_system::Module::<T>::set_block_number(T::DispatchedTransactionTimeout::get());
+    }: _(RawOrigin::None, candidate_tx_id, claimant.clone(), signature)
+    verify {
+        assert_eq!(FailedSubmitters::<T>::get(candidate_tx_id), vec![submitter]);
+        assert_last_event::<T>(RawEvent::TransactionReassigned(candidate_tx_id, claimant.account_id).into());
+    }
+
+    set_eth_tx_hash_for_batch {
+        let v in 1 .. MAX_VALIDATORS;
+        let t in T::MaxTransactionsPerBatch::get() .. MAX_TXS_PER_ACCOUNT;
+        let b in 1 .. T::MaxTransactionsPerBatch::get();
+        let (submitter, _, eth_tx_hash, signature) = setup_eth_tx_and_dispatched_tx::<T>(v, t);
+        let tx_ids = create_tx_ids::<T>(b);
+        for tx_id in tx_ids.iter() {
+            add_eth_tx_candidate_to_candidate_tx_id::<T>(&submitter, *tx_id);
+        }
+    }: _(RawOrigin::None, submitter, tx_ids.clone(), eth_tx_hash, signature)
+    verify {
+        for (index, tx_id) in tx_ids.iter().enumerate() {
+            assert_eq!(Repository::get(tx_id).get_eth_tx_hash(), Some(eth_tx_hash));
+            assert_eq!(BatchCallIndex::get(tx_id), Some(index as u32));
+        }
+        assert_last_event::<T>(RawEvent::BatchSent(tx_ids, eth_tx_hash).into());
+    }
+
+    add_replacement_eth_tx_hashes {
+        let v in 1 .. MAX_VALIDATORS;
+        let t in 1 .. MAX_TXS_PER_ACCOUNT;
+        let r in 1 .. MAX_REPLACEMENT_ETH_TX_HASHES;
+        let (submitter, candidate_tx_id, eth_tx_hash, signature) = setup_eth_tx_and_dispatched_tx::<T>(v, t);
+        Repository::mutate(candidate_tx_id, |candidate| candidate.set_eth_tx_hash::<T>(eth_tx_hash)).expect("Hash is not set yet");
+        let replacement_eth_tx_hashes: Vec<EthereumTransactionHash> = (0..r).map(|i| H256::from_low_u64_be(i as u64 + 2)).collect();
+    }: _(RawOrigin::None, submitter, candidate_tx_id, eth_tx_hash, replacement_eth_tx_hashes.clone(), signature)
+    verify {
+        assert_eq!(ReplacementEthTxHashes::get(candidate_tx_id), replacement_eth_tx_hashes);
+        assert_last_event::<T>(RawEvent::ReplacementEthTxHashesAdded(candidate_tx_id, replacement_eth_tx_hashes).into());
+    }
+
     set_publish_root_contract_storage {
         let contract_address = H160::from([1; 20]);
     }: set_publish_root_contract(RawOrigin::Root, contract_address.clone())
@@ -144,6 +209,10 @@ mod tests {
             assert_ok!(test_benchmark_set_transaction_id::<TestRuntime>());
             assert_ok!(test_benchmark_unreserve_transaction::<TestRuntime>());
             assert_ok!(test_benchmark_set_eth_tx_hash_for_dispatched_tx::<TestRuntime>());
+            assert_ok!(test_benchmark_set_transaction_outcome::<TestRuntime>());
+            assert_ok!(test_benchmark_claim_transaction::<TestRuntime>());
+            assert_ok!(test_benchmark_set_eth_tx_hash_for_batch::<TestRuntime>());
+            assert_ok!(test_benchmark_add_replacement_eth_tx_hashes::<TestRuntime>());
             assert_ok!(test_benchmark_set_publish_root_contract_storage::<TestRuntime>());
         });
     }
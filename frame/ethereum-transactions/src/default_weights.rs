@@ -14,6 +14,11 @@ pub trait WeightInfo {
     fn unreserve_transaction() -> Weight;
     fn set_eth_tx_hash_for_dispatched_tx(v: u32, t: u32, ) -> Weight;
     fn set_publish_root_contract() -> Weight;
+    fn set_transaction_outcome(v: u32, t: u32, ) -> Weight;
+    fn add_confirmation(v: u32, ) -> Weight;
+    fn claim_transaction(v: u32, t: u32, ) -> Weight;
+    fn set_eth_tx_hash_for_batch(v: u32, t: u32, b: u32, ) -> Weight;
+    fn add_replacement_eth_tx_hashes(v: u32, t: u32, r: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ethereum_transactions
@@ -39,6 +44,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(5_660_000 as Weight)
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
+	fn set_transaction_outcome(v: u32, t: u32, ) -> Weight {
+		(148_512_000 as Weight)
+			.saturating_add((871_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+	}
+	fn add_confirmation(v: u32, ) -> Weight {
+		(182_304_000 as Weight)
+			.saturating_add((512_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(5 as Weight))
+	}
+	fn claim_transaction(v: u32, t: u32, ) -> Weight {
+		(163_877_000 as Weight)
+			.saturating_add((1_104_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn set_eth_tx_hash_for_batch(v: u32, t: u32, b: u32, ) -> Weight {
+		(139_415_000 as Weight)
+			.saturating_add((434_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((41_208_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	fn add_replacement_eth_tx_hashes(v: u32, t: u32, r: u32, ) -> Weight {
+		(131_864_000 as Weight)
+			.saturating_add((874_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((1_512_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -63,4 +105,41 @@ impl crate::WeightInfo for () {
 		(5_660_000 as Weight)
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
+	fn set_transaction_outcome(v: u32, t: u32, ) -> Weight {
+		(148_512_000 as Weight)
+			.saturating_add((871_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(8 as Weight))
+	}
+	fn add_confirmation(v: u32, ) -> Weight {
+		(182_304_000 as Weight)
+			.saturating_add((512_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(5 as Weight))
+	}
+	fn claim_transaction(v: u32, t: u32, ) -> Weight {
+		(163_877_000 as Weight)
+			.saturating_add((1_104_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(6 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn set_eth_tx_hash_for_batch(v: u32, t: u32, b: u32, ) -> Weight {
+		(139_415_000 as Weight)
+			.saturating_add((434_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((41_208_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	fn add_replacement_eth_tx_hashes(v: u32, t: u32, r: u32, ) -> Weight {
+		(131_864_000 as Weight)
+			.saturating_add((874_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((35_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add((1_512_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
 }
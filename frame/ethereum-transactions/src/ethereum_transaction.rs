@@ -20,7 +20,8 @@ pub enum EthTransactionType {
     SlashValidator(SlashValidatorData),
     ActivateValidator(ActivateValidatorData),
     Invalid,
-    Discarded(TransactionId)
+    Discarded(TransactionId),
+    ContractCall(ContractCallData),
 }
 
 impl Default for EthTransactionType {
@@ -36,9 +37,112 @@ impl EthTransactionType {
             EthTransactionType::DeregisterValidator(d) => Ok(d.to_abi()),
             EthTransactionType::SlashValidator(d) => Ok(d.to_abi()),
             EthTransactionType::ActivateValidator(d) => Ok(d.to_abi()),
+            EthTransactionType::ContractCall(d) => d.to_abi(),
             _ => Err(EthAbiError::InvalidData),
         }
     }
+
+    pub fn get_contract_role(&self) -> Option<EthContractRole> {
+        match self {
+            EthTransactionType::PublishRoot(_) => Some(EthContractRole::PublishRoot),
+            EthTransactionType::DeregisterValidator(_) => Some(EthContractRole::ValidatorManager),
+            EthTransactionType::SlashValidator(_) => Some(EthContractRole::ValidatorManager),
+            EthTransactionType::ActivateValidator(_) => Some(EthContractRole::ValidatorManager),
+            EthTransactionType::ContractCall(d) => Some(d.contract_role),
+            _ => None,
+        }
+    }
+}
+
+/// The Tier1 contract a transaction is sent to. The pallet resolves each role to a contract address.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq)]
+pub enum EthContractRole {
+    PublishRoot,
+    ValidatorManager,
+}
+
+/// A value passed to a Tier1 contract function. Its variant determines the solidity type of the parameter.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+pub enum EthCallParamValue {
+    Address(H160),
+    Bytes32([u8; 32]),
+    Bytes(Vec<u8>),
+    Uint256(U256),
+    Bool(bool),
+}
+
+impl EthCallParamValue {
+    pub fn to_param_type(&self) -> ParamType {
+        match self {
+            EthCallParamValue::Address(_) => ParamType::Address,
+            EthCallParamValue::Bytes32(_) => ParamType::FixedBytes(32),
+            EthCallParamValue::Bytes(_) => ParamType::Bytes,
+            EthCallParamValue::Uint256(_) => ParamType::Uint(256),
+            EthCallParamValue::Bool(_) => ParamType::Bool,
+        }
+    }
+
+    pub fn to_token(&self) -> Token {
+        match self {
+            EthCallParamValue::Address(address) => Token::Address(*address),
+            EthCallParamValue::Bytes32(bytes) => Token::FixedBytes(bytes.to_vec()),
+            EthCallParamValue::Bytes(bytes) => Token::Bytes(bytes.clone()),
+            EthCallParamValue::Uint256(value) => Token::Uint(EthAbiHelper::u256_to_big_endian(value).into()),
+            EthCallParamValue::Bool(value) => Token::Bool(*value),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+pub struct EthCallParam {
+    pub name: Vec<u8>,
+    pub value: EthCallParamValue,
+}
+
+impl EthCallParam {
+    pub fn new(name: &str, value: EthCallParamValue) -> EthCallParam {
+        EthCallParam {
+            name: name.as_bytes().to_vec(),
+            value: value,
+        }
+    }
+}
+
+/// A call to any avn compatible function of a Tier1 contract. The `_t2TransactionId` and `_confirmations` parameters are
+/// appended when the transaction is sent, so `params` must not include them.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+pub struct ContractCallData {
+    pub contract_role: EthContractRole,
+    pub function_name: Vec<u8>,
+    pub params: Vec<EthCallParam>,
+}
+
+impl ContractCallData {
+    pub fn new(contract_role: EthContractRole, function_name: &str, params: Vec<EthCallParam>) -> ContractCallData {
+        ContractCallData {
+            contract_role: contract_role,
+            function_name: function_name.as_bytes().to_vec(),
+            params: params,
+        }
+    }
+
+    pub fn to_abi(&self) -> Result<EthTransactionDescription, ethabi::Error> {
+        let function_name = Self::to_string(&self.function_name)?;
+        if function_name.is_empty() {
+            return Err(EthAbiError::InvalidData);
+        }
+
+        let mut params = Vec::<(String, EthCallParamValue)>::new();
+        for param in self.params.iter() {
+            params.push((Self::to_string(&param.name)?, param.value.clone()));
+        }
+
+        Ok(EthAbiHelper::generate_function_description(function_name, params))
+    }
+
+    fn to_string(bytes: &Vec<u8>) -> Result<String, ethabi::Error> {
+        return String::from_utf8(bytes.clone()).map_err(|_| EthAbiError::InvalidData);
+    }
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
@@ -54,18 +158,10 @@ impl PublishRootData {
     }
 
     pub fn to_abi(&self) -> EthTransactionDescription {
-        EthTransactionDescription {
-            function_call: Function {
-                name: String::from("publishRoot"),
-                inputs: vec![Param {
-                    name: String::from("_rootHash"),
-                    kind: ParamType::FixedBytes(32),
-                }],
-                outputs: Vec::<Param>::new(),
-                constant: false,
-            },
-            call_values: vec![Token::FixedBytes(self.root_hash.to_vec())],
-        }
+        EthAbiHelper::generate_function_description(
+            String::from("publishRoot"),
+            vec![(String::from("_rootHash"), EthCallParamValue::Bytes32(self.root_hash))],
+        )
     }
 }
 
@@ -82,18 +178,10 @@ impl DeregisterValidatorData {
     }
 
     pub fn to_abi(&self) -> EthTransactionDescription {
-        EthTransactionDescription {
-            function_call: Function {
-                name: String::from("deregisterValidator"),
-                inputs: vec![Param {
-                    name: String::from("_targetT2PublicKey"),
-                    kind: ParamType::FixedBytes(32),
-                }],
-                outputs: Vec::<Param>::new(),
-                constant: false,
-            },
-            call_values: vec![Token::FixedBytes(self.t2_public_key.to_vec())],
-        }
+        EthAbiHelper::generate_function_description(
+            String::from("deregisterValidator"),
+            vec![(String::from("_targetT2PublicKey"), EthCallParamValue::Bytes32(self.t2_public_key))],
+        )
     }
 }
 
@@ -110,18 +198,10 @@ impl SlashValidatorData {
     }
 
     pub fn to_abi(&self) -> EthTransactionDescription {
-        EthTransactionDescription {
-            function_call: Function {
-                name: String::from("slashValidator"),
-                inputs: vec![Param {
-                    name: String::from("_targetT2PublicKey"),
-                    kind: ParamType::FixedBytes(32),
-                }],
-                outputs: Vec::<Param>::new(),
-                constant: false,
-            },
-            call_values: vec![Token::FixedBytes(self.t2_public_key.to_vec())],
-        }
+        EthAbiHelper::generate_function_description(
+            String::from("slashValidator"),
+            vec![(String::from("_targetT2PublicKey"), EthCallParamValue::Bytes32(self.t2_public_key))],
+        )
     }
 }
 
@@ -138,18 +218,10 @@ impl ActivateValidatorData {
     }
 
     pub fn to_abi(&self) -> EthTransactionDescription {
-        EthTransactionDescription {
-            function_call: Function {
-                name: String::from("activateValidator"),
-                inputs: vec![Param {
-                    name: String::from("_targetT2PublicKey"),
-                    kind: ParamType::FixedBytes(32),
-                }],
-                outputs: Vec::<Param>::new(),
-                constant: false,
-            },
-            call_values: vec![Token::FixedBytes(self.t2_public_key.to_vec())],
-        }
+        EthAbiHelper::generate_function_description(
+            String::from("activateValidator"),
+            vec![(String::from("_targetT2PublicKey"), EthCallParamValue::Bytes32(self.t2_public_key))],
+        )
     }
 }
 
@@ -229,6 +301,38 @@ impl EthTransactionCandidate {
         self.eth_tx_hash = new_hash;
         Ok(())
     }
+
+    /// Hands the transaction over to a new sender. Confirmations are signed for a specific sender,
+    /// so they are dropped and must be collected again before the new sender can dispatch it.
+    pub fn reassign(&mut self, new_from: [u8; 32], quorum: u32) {
+        self.from = Some(new_from);
+        self.signatures = EthSignatures::new();
+        self.quorum = quorum;
+        self.eth_tx_hash = H256::zero();
+    }
+}
+
+/// What happened to a dispatched transaction on Ethereum
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq)]
+pub enum EthTransactionOutcome {
+    /// Mined in this Ethereum block without reverting
+    Succeeded(u64),
+    Reverted,
+    /// Never mined
+    Dropped,
+}
+
+impl EthTransactionOutcome {
+    pub fn is_failure(&self) -> bool {
+        return self.eth_block_number().is_none();
+    }
+
+    pub fn eth_block_number(&self) -> Option<u64> {
+        return match self {
+            EthTransactionOutcome::Succeeded(eth_block_number) => Some(*eth_block_number),
+            _ => None,
+        };
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -249,6 +353,24 @@ impl EthAbiHelper {
         uint256
     }
 
+    pub fn generate_function_description(
+        function_name: String,
+        params: Vec<(String, EthCallParamValue)>,
+    ) -> EthTransactionDescription {
+        EthTransactionDescription {
+            function_call: Function {
+                name: function_name,
+                inputs: params.iter().map(|(name, value)| Param {
+                    name: name.clone(),
+                    kind: value.to_param_type(),
+                }).collect(),
+                outputs: Vec::<Param>::new(),
+                constant: false,
+            },
+            call_values: params.iter().map(|(_, value)| value.to_token()).collect(),
+        }
+    }
+
     pub fn generate_eth_abi_encoding(
         call: &EthTransactionDescription,
     ) -> Result<Vec<u8>, ethabi::Error> {
@@ -315,6 +437,44 @@ impl EthAbiHelper {
         let encoded_data = EthAbiHelper::generate_eth_abi_encoding(transaction_description)?;
         Ok(EthTransaction::new(from, to, encoded_data))
     }
+
+    /// Wraps transactions of the same sender into a single call to a multicall contract. The contract forwards each call,
+    /// with its own `_t2TransactionId` and `_confirmations`, to its target on behalf of the sender. A failed call does not
+    /// revert the others: the contract emits `LogCallResult(uint256 _index, bool _success)` for each call, so the outcome
+    /// of each transaction is read from its own result.
+    pub fn generate_batch_transaction_abi(
+        from: [u8; 32],
+        multicall_contract: H160,
+        transactions: &Vec<EthTransaction>,
+    ) -> Result<EthTransaction, ethabi::Error> {
+        if transactions.is_empty() || transactions.iter().any(|transaction| transaction.from != from) {
+            return Err(EthAbiError::InvalidData);
+        }
+
+        let batch_description = EthTransactionDescription {
+            function_call: Function {
+                name: String::from("tryBatchCall"),
+                inputs: vec![
+                    Param {
+                        name: String::from("_targets"),
+                        kind: ParamType::Array(Box::new(ParamType::Address)),
+                    },
+                    Param {
+                        name: String::from("_calls"),
+                        kind: ParamType::Array(Box::new(ParamType::Bytes)),
+                    },
+                ],
+                outputs: Vec::<Param>::new(),
+                constant: false,
+            },
+            call_values: vec![
+                Token::Array(transactions.iter().map(|transaction| Token::Address(transaction.to)).collect()),
+                Token::Array(transactions.iter().map(|transaction| Token::Bytes(transaction.data.clone())).collect()),
+            ],
+        };
+
+        EthAbiHelper::generate_ethereum_transaction_abi(from, multicall_contract, &batch_description)
+    }
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -7,7 +7,10 @@ extern crate alloc;
 use alloc::{string::{String, ToString}};
 
 use codec::{Encode, Decode};
-use frame_support::{debug, decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get};
+use frame_support::{
+    debug, decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get, transactional,
+    weights::Weight,
+};
 use frame_system::{
     self as system, ensure_none, ensure_root,
     offchain::{SendTransactionTypes, SubmitTransaction},
@@ -20,18 +23,27 @@ use sp_runtime::{
         TransactionValidity, ValidTransaction,
     },
     DispatchError,
-    traits::{Member, AtLeast32Bit}
+    traits::{Member, AtLeast32Bit, SaturatedConversion, Zero}
 };
+use sp_staking::offence::ReportOffence;
+use pallet_session::historical::IdentificationTuple;
 use sp_std::prelude::*;
 
 use sp_avn_common::{
-    offchain_worker_storage_lock::{self as OcwLock, OcwOperationExpiration}, EthTransaction,
+    offchain_worker_storage_lock::{self as OcwLock, OcwOperationExpiration}, EthTransaction, EthTransactionStatus,
     event_types::Validator
 };
 use sp_core::{H160, H256, ecdsa};
 
 pub mod ethereum_transaction;
-use crate::ethereum_transaction::{EthTransactionCandidate, EthTransactionType, EthereumTransactionHash, TransactionId};
+pub mod offence;
+use crate::offence::{
+    EthereumTransactionOffence, EthereumTransactionOffenceType, OutcomeNotRecordedOffence,
+    create_and_report_transaction_offence
+};
+use crate::ethereum_transaction::{
+    EthAbiHelper, EthContractRole, EthTransactionCandidate, EthTransactionOutcome, EthTransactionType, EthereumTransactionHash, TransactionId
+};
 
 use pallet_avn::{self as avn, Error as avn_error, AccountToBytesConverter};
 
@@ -60,6 +72,22 @@ mod tests_eth_transaction_type;
 #[path = "tests/test_set_publish_root_contract.rs"]
 mod test_set_publish_root_contract;
 
+#[cfg(test)]
+#[path = "tests/test_transaction_outcomes.rs"]
+mod test_transaction_outcomes;
+
+#[cfg(test)]
+#[path = "tests/test_claim_transaction.rs"]
+mod test_claim_transaction;
+
+#[cfg(test)]
+#[path = "tests/test_batch_transactions.rs"]
+mod test_batch_transactions;
+
+#[cfg(test)]
+#[path = "tests/test_pruning.rs"]
+mod test_pruning;
+
 mod benchmarking;
 
 // TODO: [TYPE: business logic][PRI: high][CRITICAL]
@@ -69,12 +97,20 @@ pub use default_weights::WeightInfo;
 
 const NAME: &'static [u8; 26] = b"eth_transactions::last_run";
 const SET_ETH_TX_HASH_FOR_DISPATCHED_TX: &'static [u8] = b"set_eth_tx_hash_for_dispatched_tx";
+const SET_TRANSACTION_OUTCOME: &'static [u8] = b"set_transaction_outcome";
+const ADD_CONFIRMATION: &'static [u8] = b"add_confirmation";
+const CLAIM_TRANSACTION: &'static [u8] = b"claim_transaction";
+const SET_ETH_TX_HASH_FOR_BATCH: &'static [u8] = b"set_eth_tx_hash_for_batch";
+const ADD_REPLACEMENT_ETH_TX_HASHES: &'static [u8] = b"add_replacement_eth_tx_hashes";
 
 const SUBMITTER_IS_NOT_VALIDATOR: u8 = 1;
 // Avoid sending multiple concurrent requests to avn-service at once. Set a throttle to 1.
 const MAX_VALUES_RETURNED: usize = 1;
 const MAX_VALIDATORS: u32 = 10; // used in benchmarks and weights calculation only
 const MAX_TXS_PER_ACCOUNT: u32 = 1_000_000; // used in benchmarks and weights calculation only
+// The most replacements recorded for a sent transaction. Each one restarts its drop delay, so this bounds how long a
+// submitter can keep a transaction from being considered dropped.
+const MAX_REPLACEMENT_ETH_TX_HASHES: u32 = 16;
 
 // TODO [TYPE: business logic][PRI: high][CRITICAL][JIRA: 354] investigate the time needed for an ethereum transaction to become stale.
 // The time for an ethereum block to be mined varies between 13 and 30 seconds. We take an average on ~20 secs
@@ -82,9 +118,19 @@ const MAX_TXS_PER_ACCOUNT: u32 = 1_000_000; // used in benchmarks and weights ca
 // As a default value we set 2 hours delay: (2 * 60 * 60) / 20 = 360
 // AvN blocks are every 3 seconds therefore 360 / 3 = 120
 const ETHEREUM_SEND_BLOCKS_EXPIRY: u32 = 120;
+// How often a sender asks the external service what happened to each of the transactions it sent to Ethereum
+const OUTCOME_CHECK_BLOCKS_EXPIRY: u32 = 10;
+// How long a validator waits before confirming a reassigned transaction again, if its confirmation did not make it in a block
+const CONFIRMATION_BLOCKS_EXPIRY: u32 = 10;
+// How long a validator waits before claiming a transaction again, if its claim did not make it in a block
+const CLAIM_BLOCKS_EXPIRY: u32 = 10;
+// How often a sender asks the external service whether it replaced each of the transactions it sent to Ethereum
+const REPLACEMENT_CHECK_BLOCKS_EXPIRY: u32 = 10;
+// How often a former sender asks the external service again to stop replacing a transaction that was dropped
+const STOP_MONITORING_BLOCKS_EXPIRY: u32 = 120;
 
 // Public interface of this pallet
-pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Config {
+pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Config + pallet_session::historical::Config {
     type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
 
     type Call: From<Call<Self>>;
@@ -93,6 +139,45 @@ pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Confi
 
     type ValidatorManagerContractAddress: Get<H160>;
 
+    /// Minimum number of Ethereum blocks mined on top of a dispatched transaction before its outcome is recorded
+    type MinEthBlockConfirmation: Get<u64>;
+
+    /// Number of blocks after its Ethereum transaction hash is recorded, after which a transaction that has not been
+    /// mined is considered dropped
+    type EthTransactionDropDelay: Get<Self::BlockNumber>;
+
+    /// Number of blocks a submitter has to send a dispatched transaction before the next validator in the fallback order
+    /// can claim it. Every further period lets the following validator claim it. It is also the number of blocks a
+    /// submitter has to attest that its transaction was dropped, before it is reported for not recording the outcome.
+    type DispatchedTransactionTimeout: Get<Self::BlockNumber>;
+
+    /// Address of the multicall contract that forwards a batch of transactions to their targets on behalf of their
+    /// sender. Transactions are sent one at a time if this is zero.
+    /// The targets see the multicall contract as `msg.sender`. It must append the address of its caller to each call
+    /// (as in EIP-2771), and every target contract must trust it as a forwarder and check the confirmations of a call
+    /// against that address. Do not set this unless all the Tier1 contracts do.
+    type MulticallContractAddress: Get<H160>;
+
+    /// Maximum number of dispatched transactions of the same sender that are sent to Ethereum in one batch
+    type MaxTransactionsPerBatch: Get<u32>;
+
+    /// Number of blocks a completed or discarded transaction is kept for, before it is pruned
+    type TransactionRetentionPeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of transactions pruned in a block
+    type MaxTransactionsPrunedPerBlock: Get<u32>;
+
+    ///  A type that gives the pallet the ability to report offences
+    type ReportTransactionOffence: ReportOffence<
+            Self::AccountId,
+            IdentificationTuple<Self>,
+            EthereumTransactionOffence<IdentificationTuple<Self>>,
+        > + ReportOffence<
+            Self::AccountId,
+            IdentificationTuple<Self>,
+            OutcomeNotRecordedOffence<IdentificationTuple<Self>>,
+        >;
+
     type WeightInfo: WeightInfo;
 }
 
@@ -101,10 +186,32 @@ pub trait Config: SendTransactionTypes<Call<Self>> + system::Config + avn::Confi
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Config>::AccountId,
+        IdentificationTuple = IdentificationTuple<T>,
+        EthereumTransactionOffenceType = EthereumTransactionOffenceType,
     {
         // TODO [TYPE: refactoring][PRI: medium] Discuss if this information is transparent enough or do we want to emit an EthTransaction
         TransactionReadyToSend(TransactionId, AccountId),
         EthereumTransactionHashAdded(TransactionId, EthereumTransactionHash),
+        /// BatchSent(TransactionIds, EthereumTransactionHash)
+        BatchSent(Vec<TransactionId>, EthereumTransactionHash),
+        /// TransactionSucceeded(TransactionId, EthereumTransactionHash, EthBlockNumber)
+        TransactionSucceeded(TransactionId, EthereumTransactionHash, u64),
+        /// TransactionFailed(TransactionId, EthTransactionOutcome, Submitter)
+        TransactionFailed(TransactionId, EthTransactionOutcome, AccountId),
+        /// TransactionReassigned(TransactionId, NewSubmitter)
+        TransactionReassigned(TransactionId, AccountId),
+        /// TransactionAbandoned(TransactionId) - none of the active validators could get it executed
+        TransactionAbandoned(TransactionId),
+        /// TransactionClaimed(TransactionId, PreviousSubmitter, NewSubmitter)
+        TransactionClaimed(TransactionId, AccountId, AccountId),
+        /// OffenceReported(OffenceType, Offenders)
+        OffenceReported(EthereumTransactionOffenceType, Vec<IdentificationTuple>),
+        /// TransactionsPruned(TransactionIds) - completed or discarded transactions removed from storage
+        TransactionsPruned(Vec<TransactionId>),
+        /// TransactionOutcomeAttested(TransactionId, EthTransactionOutcome, Validator)
+        TransactionOutcomeAttested(TransactionId, EthTransactionOutcome, AccountId),
+        /// ReplacementEthTxHashesAdded(TransactionId, ReplacementEthereumTransactionHashes)
+        ReplacementEthTxHashesAdded(TransactionId, Vec<EthereumTransactionHash>),
     }
 );
 
@@ -126,6 +233,48 @@ decl_storage! {
         pub PublishRootContract get(fn get_publish_root_contract) config(): H160;
 
         Nonce: TransactionId;
+
+        /// The final outcome on Ethereum of a dispatched transaction
+        pub TransactionOutcomes get(fn get_transaction_outcome): map hasher(blake2_128_concat)
+            TransactionId => Option<EthTransactionOutcome>;
+
+        /// The outcomes of a sent transaction attested by the validators, until a quorum of them agree on one
+        pub OutcomeAttestations get(fn get_outcome_attestations): map hasher(blake2_128_concat)
+            TransactionId => Vec<(T::AccountId, EthTransactionOutcome)>;
+
+        /// The block in which the Ethereum transaction hash of a dispatched transaction was recorded
+        pub EthTxHashRecordedAt get(fn get_eth_tx_hash_recorded_at): map hasher(blake2_128_concat)
+            TransactionId => T::BlockNumber;
+
+        /// The hashes of the transactions the submitter's external service sent to replace a sent transaction with higher
+        /// fees, oldest first. Any of them can be mined instead of the recorded hash.
+        pub ReplacementEthTxHashes get(fn get_replacement_eth_tx_hashes): map hasher(blake2_128_concat)
+            TransactionId => Vec<EthereumTransactionHash>;
+
+        /// The position of a dispatched transaction in the multicall batch that sent it, if it was sent in a batch
+        pub BatchCallIndex get(fn get_batch_call_index): map hasher(blake2_128_concat)
+            TransactionId => Option<u32>;
+
+        /// The validators that failed to get a transaction executed on Ethereum, in the order they failed
+        pub FailedSubmitters get(fn get_failed_submitters): map hasher(blake2_128_concat)
+            TransactionId => Vec<T::AccountId>;
+
+        /// The submitters whose transaction was dropped, with the hash they recorded for it, so their external service
+        /// stops replacing it
+        pub DroppedEthTxHashes get(fn get_dropped_eth_tx_hashes): map hasher(blake2_128_concat)
+            TransactionId => Vec<(T::AccountId, EthereumTransactionHash)>;
+
+        /// Transactions handed over to a new submitter, waiting for the validators to confirm them again
+        pub TransactionsAwaitingConfirmations get(fn get_transactions_awaiting_confirmations): Vec<TransactionId>;
+
+        /// The validators that confirmed a transaction awaiting confirmations
+        pub Confirmers get(fn get_confirmers): map hasher(blake2_128_concat) TransactionId => Vec<T::AccountId>;
+
+        /// Completed or discarded transactions, in the order they completed, with the block they completed in.
+        /// They are pruned from the head of the queue once they are older than `TransactionRetentionPeriod`.
+        pub PruningQueue get(fn get_pruning_queue_entry): map hasher(blake2_128_concat) u64 => (TransactionId, T::BlockNumber);
+        pub PruningQueueHead get(fn get_pruning_queue_head): u64;
+        pub PruningQueueTail get(fn get_pruning_queue_tail): u64;
     }
 }
 
@@ -152,6 +301,18 @@ decl_error! {
         RequestTimedOut,
         UnexpectedStatusCode,
         InvalidContractAddress,
+        MissingEthTxHash,
+        TransactionNotDroppedYet,
+        TransactionNotAwaitingConfirmations,
+        DuplicateConfirmation,
+        ErrorGettingTransactionStatus,
+        ClaimNotAllowed,
+        InvalidContractCall,
+        InvalidBatch,
+        EthTxHashMismatch,
+        DuplicateAttestation,
+        InvalidReplacementEthTxHashes,
+        ErrorGettingEthTxHashes,
     }
 }
 
@@ -200,24 +361,322 @@ decl_module! {
         {
             ensure_none(origin)?;
             ensure!(AVN::<T>::is_validator(&submitter), Error::<T>::InvalidKey);
+
+            Self::record_eth_tx_hash(&submitter, candidate_tx_id, eth_tx_hash)?;
+
+            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
+            return Ok(());
+        }
+
+        /// Records the hash of the Ethereum transaction that sent a batch of dispatched transactions through the multicall
+        /// contract. Each transaction of the batch gets the same hash and its position in the batch, so its outcome is then
+        /// tracked on its own.
+        /// # <weight>
+        /// Keys:
+        ///     V: number of validators
+        ///     T: number transaction Ids per account
+        ///     B: number of transactions in the batch
+        ///  - avn pallet is_validator operation: O(V)
+        ///  - Check the batch has no duplicates: O(B^2)
+        ///  - For each transaction of the batch:
+        ///     - DbReads: `Repository`, `DispatchedAvnTxIds`: O(1)
+        ///     - Account transaction Id vector contains operation: O(T)
+        ///     - DbMutate: `Repository`: O(1)
+        ///     - DbWrites: `EthTxHashRecordedAt`, `BatchCallIndex`: O(1)
+        ///     - Emit an event: O(1)
+        ///  - Emit an event: O(1)
+        /// - Total Complexity: `O(V + B^2 + B * T)`
+        /// # </weight>
+        #[weight = T::WeightInfo::set_eth_tx_hash_for_batch(MAX_VALIDATORS, MAX_TXS_PER_ACCOUNT, T::MaxTransactionsPerBatch::get())]
+        #[transactional]
+        fn set_eth_tx_hash_for_batch(
+            origin,
+            submitter: T::AccountId,
+            tx_ids: Vec<TransactionId>,
+            eth_tx_hash: EthereumTransactionHash,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+            ensure!(AVN::<T>::is_validator(&submitter), Error::<T>::InvalidKey);
+            ensure!(
+                !tx_ids.is_empty() && tx_ids.len() <= T::MaxTransactionsPerBatch::get() as usize,
+                Error::<T>::InvalidBatch
+            );
+            ensure!(
+                tx_ids.iter().enumerate().all(|(index, tx_id)| !tx_ids[..index].contains(tx_id)),
+                Error::<T>::InvalidBatch
+            );
+
+            for (index, tx_id) in tx_ids.iter().enumerate() {
+                Self::record_eth_tx_hash(&submitter, *tx_id, eth_tx_hash)?;
+                <BatchCallIndex>::insert(tx_id, index as u32);
+            }
+
+            Self::deposit_event(Event::<T>::BatchSent(tx_ids, eth_tx_hash));
+
+            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
+            return Ok(());
+        }
+
+        /// Attests what happened on Ethereum to a sent transaction. Every validator checks the outcome independently, and
+        /// it is only recorded once a quorum of them attest the same one. A failed transaction is then handed over to the
+        /// next active validator that has not failed to send it yet. If the submitter had not attested the failure by
+        /// the time it could attest the transaction dropped, it is reported. The outcome is attested for the recorded
+        /// hash, but it is the outcome of whichever of the recorded hash and its replacements was mined.
+        /// # <weight>
+        /// Keys:
+        ///     V: number of validators
+        ///     T: number transaction Ids per account
+        ///  - avn pallet is_validator operation: O(V)
+        ///  - DbReads: `Repository`, `DispatchedAvnTxIds`, `EthTxHashRecordedAt`, `OutcomeAttestations`: O(T)
+        ///  - DbWrites: `OutcomeAttestations`: O(1)
+        ///  - Count the attestations of active validators: O(V^2)
+        ///  - Emit an event: O(1)
+        ///  - If a quorum attested the outcome:
+        ///     - DbReads: `FailedSubmitters`: O(1)
+        ///     - DbMutate: `DispatchedAvnTxIds`: O(T)
+        ///     - DbWrites: `OutcomeAttestations`, `EthTxHashRecordedAt`, `BatchCallIndex`, `ReplacementEthTxHashes`: O(1)
+        ///  - If succeeded:
+        ///     - DbWrites: `TransactionOutcomes`, `FailedSubmitters`, `PruningQueue`, `PruningQueueTail`: O(1)
+        ///  - If failed:
+        ///     - Create and report the offence of the submitter: O(1)
+        ///     - Choose the next submitter: O(V)
+        ///     - DbWrites: `DroppedEthTxHashes`, `FailedSubmitters`, `Repository`, `TransactionsAwaitingConfirmations`: O(1)
+        ///  - Emit events: O(1)
+        /// - Total Complexity: `O(V^2 + T + 1)`
+        /// # </weight>
+        #[weight = T::WeightInfo::set_transaction_outcome(MAX_VALIDATORS, MAX_TXS_PER_ACCOUNT)]
+        fn set_transaction_outcome(
+            origin,
+            validator: T::AccountId,
+            tx_id: TransactionId,
+            eth_tx_hash: EthereumTransactionHash,
+            outcome: EthTransactionOutcome,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+            ensure!(AVN::<T>::is_validator(&validator), Error::<T>::InvalidKey);
+
+            let (submitter, _) = Self::get_dispatched_data(tx_id).ok_or(Error::<T>::MissingDispatchedAvnTx)?;
+
+            // An attestation of an earlier attempt to send the transaction must not count towards the current one
+            let recorded_eth_tx_hash = Self::get_transaction(tx_id).get_eth_tx_hash().ok_or(Error::<T>::MissingEthTxHash)?;
+            ensure!(recorded_eth_tx_hash == eth_tx_hash, Error::<T>::EthTxHashMismatch);
+
+            if outcome == EthTransactionOutcome::Dropped {
+                ensure!(
+                    <system::Module<T>>::block_number() >= Self::get_eth_tx_hash_recorded_at(tx_id) + T::EthTransactionDropDelay::get(),
+                    Error::<T>::TransactionNotDroppedYet
+                );
+            }
+
+            let mut attestations = Self::get_outcome_attestations(tx_id);
+            ensure!(!attestations.contains(&(validator.clone(), outcome)), Error::<T>::DuplicateAttestation);
+
+            // A validator can change its mind, for example if the transaction was mined after it attested it dropped
+            attestations.retain(|(account_id, _)| *account_id != validator);
+            attestations.push((validator.clone(), outcome));
+            <OutcomeAttestations<T>>::insert(tx_id, &attestations);
+            Self::deposit_event(Event::<T>::TransactionOutcomeAttested(tx_id, outcome, validator));
+
+            if !Self::outcome_has_quorum(&attestations, outcome) {
+                return Ok(());
+            }
+
+            let eth_tx_hash_recorded_at = Self::get_eth_tx_hash_recorded_at(tx_id);
+            <OutcomeAttestations<T>>::remove(tx_id);
+            Self::remove_from_dispatched_avn_tx_ids(&submitter, tx_id);
+            <EthTxHashRecordedAt<T>>::remove(tx_id);
+            <BatchCallIndex>::remove(tx_id);
+            <ReplacementEthTxHashes>::remove(tx_id);
+
+            match outcome {
+                EthTransactionOutcome::Succeeded(eth_block_number) => {
+                    <TransactionOutcomes>::insert(tx_id, outcome);
+                    <FailedSubmitters<T>>::remove(tx_id);
+                    Self::schedule_pruning(tx_id);
+                    Self::deposit_event(Event::<T>::TransactionSucceeded(tx_id, eth_tx_hash, eth_block_number));
+                },
+                _ => {
+                    let submitter_deadline = eth_tx_hash_recorded_at + T::EthTransactionDropDelay::get() +
+                        T::DispatchedTransactionTimeout::get();
+                    if <system::Module<T>>::block_number() >= submitter_deadline &&
+                        !attestations.contains(&(submitter.clone(), outcome))
+                    {
+                        create_and_report_transaction_offence::<T>(
+                            &validator,
+                            &vec![submitter.clone()],
+                            EthereumTransactionOffenceType::OutcomeNotRecorded
+                        );
+                    }
+
+                    // A reverted transaction was mined, so only a dropped one can still be replaced by its submitter
+                    if outcome == EthTransactionOutcome::Dropped {
+                        <DroppedEthTxHashes<T>>::append(tx_id, (submitter.clone(), eth_tx_hash));
+                    }
+
+                    Self::deposit_event(Event::<T>::TransactionFailed(tx_id, outcome, submitter.clone()));
+                    Self::reassign_transaction(tx_id, submitter, outcome);
+                }
+            }
+
+            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
+            return Ok(());
+        }
+
+        /// Records the hashes of the transactions the submitter's external service sent to replace a stuck transaction with
+        /// higher fees. Only the submitter knows them, so every validator then checks them along with the recorded hash.
+        /// Each replacement restarts the delay after which the transaction can be attested dropped, so the attestations
+        /// that it was dropped no longer count.
+        /// # <weight>
+        /// Keys:
+        ///     V: number of validators
+        ///     T: number transaction Ids per account
+        ///     R: number of replacements, at most `MAX_REPLACEMENT_ETH_TX_HASHES`
+        ///  - avn pallet is_validator operation: O(V)
+        ///  - DbReads: `Repository`, `DispatchedAvnTxIds`, `ReplacementEthTxHashes`, `OutcomeAttestations`: O(T)
+        ///  - Check the replacements have no duplicates: O(R^2)
+        ///  - DbWrites: `ReplacementEthTxHashes`, `EthTxHashRecordedAt`, `OutcomeAttestations`: O(1)
+        ///  - Emit an event: O(1)
+        /// - Total Complexity: `O(V + T + R^2)`
+        /// # </weight>
+        #[weight = T::WeightInfo::add_replacement_eth_tx_hashes(MAX_VALIDATORS, MAX_TXS_PER_ACCOUNT, MAX_REPLACEMENT_ETH_TX_HASHES)]
+        fn add_replacement_eth_tx_hashes(
+            origin,
+            submitter: T::AccountId,
+            tx_id: TransactionId,
+            eth_tx_hash: EthereumTransactionHash,
+            replacement_eth_tx_hashes: Vec<EthereumTransactionHash>,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+            ensure!(AVN::<T>::is_validator(&submitter), Error::<T>::InvalidKey);
+
+            let (current_submitter, _) = Self::get_dispatched_data(tx_id).ok_or(Error::<T>::MissingDispatchedAvnTx)?;
+            ensure!(current_submitter == submitter, Error::<T>::InvalidTransactionSubmitter);
+
+            let recorded_eth_tx_hash = Self::get_transaction(tx_id).get_eth_tx_hash().ok_or(Error::<T>::MissingEthTxHash)?;
+            ensure!(recorded_eth_tx_hash == eth_tx_hash, Error::<T>::EthTxHashMismatch);
+
+            let mut replacements = Self::get_replacement_eth_tx_hashes(tx_id);
             ensure!(
-                Self::get_transaction(candidate_tx_id).from == Some(T::AccountToBytesConvert::into_bytes(&submitter)),
-                Error::<T>::InvalidTransactionSubmitter
+                !replacement_eth_tx_hashes.is_empty() &&
+                    replacements.len() + replacement_eth_tx_hashes.len() <= MAX_REPLACEMENT_ETH_TX_HASHES as usize,
+                Error::<T>::InvalidReplacementEthTxHashes
             );
+
+            for replacement_eth_tx_hash in replacement_eth_tx_hashes.iter() {
+                ensure!(
+                    *replacement_eth_tx_hash != eth_tx_hash && !replacements.contains(replacement_eth_tx_hash),
+                    Error::<T>::InvalidReplacementEthTxHashes
+                );
+                replacements.push(*replacement_eth_tx_hash);
+            }
+
+            <ReplacementEthTxHashes>::insert(tx_id, replacements);
+            <EthTxHashRecordedAt<T>>::insert(tx_id, <system::Module<T>>::block_number());
+            if <OutcomeAttestations<T>>::contains_key(tx_id) {
+                <OutcomeAttestations<T>>::mutate(tx_id, |attestations|
+                    attestations.retain(|(_, outcome)| *outcome != EthTransactionOutcome::Dropped)
+                );
+            }
+
+            Self::deposit_event(Event::<T>::ReplacementEthTxHashesAdded(tx_id, replacement_eth_tx_hashes));
+
+            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
+            return Ok(());
+        }
+
+        /// Adds the confirmation of a validator to a transaction that was handed over to a new submitter.
+        /// Once there are enough confirmations, the new submitter can send the transaction to Ethereum.
+        /// # <weight>
+        /// Keys:
+        ///     V: number of validators
+        ///  - DbReads: `TransactionsAwaitingConfirmations`, `Confirmers`, `Repository`: O(1)
+        ///  - Eth signature is valid operation: O(V)
+        ///  - DbWrites: `Repository`, `Confirmers`: O(1)
+        ///  - If there are enough confirmations:
+        ///     - DbWrites: `TransactionsAwaitingConfirmations`, `Confirmers`, `DispatchedAvnTxIds`: O(1)
+        ///     - Emit an event: O(1)
+        /// - Total Complexity: `O(V + 1)`
+        /// # </weight>
+        #[weight = T::WeightInfo::add_confirmation(MAX_VALIDATORS)]
+        fn add_confirmation(
+            origin,
+            tx_id: TransactionId,
+            confirmation: ecdsa::Signature,
+            validator: Validator<T::AuthorityId, T::AccountId>,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
             ensure!(
-                DispatchedAvnTxIds::<T>::contains_key(&submitter),
-                Error::<T>::MissingDispatchedAvnTxSubmitter
+                Self::get_transactions_awaiting_confirmations().contains(&tx_id),
+                Error::<T>::TransactionNotAwaitingConfirmations
             );
+            ensure!(!Self::get_confirmers(tx_id).contains(&validator.account_id), Error::<T>::DuplicateConfirmation);
+
+            let mut transaction = Self::get_transaction(tx_id);
+            let data_to_confirm = Self::get_data_to_confirm(&transaction)?;
+            if !AVN::<T>::eth_signature_is_valid(data_to_confirm, &validator, &confirmation) {
+                return Err(avn_error::<T>::InvalidECDSASignature)?;
+            }
+
+            transaction.signatures.add(confirmation).map_err(|_| Error::<T>::InvalidConfirmations)?;
+            <Repository>::insert(tx_id, transaction.clone());
+            <Confirmers<T>>::append(tx_id, validator.account_id);
+
+            if transaction.ready_to_dispatch() {
+                let submitter = T::AccountToBytesConvert::try_from(&transaction.from.expect("Ready transactions have a sender"))?;
+                <TransactionsAwaitingConfirmations>::mutate(|tx_ids| tx_ids.retain(|id| *id != tx_id));
+                <Confirmers<T>>::remove(tx_id);
+                Self::promote_candidate_transaction_to_dispatched(submitter, tx_id);
+            }
+
+            // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
+            return Ok(());
+        }
+
+        /// Lets a validator take over a dispatched transaction that its submitter did not send in time. The submitter is
+        /// reported, and the transaction is confirmed again for the claimant. A sent transaction cannot be claimed: it is
+        /// only handed over once a quorum of validators attest that it failed, so it is never sent twice.
+        /// # <weight>
+        /// Keys:
+        ///     V: number of validators
+        ///     T: number transaction Ids per account
+        ///  - DbReads: `Repository`, `DispatchedAvnTxIds`, `FailedSubmitters`: O(1)
+        ///  - Choose the claimant: O(V)
+        ///  - Create and report the offence of the submitter: O(1)
+        ///  - DbMutate: `DispatchedAvnTxIds`: O(T)
+        ///  - DbWrites: `FailedSubmitters`, `Repository`, `TransactionsAwaitingConfirmations`: O(1)
+        ///  - Emit events: O(1)
+        /// - Total Complexity: `O(V + T + 1)`
+        /// # </weight>
+        #[weight = T::WeightInfo::claim_transaction(MAX_VALIDATORS, MAX_TXS_PER_ACCOUNT)]
+        fn claim_transaction(
+            origin,
+            tx_id: TransactionId,
+            claimant: Validator<T::AuthorityId, T::AccountId>,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+
+            let (submitter, dispatched_data) = Self::get_dispatched_data(tx_id).ok_or(Error::<T>::MissingDispatchedAvnTx)?;
             ensure!(
-                Self::get_dispatched_avn_tx_ids(submitter).iter().any(|data| data.transaction_id == candidate_tx_id),
-                Error::<T>::MissingDispatchedAvnTx
+                Self::get_claimant(tx_id, &submitter, &dispatched_data, <system::Module<T>>::block_number()) ==
+                    Some(claimant.account_id.clone()),
+                Error::<T>::ClaimNotAllowed
             );
 
-            let _ = <Repository>::mutate(candidate_tx_id, |storage_candidate| {
-                storage_candidate.set_eth_tx_hash::<T>(eth_tx_hash)
-            })?;
+            create_and_report_transaction_offence::<T>(
+                &claimant.account_id,
+                &vec![submitter.clone()],
+                EthereumTransactionOffenceType::TransactionNotSent
+            );
 
-            Self::deposit_event(Event::<T>::EthereumTransactionHashAdded(candidate_tx_id, eth_tx_hash));
+            Self::remove_from_dispatched_avn_tx_ids(&submitter, tx_id);
+            <FailedSubmitters<T>>::append(tx_id, submitter.clone());
+            Self::deposit_event(Event::<T>::TransactionClaimed(tx_id, submitter, claimant.account_id.clone()));
+            Self::hand_over_transaction(tx_id, claimant.account_id);
 
             // TODO [TYPE: weightInfo][PRI: medium]: Return accurate weight
             return Ok(());
@@ -229,6 +688,8 @@ decl_module! {
         /// # <weight>
         ///  - DbReads: `ReservedTransactions` : O(1)
         ///  - DbWrites: `ReservedTransactions` * 2 : O(1)
+        ///  - DbReads: `PruningQueueTail` : O(1)
+        ///  - DbWrites: `PruningQueue`, `PruningQueueTail` : O(1)
         ///  - Total Complexity: O(1)
         /// # </weights>
         #[weight = T::WeightInfo::unreserve_transaction()]
@@ -238,10 +699,28 @@ decl_module! {
                 let reserved_tx_id = Self::get_reserved(&transaction_type);
                 <ReservedTransactions>::remove(&transaction_type);
                 <ReservedTransactions>::insert(EthTransactionType::Discarded(reserved_tx_id), reserved_tx_id);
+                Self::schedule_pruning(reserved_tx_id);
             }
             Ok(())
         }
 
+        /// # <weight>
+        /// Keys: P - number of transactions pruned, at most `MaxTransactionsPrunedPerBlock`
+        ///   DbReads: `PruningQueueHead`, `PruningQueueTail`: O(1)
+        ///   For each transaction of the queue that is checked:
+        ///     - DbReads: `PruningQueue`: O(1)
+        ///   For each pruned transaction:
+        ///     - DbWrites: `PruningQueue`, `Repository`, `TransactionOutcomes`, `FailedSubmitters`,
+        ///       `DroppedEthTxHashes`, `ReservedTransactions`: O(1)
+        ///   If any transaction is pruned:
+        ///     - DbWrites: `PruningQueueHead`: O(1)
+        ///     - Emitted event: `TransactionsPruned`: O(1)
+        /// Total Complexity: O(P)
+        /// # </weight>
+        fn on_initialize(block_number: T::BlockNumber) -> Weight {
+            return Self::prune_completed_transactions(block_number);
+        }
+
         /// Offchain Worker entry point.
         fn offchain_worker(block_number: T::BlockNumber) {
             let setup_result = AVN::<T>::pre_run_setup(block_number, NAME.to_vec());
@@ -259,9 +738,11 @@ decl_module! {
 
             // ====================== Choose Offchain-Worker Action ===============
             Self::send_transaction_candidates(&this_validator, block_number);
-
-            // TODO [TYPE: review][PRI: high][CRITICAL][JIRA: 352] add the rest offchain worker logic here, corresponding to the
-            // confirmation loop (eg transactions sent to Ethereum)
+            Self::record_replacements_of_sent_transactions(&this_validator, block_number);
+            Self::check_outcome_of_sent_transactions(&this_validator, block_number);
+            Self::stop_monitoring_dropped_transactions(&this_validator, block_number);
+            Self::confirm_reassigned_transactions(&this_validator, block_number);
+            Self::claim_timed_out_transactions(&this_validator, block_number);
         }
 
         // # <weight>
@@ -294,10 +775,22 @@ impl<T: Config> Module<T> {
         Self::get_dispatched_avn_tx_ids(account_id)
             .into_iter()
             .filter_map(|data| Self::get_transaction_to_send_if_available(data, account_id))
-            .take(MAX_VALUES_RETURNED)
+            .take(Self::max_transactions_per_send())
             .collect()
     }
 
+    fn is_batching_enabled() -> bool {
+        return T::MulticallContractAddress::get() != H160::zero() && T::MaxTransactionsPerBatch::get() > 1;
+    }
+
+    fn max_transactions_per_send() -> usize {
+        if Self::is_batching_enabled() {
+            return T::MaxTransactionsPerBatch::get() as usize;
+        }
+
+        return MAX_VALUES_RETURNED;
+    }
+
     fn get_transaction_to_send_if_available(
         dispatched_data: DispatchedData<T::BlockNumber>,
         account_id: &T::AccountId,
@@ -341,13 +834,38 @@ impl<T: Config> Module<T> {
         return None;
     }
 
+    fn record_eth_tx_hash(
+        submitter: &T::AccountId,
+        tx_id: TransactionId,
+        eth_tx_hash: EthereumTransactionHash,
+    ) -> DispatchResult {
+        ensure!(
+            Self::get_transaction(tx_id).from == Some(T::AccountToBytesConvert::into_bytes(submitter)),
+            Error::<T>::InvalidTransactionSubmitter
+        );
+        ensure!(
+            DispatchedAvnTxIds::<T>::contains_key(submitter),
+            Error::<T>::MissingDispatchedAvnTxSubmitter
+        );
+        ensure!(
+            Self::get_dispatched_avn_tx_ids(submitter).iter().any(|data| data.transaction_id == tx_id),
+            Error::<T>::MissingDispatchedAvnTx
+        );
+
+        let _ = <Repository>::mutate(tx_id, |storage_candidate| {
+            storage_candidate.set_eth_tx_hash::<T>(eth_tx_hash)
+        })?;
+        <EthTxHashRecordedAt<T>>::insert(tx_id, <system::Module<T>>::block_number());
+
+        Self::deposit_event(Event::<T>::EthereumTransactionHashAdded(tx_id, eth_tx_hash));
+
+        return Ok(());
+    }
+
     fn get_contract_address(transaction_type: &EthTransactionType) -> Option<H160> {
-        return match transaction_type {
-            EthTransactionType::PublishRoot(_) => Some(Self::get_publish_root_contract()),
-            EthTransactionType::DeregisterValidator(_) => Some(T::ValidatorManagerContractAddress::get()),
-            EthTransactionType::SlashValidator(_) => Some(T::ValidatorManagerContractAddress::get()),
-            EthTransactionType::ActivateValidator(_) => Some(T::ValidatorManagerContractAddress::get()),
-            _ => None,
+        return match transaction_type.get_contract_role()? {
+            EthContractRole::PublishRoot => Some(Self::get_publish_root_contract()),
+            EthContractRole::ValidatorManager => Some(T::ValidatorManagerContractAddress::get()),
         };
     }
 
@@ -367,6 +885,236 @@ impl<T: Config> Module<T> {
         }
     }
 
+    fn remove_from_dispatched_avn_tx_ids(submitter: &T::AccountId, tx_id: TransactionId) {
+        let mut dispatched_tx_ids = Self::get_dispatched_avn_tx_ids(submitter);
+        dispatched_tx_ids.retain(|data| data.transaction_id != tx_id);
+
+        if dispatched_tx_ids.is_empty() {
+            <DispatchedAvnTxIds<T>>::remove(submitter);
+        } else {
+            <DispatchedAvnTxIds<T>>::insert(submitter, dispatched_tx_ids);
+        }
+    }
+
+    /// Queues a completed or discarded transaction, to be pruned after `TransactionRetentionPeriod`
+    fn schedule_pruning(tx_id: TransactionId) {
+        let tail = Self::get_pruning_queue_tail();
+        <PruningQueue<T>>::insert(tail, (tx_id, <system::Module<T>>::block_number()));
+        <PruningQueueTail>::put(tail + 1);
+    }
+
+    // Prunes the oldest transactions first, and at most `MaxTransactionsPrunedPerBlock` of them per block, so the weight of
+    // a block stays predictable when many transactions complete together. The rest are pruned in the following blocks.
+    fn prune_completed_transactions(block_number: T::BlockNumber) -> Weight {
+        let mut consumed_weight = T::DbWeight::get().reads(2);
+
+        let mut head = Self::get_pruning_queue_head();
+        let tail = Self::get_pruning_queue_tail();
+        let mut pruned_tx_ids = Vec::<TransactionId>::new();
+
+        while head < tail && pruned_tx_ids.len() < T::MaxTransactionsPrunedPerBlock::get() as usize {
+            let (tx_id, completed_at) = Self::get_pruning_queue_entry(head);
+            consumed_weight = consumed_weight.saturating_add(T::DbWeight::get().reads(1));
+            if block_number < completed_at + T::TransactionRetentionPeriod::get() {
+                break;
+            }
+
+            <PruningQueue<T>>::remove(head);
+            <Repository>::remove(tx_id);
+            <TransactionOutcomes>::remove(tx_id);
+            <FailedSubmitters<T>>::remove(tx_id);
+            <DroppedEthTxHashes<T>>::remove(tx_id);
+            <ReservedTransactions>::remove(EthTransactionType::Discarded(tx_id));
+            pruned_tx_ids.push(tx_id);
+            head += 1;
+        }
+
+        if !pruned_tx_ids.is_empty() {
+            <PruningQueueHead>::put(head);
+            consumed_weight = consumed_weight.saturating_add(
+                T::DbWeight::get().writes((pruned_tx_ids.len() as Weight).saturating_mul(6).saturating_add(1)));
+            Self::deposit_event(Event::<T>::TransactionsPruned(pruned_tx_ids));
+        }
+
+        return consumed_weight;
+    }
+
+    /// Hands a failed transaction over to the next active validator that has not failed to send it yet, or records the
+    /// failure as its final outcome if there is no such validator
+    fn reassign_transaction(tx_id: TransactionId, failed_submitter: T::AccountId, outcome: EthTransactionOutcome) {
+        <FailedSubmitters<T>>::append(tx_id, failed_submitter.clone());
+
+        match Self::get_fallback_submitters(&failed_submitter, &Self::get_failed_submitters(tx_id)).into_iter().nth(0) {
+            Some(new_submitter) => Self::hand_over_transaction(tx_id, new_submitter),
+            None => {
+                <TransactionOutcomes>::insert(tx_id, outcome);
+                Self::schedule_pruning(tx_id);
+                Self::deposit_event(Event::<T>::TransactionAbandoned(tx_id));
+            }
+        }
+    }
+
+    fn hand_over_transaction(tx_id: TransactionId, new_submitter: T::AccountId) {
+        <Repository>::mutate(tx_id, |transaction| transaction.reassign(
+            T::AccountToBytesConvert::into_bytes(&new_submitter),
+            AVN::<T>::calculate_two_third_quorum()
+        ));
+        <TransactionsAwaitingConfirmations>::append(tx_id);
+        Self::deposit_event(Event::<T>::TransactionReassigned(tx_id, new_submitter));
+    }
+
+    /// The validators that can take over a transaction from its submitter, in order. They follow the submitter in the
+    /// active validators list, wrapping around, so every node agrees on the order.
+    fn get_fallback_submitters(submitter: &T::AccountId, failed_submitters: &Vec<T::AccountId>) -> Vec<T::AccountId> {
+        let validators = AVN::<T>::active_validators();
+        let start = validators.iter()
+            .position(|validator| validator.account_id == *submitter)
+            .map_or(0, |index| index + 1);
+
+        return validators.iter()
+            .cycle()
+            .skip(start)
+            .take(validators.len())
+            .map(|validator| validator.account_id.clone())
+            .filter(|account_id| account_id != submitter && !failed_submitters.contains(account_id))
+            .collect();
+    }
+
+    /// True if enough of the active validators attested `outcome`
+    fn outcome_has_quorum(attestations: &Vec<(T::AccountId, EthTransactionOutcome)>, outcome: EthTransactionOutcome) -> bool {
+        let attested_by = attestations.iter()
+            .filter(|(account_id, attested_outcome)| *attested_outcome == outcome && AVN::<T>::is_validator(account_id))
+            .count() as u32;
+
+        return attested_by >= AVN::<T>::calculate_two_third_quorum();
+    }
+
+    fn get_dispatched_data(tx_id: TransactionId) -> Option<(T::AccountId, DispatchedData<T::BlockNumber>)> {
+        let from = Self::get_transaction(tx_id).from?;
+        let submitter = T::AccountToBytesConvert::try_from(&from).ok()?;
+        let dispatched_data = Self::get_dispatched_avn_tx_ids(&submitter)
+            .into_iter()
+            .find(|data| data.transaction_id == tx_id)?;
+
+        return Some((submitter, dispatched_data));
+    }
+
+    /// The validator that can claim a dispatched transaction at `block_number`, if any. The submitter has
+    /// `DispatchedTransactionTimeout` blocks to send the transaction. After that each fallback submitter in turn gets a
+    /// period of the same length to claim it. Once the submitter records that it sent the transaction, nobody can claim
+    /// it: whether it must be sent again depends on its outcome on Ethereum.
+    pub fn get_claimant(
+        tx_id: TransactionId,
+        submitter: &T::AccountId,
+        dispatched_data: &DispatchedData<T::BlockNumber>,
+        block_number: T::BlockNumber,
+    ) -> Option<T::AccountId> {
+        if Self::get_transaction(tx_id).get_eth_tx_hash().is_some() {
+            return None;
+        }
+
+        let timeout = T::DispatchedTransactionTimeout::get();
+        let deadline = dispatched_data.submitted_at_block + timeout;
+
+        let fallback_submitters = Self::get_fallback_submitters(submitter, &Self::get_failed_submitters(tx_id));
+        if block_number < deadline || fallback_submitters.is_empty() || timeout.is_zero() {
+            return None;
+        }
+
+        let period = ((block_number - deadline) / timeout).saturated_into::<usize>();
+        return fallback_submitters.into_iter().nth(period % fallback_submitters.len());
+    }
+
+    /// The data every confirmation of a transaction signs. It includes the sender, so a transaction needs new
+    /// confirmations when it changes hands.
+    pub fn get_data_to_confirm(transaction: &EthTransactionCandidate) -> Result<String, DispatchError> {
+        let from = transaction.from.ok_or(Error::<T>::MissingDispatchedAvnTxSubmitter)?;
+        let eth_description = EthAbiHelper::generate_ethereum_description_for_signature_request(
+            &from,
+            &transaction.call_data,
+            transaction.tx_id
+        )
+        .map_err(|_| Error::<T>::InvalidConfirmations)?;
+
+        return Ok(hex::encode(EthAbiHelper::generate_eth_abi_encoding_for_params_only(&eth_description)));
+    }
+
+    /// The outcome of a sent transaction, or None if it is too early to tell
+    pub fn get_outcome(
+        tx_status: EthTransactionStatus,
+        eth_tx_hash_recorded_at: T::BlockNumber,
+        block_number: T::BlockNumber,
+    ) -> Option<EthTransactionOutcome> {
+        return match tx_status {
+            EthTransactionStatus::Mined { num_confirmations, .. } if num_confirmations < T::MinEthBlockConfirmation::get() => None,
+            EthTransactionStatus::Mined { succeeded: true, block_number: eth_block_number, .. } =>
+                Some(EthTransactionOutcome::Succeeded(eth_block_number)),
+            EthTransactionStatus::Mined { succeeded: false, .. } => Some(EthTransactionOutcome::Reverted),
+            EthTransactionStatus::NotMined if block_number >= eth_tx_hash_recorded_at + T::EthTransactionDropDelay::get() =>
+                Some(EthTransactionOutcome::Dropped),
+            EthTransactionStatus::NotMined => None,
+        };
+    }
+
+    /// The status of a transaction sent in a batch only reports it succeeded if its own call in the batch succeeded
+    fn get_transaction_status(
+        eth_tx_hash: EthereumTransactionHash,
+        batch_call_index: Option<u32>,
+    ) -> Result<EthTransactionStatus, DispatchError> {
+        let mut url_path = String::from("eth/tx_status/0x");
+        url_path.push_str(&hex::encode(eth_tx_hash.as_bytes()));
+        if let Some(index) = batch_call_index {
+            url_path.push_str(&"/".to_string());
+            url_path.push_str(&index.to_string());
+        }
+
+        let response = AVN::<T>::get_data_from_service(url_path)?;
+        let tx_status_bytes = hex::decode(&response).map_err(|_| Error::<T>::InvalidHexString)?;
+        let tx_status = EthTransactionStatus::decode(&mut &tx_status_bytes[..])
+            .map_err(|_| Error::<T>::ErrorGettingTransactionStatus)?;
+
+        return Ok(tx_status);
+    }
+
+    /// The status of whichever of the recorded hash and its replacements was mined. They all use the same nonce, so at
+    /// most one of them can be mined.
+    fn get_status_of_sent_transaction(
+        tx_id: TransactionId,
+        eth_tx_hash: EthereumTransactionHash,
+    ) -> Result<EthTransactionStatus, DispatchError> {
+        let batch_call_index = Self::get_batch_call_index(tx_id);
+
+        for hash in vec![eth_tx_hash].into_iter().chain(Self::get_replacement_eth_tx_hashes(tx_id).into_iter()) {
+            let tx_status = Self::get_transaction_status(hash, batch_call_index)?;
+            if tx_status != EthTransactionStatus::NotMined {
+                return Ok(tx_status);
+            }
+        }
+
+        return Ok(EthTransactionStatus::NotMined);
+    }
+
+    /// The hash sent to Ethereum followed by the hashes of the transactions the external service replaced it with
+    fn get_eth_tx_hashes(eth_tx_hash: EthereumTransactionHash) -> Result<Vec<EthereumTransactionHash>, DispatchError> {
+        let mut url_path = String::from("eth/tx_hashes/0x");
+        url_path.push_str(&hex::encode(eth_tx_hash.as_bytes()));
+
+        let response = AVN::<T>::get_data_from_service(url_path)?;
+        let eth_tx_hashes_bytes = hex::decode(&response).map_err(|_| Error::<T>::InvalidHexString)?;
+        let eth_tx_hashes = Vec::<EthereumTransactionHash>::decode(&mut &eth_tx_hashes_bytes[..])
+            .map_err(|_| Error::<T>::ErrorGettingEthTxHashes)?;
+
+        return Ok(eth_tx_hashes);
+    }
+
+    fn stop_monitoring_eth_transaction(eth_tx_hash: EthereumTransactionHash) -> Result<(), DispatchError> {
+        let mut url_path = String::from("eth/stop_monitoring/0x");
+        url_path.push_str(&hex::encode(eth_tx_hash.as_bytes()));
+
+        AVN::<T>::post_data_to_service(url_path, vec![])?;
+
+        return Ok(());
+    }
 
     // TODO [TYPE: refactoring][PRI: medium]: Centralise logic, possibly into a separate service helper module
     pub fn send_transaction_to_ethereum(
@@ -374,12 +1122,13 @@ impl<T: Config> Module<T> {
     ) -> Result<H256, DispatchError> {
         let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(2_000));
         let external_service_port_number = AVN::<T>::get_external_service_port_number();
+        let auth_secret = AVN::<T>::get_external_service_auth_secret();
 
         let mut url = String::from("http://127.0.0.1:");
         url.push_str(&external_service_port_number);
         url.push_str(&"/eth/send".to_string());
 
-        let pending = http::Request::default()
+        let pending = AVN::<T>::with_external_service_auth(http::Request::default(), &auth_secret)
             .deadline(deadline)
             .method(http::Method::Post)
             .url(&url)
@@ -392,12 +1141,11 @@ impl<T: Config> Module<T> {
             .map_err(|_| Error::<T>::DeadlineReached)?
             .map_err(|_| Error::<T>::DeadlineReached)?;
 
+        let result: Vec<u8> = response.body().collect::<Vec<u8>>();
         if response.code != 200 {
-            debug::native::error!("❌ Unexpected status code: {}", response.code);
-            return Err(Error::<T>::UnexpectedStatusCode)?;
+            return Err(AVN::<T>::get_external_service_error(response.code, &result))?;
         }
 
-        let result: Vec<u8> = response.body().collect::<Vec<u8>>();
         if result.len() != 64 {
             debug::native::error!("❌ Ethereum transaction hash is not valid: {:?}", result);
             return Err(Error::<T>::InvalidHashLength)?;
@@ -443,38 +1191,185 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    // ================================= Offchain Worker Helpers ========================================
+    fn issue_set_eth_tx_hash_for_batch(
+        tx_ids: Vec<TransactionId>,
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        eth_tx_hash: H256,
+    ) -> Result<(), Error<T>> {
+        let data_to_sign = (&authority.account_id, &tx_ids, eth_tx_hash);
 
+        let signature = authority
+            .key
+            .sign(&(SET_ETH_TX_HASH_FOR_BATCH, data_to_sign).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
 
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::set_eth_tx_hash_for_batch(authority.account_id.clone(), tx_ids, eth_tx_hash, signature).into(),
+        )
+        .map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
 
+        Ok(())
+    }
 
+    fn issue_set_transaction_outcome(
+        tx_id: TransactionId,
+        eth_tx_hash: EthereumTransactionHash,
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        outcome: EthTransactionOutcome,
+    ) -> Result<(), Error<T>> {
+        let data_to_sign = (&authority.account_id, &tx_id, eth_tx_hash, outcome);
 
-    fn generate_sending_lock_name(candidate_id: TransactionId) -> OcwLock::PersistentId {
+        let signature = authority
+            .key
+            .sign(&(SET_TRANSACTION_OUTCOME, data_to_sign).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::set_transaction_outcome(authority.account_id.clone(), tx_id, eth_tx_hash, outcome, signature).into(),
+        )
+        .map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    fn issue_add_replacement_eth_tx_hashes(
+        tx_id: TransactionId,
+        eth_tx_hash: EthereumTransactionHash,
+        replacement_eth_tx_hashes: Vec<EthereumTransactionHash>,
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+    ) -> Result<(), Error<T>> {
+        let data_to_sign = (&authority.account_id, &tx_id, eth_tx_hash, &replacement_eth_tx_hashes);
+
+        let signature = authority
+            .key
+            .sign(&(ADD_REPLACEMENT_ETH_TX_HASHES, data_to_sign).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::add_replacement_eth_tx_hashes(
+                authority.account_id.clone(),
+                tx_id,
+                eth_tx_hash,
+                replacement_eth_tx_hashes,
+                signature,
+            )
+            .into(),
+        )
+        .map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    fn issue_add_confirmation(
+        tx_id: TransactionId,
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        confirmation: ecdsa::Signature,
+    ) -> Result<(), Error<T>> {
+        let signature = authority
+            .key
+            .sign(&(ADD_CONFIRMATION, tx_id, confirmation.encode()).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::add_confirmation(tx_id, confirmation, authority.clone(), signature).into(),
+        )
+        .map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    fn issue_claim_transaction(
+        tx_id: TransactionId,
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+    ) -> Result<(), Error<T>> {
+        let signature = authority
+            .key
+            .sign(&(CLAIM_TRANSACTION, tx_id).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::claim_transaction(tx_id, authority.clone(), signature).into(),
+        )
+        .map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
+    // ================================= Offchain Worker Helpers ========================================
+
+
+
+
+
+    fn generate_sending_lock_name(candidate_id: TransactionId) -> OcwLock::PersistentId {
         let mut name = b"eth_transactions::lock::tx_id::".to_vec();
         name.extend_from_slice(&mut &candidate_id.to_le_bytes()[..]);
         name
     }
 
+    fn generate_outcome_check_lock_name(tx_id: TransactionId) -> OcwLock::PersistentId {
+        let mut name = b"eth_transactions::outcome_check::tx_id::".to_vec();
+        name.extend_from_slice(&mut &tx_id.to_le_bytes()[..]);
+        name
+    }
+
+    fn generate_replacement_check_lock_name(tx_id: TransactionId) -> OcwLock::PersistentId {
+        let mut name = b"eth_transactions::replacement_check::tx_id::".to_vec();
+        name.extend_from_slice(&mut &tx_id.to_le_bytes()[..]);
+        name
+    }
+
+    fn generate_stop_monitoring_lock_name(tx_id: TransactionId) -> OcwLock::PersistentId {
+        let mut name = b"eth_transactions::stop_monitoring::tx_id::".to_vec();
+        name.extend_from_slice(&mut &tx_id.to_le_bytes()[..]);
+        name
+    }
+
+    fn generate_confirmation_lock_name(tx_id: TransactionId) -> OcwLock::PersistentId {
+        let mut name = b"eth_transactions::confirmation::tx_id::".to_vec();
+        name.extend_from_slice(&mut &tx_id.to_le_bytes()[..]);
+        name
+    }
+
+    fn generate_claim_lock_name(tx_id: TransactionId) -> OcwLock::PersistentId {
+        let mut name = b"eth_transactions::claim::tx_id::".to_vec();
+        name.extend_from_slice(&mut &tx_id.to_le_bytes()[..]);
+        name
+    }
+
     fn is_transaction_locked_for_sending(candidate_id: &TransactionId) -> bool {
         let persistent_data = Self::generate_sending_lock_name(*candidate_id);
         return OcwLock::is_locked(&persistent_data);
     }
 
+    /// True if the external service reported that the transaction was not sent, so sending it again cannot duplicate it
+    fn can_resend_immediately(error: &DispatchError) -> bool {
+        return *error == avn_error::<T>::EthereumNodeUnavailable.into() ||
+            *error == avn_error::<T>::EthereumNonceTooLow.into();
+    }
+
     fn send_transaction_candidates(
         authority: &Validator<T::AuthorityId, T::AccountId>,
         block_number: T::BlockNumber,
     ) {
-        for (tx_id, eth_transaction) in Self::transactions_ready_to_be_sent(&authority.account_id) {
-            if OcwLock::set_lock_with_expiry(
-                block_number,
-                OcwOperationExpiration::Custom(ETHEREUM_SEND_BLOCKS_EXPIRY),
-                Self::generate_sending_lock_name(tx_id),
+        let transactions_to_send: Vec<(TransactionId, EthTransaction)> = Self::transactions_ready_to_be_sent(&authority.account_id)
+            .into_iter()
+            .filter(|(tx_id, _)|
+                OcwLock::set_lock_with_expiry(
+                    block_number,
+                    OcwOperationExpiration::Custom(ETHEREUM_SEND_BLOCKS_EXPIRY),
+                    Self::generate_sending_lock_name(*tx_id),
+                )
+                .is_ok()
             )
-            .is_err()
-            {
-                continue;
-            }
+            .collect();
 
+        if transactions_to_send.len() > 1 {
+            Self::send_batch_of_transactions(authority, block_number, transactions_to_send);
+            return;
+        }
+
+        for (tx_id, eth_transaction) in transactions_to_send {
             // We don't send that often so an info log here should be ok.
             debug::native::info!("ℹ️ Sending transaction (tx Id: {:?}) to Ethereum", tx_id);
 
@@ -498,7 +1393,281 @@ impl<T: Config> Module<T> {
                         "External service could not send transaction to Ethereum: {:?}",
                         e
                     );
+                    Self::release_sending_locks_if_not_sent(&e, block_number, &[tx_id]);
+                }
+            }
+        }
+    }
+
+    fn send_batch_of_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+        transactions_to_send: Vec<(TransactionId, EthTransaction)>,
+    ) {
+        let (tx_ids, eth_transactions): (Vec<TransactionId>, Vec<EthTransaction>) = transactions_to_send.into_iter().unzip();
+
+        let batch_transaction = match EthAbiHelper::generate_batch_transaction_abi(
+            T::AccountToBytesConvert::into_bytes(&authority.account_id),
+            T::MulticallContractAddress::get(),
+            &eth_transactions,
+        ) {
+            Ok(batch_transaction) => batch_transaction,
+            Err(e) => {
+                debug::native::error!("Error abi encoding batch of transactions {:?}: {:#?}", tx_ids, e);
+                Self::release_sending_locks(block_number, &tx_ids);
+                return;
+            }
+        };
+
+        debug::native::info!("ℹ️ Sending a batch of transactions (tx Ids: {:?}) to Ethereum", tx_ids);
+
+        match Self::send_transaction_to_ethereum(batch_transaction) {
+            Ok(eth_tx_hash) => {
+                if let Err(e) = Self::issue_set_eth_tx_hash_for_batch(tx_ids, authority, eth_tx_hash) {
+                    debug::native::error!("Error updating avn transactions with batch eth tx hash: {:#?}", e);
+                }
+            }
+            Err(e) => {
+                debug::native::info!("External service could not send batch of transactions to Ethereum: {:?}", e);
+                Self::release_sending_locks_if_not_sent(&e, block_number, &tx_ids);
+            }
+        }
+    }
+
+    fn release_sending_locks_if_not_sent(error: &DispatchError, block_number: T::BlockNumber, tx_ids: &[TransactionId]) {
+        if Self::can_resend_immediately(error) {
+            Self::release_sending_locks(block_number, tx_ids);
+        }
+    }
+
+    /// Releases the locks so we try again in the next run instead of waiting for them to expire
+    fn release_sending_locks(block_number: T::BlockNumber, tx_ids: &[TransactionId]) {
+        for tx_id in tx_ids {
+            let _ = OcwLock::remove_storage_lock(
+                block_number,
+                OcwOperationExpiration::Custom(ETHEREUM_SEND_BLOCKS_EXPIRY),
+                Self::generate_sending_lock_name(*tx_id),
+            );
+        }
+    }
+
+    /// Transactions of any submitter whose Ethereum transaction hash was recorded, but whose outcome was not
+    fn transactions_awaiting_outcome() -> Vec<(TransactionId, EthereumTransactionHash)> {
+        return <DispatchedAvnTxIds<T>>::iter()
+            .flat_map(|(submitter, dispatched_tx_ids)| {
+                let from = Some(T::AccountToBytesConvert::into_bytes(&submitter));
+                dispatched_tx_ids.into_iter()
+                    .map(|data| Self::get_transaction(data.transaction_id))
+                    .filter(move |transaction| transaction.from == from)
+            })
+            .filter_map(|transaction| transaction.get_eth_tx_hash().map(|eth_tx_hash| (transaction.tx_id, eth_tx_hash)))
+            .filter(|(tx_id, _)| !OcwLock::is_locked(&Self::generate_outcome_check_lock_name(*tx_id)))
+            .take(MAX_VALUES_RETURNED)
+            .collect();
+    }
+
+    /// The submitter records the replacements its external service sent for its stuck transactions, so the other
+    /// validators check them too when they attest the outcome
+    fn record_replacements_of_sent_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+    ) {
+        let from = Some(T::AccountToBytesConvert::into_bytes(&authority.account_id));
+        let sent_transactions: Vec<(TransactionId, EthereumTransactionHash)> =
+            Self::get_dispatched_avn_tx_ids(&authority.account_id)
+                .into_iter()
+                .map(|data| Self::get_transaction(data.transaction_id))
+                .filter(|transaction| transaction.from == from)
+                .filter_map(|transaction| transaction.get_eth_tx_hash().map(|eth_tx_hash| (transaction.tx_id, eth_tx_hash)))
+                .filter(|(tx_id, _)| !OcwLock::is_locked(&Self::generate_replacement_check_lock_name(*tx_id)))
+                .take(MAX_VALUES_RETURNED)
+                .collect();
+
+        for (tx_id, eth_tx_hash) in sent_transactions {
+            if OcwLock::set_lock_with_expiry(
+                block_number,
+                OcwOperationExpiration::Custom(REPLACEMENT_CHECK_BLOCKS_EXPIRY),
+                Self::generate_replacement_check_lock_name(tx_id),
+            )
+            .is_err()
+            {
+                continue;
+            }
+
+            let eth_tx_hashes = match Self::get_eth_tx_hashes(eth_tx_hash) {
+                Ok(eth_tx_hashes) => eth_tx_hashes,
+                Err(e) => {
+                    debug::native::info!("External service could not get the replacements of transaction {:?}: {:?}", eth_tx_hash, e);
+                    continue;
+                }
+            };
+
+            let recorded_replacements = Self::get_replacement_eth_tx_hashes(tx_id);
+            let mut new_replacements: Vec<EthereumTransactionHash> = eth_tx_hashes.into_iter()
+                .filter(|hash| *hash != eth_tx_hash && !recorded_replacements.contains(hash))
+                .collect();
+            if new_replacements.is_empty() {
+                continue;
+            }
+
+            let free_slots = (MAX_REPLACEMENT_ETH_TX_HASHES as usize).saturating_sub(recorded_replacements.len());
+            if new_replacements.len() > free_slots {
+                debug::native::warn!(
+                    "⚠️ Transaction (tx Id: {:?}) was replaced more than {} times, the latest replacements are not recorded",
+                    tx_id, MAX_REPLACEMENT_ETH_TX_HASHES
+                );
+                new_replacements.truncate(free_slots);
+                if new_replacements.is_empty() {
+                    continue;
+                }
+            }
+
+            debug::native::info!("ℹ️ Recording replacements of transaction (tx Id: {:?}): {:?}", tx_id, new_replacements);
+
+            if let Err(e) = Self::issue_add_replacement_eth_tx_hashes(tx_id, eth_tx_hash, new_replacements, authority) {
+                debug::native::error!("💔 Error recording the replacements of transaction {:?}: {:?}", tx_id, e);
+            }
+        }
+    }
+
+    /// Every validator attests the outcome of the sent transactions, not just their submitter, so a submitter cannot
+    /// record an outcome on its own
+    fn check_outcome_of_sent_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+    ) {
+        for (tx_id, eth_tx_hash) in Self::transactions_awaiting_outcome() {
+            if OcwLock::set_lock_with_expiry(
+                block_number,
+                OcwOperationExpiration::Custom(OUTCOME_CHECK_BLOCKS_EXPIRY),
+                Self::generate_outcome_check_lock_name(tx_id),
+            )
+            .is_err()
+            {
+                continue;
+            }
+
+            let tx_status = match Self::get_status_of_sent_transaction(tx_id, eth_tx_hash) {
+                Ok(tx_status) => tx_status,
+                Err(e) => {
+                    debug::native::info!("External service could not get the status of transaction {:?}: {:?}", eth_tx_hash, e);
+                    continue;
                 }
+            };
+
+            if let Some(outcome) = Self::get_outcome(tx_status, Self::get_eth_tx_hash_recorded_at(tx_id), block_number) {
+                if Self::get_outcome_attestations(tx_id).contains(&(authority.account_id.clone(), outcome)) {
+                    continue;
+                }
+
+                debug::native::info!("ℹ️ Transaction (tx Id: {:?}) outcome on Ethereum: {:?}", tx_id, outcome);
+
+                if let Err(e) = Self::issue_set_transaction_outcome(tx_id, eth_tx_hash, authority, outcome) {
+                    debug::native::error!("💔 Error setting the outcome of transaction {:?}: {:?}", tx_id, e);
+                }
+            }
+        }
+    }
+
+    /// A submitter whose transaction was dropped and handed over asks its external service to stop replacing it, so
+    /// the transaction is not mined by both submitters
+    fn stop_monitoring_dropped_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+    ) {
+        let dropped_transactions: Vec<(TransactionId, EthereumTransactionHash)> = <DroppedEthTxHashes<T>>::iter()
+            .flat_map(|(tx_id, dropped_eth_tx_hashes)|
+                dropped_eth_tx_hashes.into_iter().map(move |(submitter, eth_tx_hash)| (tx_id, submitter, eth_tx_hash))
+            )
+            .filter(|(_, submitter, _)| *submitter == authority.account_id)
+            .map(|(tx_id, _, eth_tx_hash)| (tx_id, eth_tx_hash))
+            .filter(|(tx_id, _)| !OcwLock::is_locked(&Self::generate_stop_monitoring_lock_name(*tx_id)))
+            .take(MAX_VALUES_RETURNED)
+            .collect();
+
+        for (tx_id, eth_tx_hash) in dropped_transactions {
+            if OcwLock::set_lock_with_expiry(
+                block_number,
+                OcwOperationExpiration::Custom(STOP_MONITORING_BLOCKS_EXPIRY),
+                Self::generate_stop_monitoring_lock_name(tx_id),
+            )
+            .is_err()
+            {
+                continue;
+            }
+
+            if let Err(e) = Self::stop_monitoring_eth_transaction(eth_tx_hash) {
+                debug::native::error!("💔 Error stopping the monitoring of transaction {:?}: {:?}", eth_tx_hash, e);
+            }
+        }
+    }
+
+    fn confirm_reassigned_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+    ) {
+        let tx_ids_to_confirm: Vec<TransactionId> = Self::get_transactions_awaiting_confirmations()
+            .into_iter()
+            .filter(|tx_id| !Self::get_confirmers(tx_id).contains(&authority.account_id))
+            .filter(|tx_id| !OcwLock::is_locked(&Self::generate_confirmation_lock_name(*tx_id)))
+            .take(MAX_VALUES_RETURNED)
+            .collect();
+
+        for tx_id in tx_ids_to_confirm {
+            if OcwLock::set_lock_with_expiry(
+                block_number,
+                OcwOperationExpiration::Custom(CONFIRMATION_BLOCKS_EXPIRY),
+                Self::generate_confirmation_lock_name(tx_id),
+            )
+            .is_err()
+            {
+                continue;
+            }
+
+            let confirmation = Self::get_data_to_confirm(&Self::get_transaction(tx_id))
+                .and_then(|data| AVN::<T>::request_ecdsa_signature_from_external_service(&data));
+
+            match confirmation {
+                Ok(confirmation) => {
+                    if let Err(e) = Self::issue_add_confirmation(tx_id, authority, confirmation) {
+                        debug::native::error!("💔 Error confirming transaction {:?}: {:?}", tx_id, e);
+                    }
+                },
+                Err(e) => debug::native::error!("💔 Error signing transaction {:?}: {:?}", tx_id, e),
+            }
+        }
+    }
+
+    fn claim_timed_out_transactions(
+        authority: &Validator<T::AuthorityId, T::AccountId>,
+        block_number: T::BlockNumber,
+    ) {
+        let tx_ids_to_claim: Vec<TransactionId> = <DispatchedAvnTxIds<T>>::iter()
+            .filter(|(submitter, _)| *submitter != authority.account_id)
+            .flat_map(|(submitter, dispatched_tx_ids)| dispatched_tx_ids.into_iter().map(move |data| (submitter.clone(), data)))
+            .filter(|(submitter, data)|
+                Self::get_claimant(data.transaction_id, submitter, data, block_number) == Some(authority.account_id.clone())
+            )
+            .map(|(_, data)| data.transaction_id)
+            .filter(|tx_id| !OcwLock::is_locked(&Self::generate_claim_lock_name(*tx_id)))
+            .take(MAX_VALUES_RETURNED)
+            .collect();
+
+        for tx_id in tx_ids_to_claim {
+            if OcwLock::set_lock_with_expiry(
+                block_number,
+                OcwOperationExpiration::Custom(CLAIM_BLOCKS_EXPIRY),
+                Self::generate_claim_lock_name(tx_id),
+            )
+            .is_err()
+            {
+                continue;
+            }
+
+            debug::native::info!("ℹ️ Claiming transaction (tx Id: {:?}) from its submitter", tx_id);
+
+            if let Err(e) = Self::issue_claim_transaction(tx_id, authority) {
+                debug::native::error!("💔 Error claiming transaction {:?}: {:?}", tx_id, e);
             }
         }
     }
@@ -543,6 +1712,85 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
                 .longevity(64_u64)
                 .propagate(true)
                 .build()
+        } else if let Call::set_eth_tx_hash_for_batch(submitter, tx_ids, eth_tx_hash, signature) = call {
+            let submitter_validator = AVN::<T>::try_get_validator(&submitter);
+            if submitter_validator.is_none() {
+                return InvalidTransaction::Custom(SUBMITTER_IS_NOT_VALIDATOR).into();
+            }
+            if !AVN::<T>::signature_is_valid(
+                &(SET_ETH_TX_HASH_FOR_BATCH, (&submitter, &tx_ids, eth_tx_hash)),
+                &submitter_validator.expect("If it got here, its not none"),
+                signature,
+            ) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumTransactions")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(SET_ETH_TX_HASH_FOR_BATCH, submitter, tx_ids, eth_tx_hash).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
+        } else if let Call::set_transaction_outcome(validator, tx_id, eth_tx_hash, outcome, signature) = call {
+            let attesting_validator = AVN::<T>::try_get_validator(&validator);
+            if attesting_validator.is_none() {
+                return InvalidTransaction::Custom(SUBMITTER_IS_NOT_VALIDATOR).into();
+            }
+            if !AVN::<T>::signature_is_valid(
+                &(SET_TRANSACTION_OUTCOME, (&validator, &tx_id, eth_tx_hash, outcome)),
+                &attesting_validator.expect("If it got here, its not none"),
+                signature,
+            ) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumTransactions")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(SET_TRANSACTION_OUTCOME, validator, tx_id, eth_tx_hash, outcome).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
+        } else if let Call::add_replacement_eth_tx_hashes(submitter, tx_id, eth_tx_hash, replacement_eth_tx_hashes, signature) = call {
+            let submitter_validator = AVN::<T>::try_get_validator(&submitter);
+            if submitter_validator.is_none() {
+                return InvalidTransaction::Custom(SUBMITTER_IS_NOT_VALIDATOR).into();
+            }
+            if !AVN::<T>::signature_is_valid(
+                &(ADD_REPLACEMENT_ETH_TX_HASHES, (&submitter, &tx_id, eth_tx_hash, &replacement_eth_tx_hashes)),
+                &submitter_validator.expect("If it got here, its not none"),
+                signature,
+            ) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumTransactions")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(ADD_REPLACEMENT_ETH_TX_HASHES, submitter, tx_id, replacement_eth_tx_hashes).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
+        } else if let Call::add_confirmation(tx_id, confirmation, validator, signature) = call {
+            if !AVN::<T>::signature_is_valid(&(ADD_CONFIRMATION, tx_id, confirmation.encode()), validator, signature) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumTransactions")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(ADD_CONFIRMATION, tx_id, &validator.account_id).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
+        } else if let Call::claim_transaction(tx_id, claimant, signature) = call {
+            if !AVN::<T>::signature_is_valid(&(CLAIM_TRANSACTION, tx_id), claimant, signature) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            ValidTransaction::with_tag_prefix("EthereumTransactions")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(CLAIM_TRANSACTION, tx_id).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build()
         } else {
             return InvalidTransaction::Call.into();
         }
@@ -571,6 +1819,9 @@ impl<T: Config> CandidateTransactionSubmitter<T::AccountId> for Module<T> {
 
     fn reserve_transaction_id(candidate_type: &EthTransactionType) -> Result<TransactionId, DispatchError> {
         ensure!(!<ReservedTransactions>::contains_key(candidate_type), Error::<T>::TransactionExists);
+        if let EthTransactionType::ContractCall(contract_call) = candidate_type {
+            ensure!(contract_call.to_abi().is_ok(), Error::<T>::InvalidContractCall);
+        }
 
         let reserved_transaction_id = Self::get_unique_transaction_identifier();
         <ReservedTransactions>::insert(candidate_type, reserved_transaction_id);
@@ -2,31 +2,34 @@
 
 #![cfg(test)]
 
-use frame_support::{parameter_types, weights::Weight, BasicExternalities};
+use frame_support::{assert_ok, parameter_types, traits::Get, weights::Weight, BasicExternalities};
 use hex_literal::hex;
 use sp_core::{
     crypto::KeyTypeId,
     offchain::{
-        testing::{OffchainState, PoolState, TestOffchainExt, TestTransactionPoolExt},
+        testing::{OffchainState, PendingRequest, PoolState, TestOffchainExt, TestTransactionPoolExt},
         OffchainExt, TransactionPoolExt,
     },
     H256,
 };
 use sp_io::TestExternalities;
+use sp_staking::{SessionIndex, offence::{ReportOffence, OffenceError}};
 use sp_runtime::{
-    testing::{Header, TestXt, UintAuthorityId},
+    testing::{Header, TestSignature, TestXt, UintAuthorityId},
     traits::{BlakeTwo256, ConvertInto, IdentityLookup},
     Perbill,
 };
 use std::cell::RefCell;
 
 use frame_system as system;
+use system::RawOrigin;
 
 use codec::{alloc::sync::Arc};
 use parking_lot::RwLock;
 use pallet_session as session;
 
 use crate::{self as ethereum_transactions, *};
+use crate::ethereum_transaction::PublishRootData;
 use pallet_avn::{testing::U64To32BytesConverter, FinalisedBlockChecker};
 
 pub type AccountId = <TestRuntime as system::Config>::AccountId;
@@ -62,6 +65,24 @@ pub mod crypto {
 
 parameter_types! {
 	pub TestValidatorManagerContractAddress: H160 = CUSTOM_VALIDATOR_MANAGER_CONTRACT;
+	pub const MinEthBlockConfirmation: u64 = 20;
+	pub const EthTransactionDropDelay: u64 = 10;
+	pub const DispatchedTransactionTimeout: u64 = 20;
+	pub const MaxTransactionsPerBatch: u32 = 3;
+	pub const TransactionRetentionPeriod: u64 = 100;
+	pub const MaxTransactionsPrunedPerBlock: u32 = 2;
+}
+
+thread_local! {
+    // Batching is disabled until a test sets the multicall contract
+    pub static MULTICALL_CONTRACT: RefCell<H160> = RefCell::new(H160::zero());
+}
+
+pub struct TestMulticallContractAddress;
+impl Get<H160> for TestMulticallContractAddress {
+    fn get() -> H160 {
+        MULTICALL_CONTRACT.with(|contract| *contract.borrow())
+    }
 }
 
 impl Config for TestRuntime {
@@ -69,6 +90,14 @@ impl Config for TestRuntime {
     type Call = Call;
     type AccountToBytesConvert = U64To32BytesConverter;
     type ValidatorManagerContractAddress = TestValidatorManagerContractAddress;
+    type MinEthBlockConfirmation = MinEthBlockConfirmation;
+    type EthTransactionDropDelay = EthTransactionDropDelay;
+    type DispatchedTransactionTimeout = DispatchedTransactionTimeout;
+    type ReportTransactionOffence = OffenceHandler;
+    type MulticallContractAddress = TestMulticallContractAddress;
+    type MaxTransactionsPerBatch = MaxTransactionsPerBatch;
+    type TransactionRetentionPeriod = TransactionRetentionPeriod;
+    type MaxTransactionsPrunedPerBlock = MaxTransactionsPrunedPerBlock;
     type WeightInfo = ();
 }
 
@@ -134,8 +163,6 @@ thread_local! {
     pub static VALIDATORS: RefCell<Option<Vec<u64>>> = RefCell::new(Some(vec![1, 2, 3]));
 }
 
-pub type SessionIndex = u32;
-
 pub struct TestSessionManager;
 impl session::SessionManager<u64> for TestSessionManager {
     fn new_session(_new_index: SessionIndex) -> Option<Vec<u64>> {
@@ -158,6 +185,52 @@ impl session::Config for TestRuntime {
     type WeightInfo = ();
 }
 
+impl pallet_session::historical::Config for TestRuntime {
+    type FullIdentification = AccountId;
+    type FullIdentificationOf = ConvertInto;
+}
+
+type IdentificationTuple = (AccountId, AccountId);
+type Offence = crate::EthereumTransactionOffence<IdentificationTuple>;
+
+thread_local! {
+    pub static OFFENCES: RefCell<Vec<(Vec<AccountId>, Offence)>> = RefCell::new(vec![]);
+}
+
+/// A mock offence report handler.
+pub struct OffenceHandler;
+impl ReportOffence<AccountId, IdentificationTuple, Offence> for OffenceHandler {
+    fn report_offence(reporters: Vec<AccountId>, offence: Offence) -> Result<(), OffenceError> {
+        OFFENCES.with(|l| l.borrow_mut().push((reporters, offence)));
+        Ok(())
+    }
+
+    fn is_known_offence(_offenders: &[IdentificationTuple], _time_slot: &SessionIndex) -> bool {
+        false
+    }
+}
+
+/// Records the offence with its type, so tests can check both offences in `OFFENCES`
+impl ReportOffence<AccountId, IdentificationTuple, OutcomeNotRecordedOffence<IdentificationTuple>> for OffenceHandler {
+    fn report_offence(
+        reporters: Vec<AccountId>,
+        offence: OutcomeNotRecordedOffence<IdentificationTuple>
+    ) -> Result<(), OffenceError> {
+        let offence = Offence {
+            session_index: offence.session_index,
+            validator_set_count: offence.validator_set_count,
+            offenders: offence.offenders,
+            offence_type: EthereumTransactionOffenceType::OutcomeNotRecorded,
+        };
+        OFFENCES.with(|l| l.borrow_mut().push((reporters, offence)));
+        Ok(())
+    }
+
+    fn is_known_offence(_offenders: &[IdentificationTuple], _time_slot: &SessionIndex) -> bool {
+        false
+    }
+}
+
 impl FinalisedBlockChecker<BlockNumber> for TestRuntime {
     fn is_finalised(_block_number: BlockNumber) -> bool { true }
 }
@@ -227,6 +300,129 @@ pub fn get_publish_root_default_contract() -> H160 {
     return H160::from([2u8; 20]);
 }
 
+/// The block in which the Ethereum transaction hash of the transactions added by `add_sent_transaction` is recorded
+pub const ETH_TX_HASH_RECORDED_AT: BlockNumber = 1;
+
+pub fn eth_tx_hash() -> H256 {
+    return H256::from([5; 32]);
+}
+
+/// A hash the external service sent to replace the transaction sent with `eth_tx_hash()`
+pub fn replacement_eth_tx_hash(index: u8) -> H256 {
+    return H256::from([6 + index; 32]);
+}
+
+pub fn validator(account_id: AccountId) -> Validator<UintAuthorityId, AccountId> {
+    return AVN::try_get_validator(&account_id).unwrap();
+}
+
+pub fn event_emitted(event: crate::Event<TestRuntime>) -> bool {
+    return EthereumTransactions::event_emitted(&Event::ethereum_transactions(event));
+}
+
+/// Adds a transaction that `submitter` can send to Ethereum
+pub fn add_dispatched_transaction(submitter: AccountId) -> TransactionId {
+    let tx_id = EthereumTransactions::get_unique_transaction_identifier();
+    EthereumTransactions::insert_to_repository(EthTransactionCandidate::new(
+        tx_id,
+        Some(<TestRuntime as Config>::AccountToBytesConvert::into_bytes(&submitter)),
+        EthTransactionType::PublishRoot(PublishRootData::new([3; 32])),
+        1
+    ));
+    <EthereumTransactions as Store>::DispatchedAvnTxIds::append(submitter, DispatchedData::new(tx_id, 0u64));
+
+    return tx_id;
+}
+
+/// Adds a transaction that `submitter` sent to Ethereum, with the hash `eth_tx_hash()`
+pub fn add_sent_transaction(submitter: AccountId) -> TransactionId {
+    let tx_id = add_dispatched_transaction(submitter);
+    assert_ok!(<EthereumTransactions as Store>::Repository::mutate(
+        tx_id,
+        |tx| tx.set_eth_tx_hash::<TestRuntime>(eth_tx_hash())
+    ));
+    <EthereumTransactions as Store>::EthTxHashRecordedAt::insert(tx_id, ETH_TX_HASH_RECORDED_AT);
+
+    return tx_id;
+}
+
+pub fn attest_outcome(validator: AccountId, tx_id: TransactionId, outcome: EthTransactionOutcome) -> DispatchResult {
+    return EthereumTransactions::set_transaction_outcome(
+        RawOrigin::None.into(),
+        validator,
+        tx_id,
+        eth_tx_hash(),
+        outcome,
+        TestSignature(0, vec![])
+    );
+}
+
+/// Enough validators attest `outcome` for it to be recorded
+pub fn record_outcome(tx_id: TransactionId, outcome: EthTransactionOutcome) {
+    let quorum = AVN::calculate_two_third_quorum() as usize;
+    for validator in EthereumTransactions::get_validator_account_ids().into_iter().take(quorum) {
+        assert_ok!(attest_outcome(validator, tx_id, outcome));
+    }
+}
+
+pub fn mock_send_response(state: &mut OffchainState, body: EthTransaction) {
+    state.expect_request(PendingRequest {
+        method: "POST".into(),
+        uri: "http://127.0.0.1:2020/eth/send".into(),
+        response: Some(hex::encode(eth_tx_hash().as_bytes()).into_bytes()),
+        headers: vec![],
+        body: body.encode(),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+pub fn mock_tx_status_response(state: &mut OffchainState, tx_status: EthTransactionStatus) {
+    mock_tx_status_response_for(state, format!("http://127.0.0.1:2020/eth/tx_status/0x{}", hex::encode(eth_tx_hash())), tx_status);
+}
+
+pub fn mock_batch_call_status_response(state: &mut OffchainState, batch_call_index: u32, tx_status: EthTransactionStatus) {
+    mock_tx_status_response_for(
+        state,
+        format!("http://127.0.0.1:2020/eth/tx_status/0x{}/{}", hex::encode(eth_tx_hash()), batch_call_index),
+        tx_status
+    );
+}
+
+pub fn mock_tx_status_response_for(state: &mut OffchainState, uri: String, tx_status: EthTransactionStatus) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: uri,
+        response: Some(hex::encode(tx_status.encode()).into_bytes()),
+        headers: vec![],
+        sent: true,
+        ..Default::default()
+    });
+}
+
+pub fn mock_tx_hashes_response(state: &mut OffchainState, eth_tx_hashes: Vec<H256>) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: format!("http://127.0.0.1:2020/eth/tx_hashes/0x{}", hex::encode(eth_tx_hash())),
+        response: Some(hex::encode(eth_tx_hashes.encode()).into_bytes()),
+        headers: vec![],
+        sent: true,
+        ..Default::default()
+    });
+}
+
+pub fn mock_stop_monitoring_response(state: &mut OffchainState) {
+    state.expect_request(PendingRequest {
+        method: "POST".into(),
+        uri: format!("http://127.0.0.1:2020/eth/stop_monitoring/0x{}", hex::encode(eth_tx_hash())),
+        response: Some(hex::encode(eth_tx_hash()).into_bytes()),
+        headers: vec![],
+        body: vec![],
+        sent: true,
+        ..Default::default()
+    });
+}
+
 pub struct ExtBuilder {
     storage: sp_runtime::Storage,
     offchain_state: Option<Arc<RwLock<OffchainState>>>,
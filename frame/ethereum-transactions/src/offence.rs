@@ -0,0 +1,161 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+use sp_runtime::Perbill;
+use sp_staking::{
+    SessionIndex,
+    offence::{Offence, Kind},
+};
+
+use sp_std::prelude::*;
+use sp_runtime::traits::Convert;
+use sp_staking::offence::ReportOffence;
+use pallet_session::{Config as SessionConfig, historical::IdentificationTuple};
+use frame_support::debug;
+use codec::{Encode, Decode};
+
+#[derive(PartialEq, Eq, Clone, Debug, Encode, Decode)]
+pub enum EthereumTransactionOffenceType {
+    TransactionNotSent,
+    OutcomeNotRecorded,
+}
+use crate::Event;
+
+const OUTCOME_NOT_RECORDED_SLASH_PERCENTAGE: u32 = 10;
+
+#[derive(PartialEq, Clone, Debug, Encode, Decode)]
+pub struct EthereumTransactionOffence<Offender> {
+    /// The current session index in which we report the validators that did not send their transaction.
+    pub session_index: SessionIndex,
+    /// The size of the validator set in current session/era.
+    pub validator_set_count: u32,
+    /// Authorities that did not send their transaction.
+    pub offenders: Vec<Offender>,
+    /// The different types of the offence
+    pub offence_type: EthereumTransactionOffenceType,
+}
+
+impl<Offender: Clone> Offence<Offender> for EthereumTransactionOffence<Offender> {
+    const ID: Kind = *b"ethtx:unsent-txn";
+    type TimeSlot = SessionIndex;
+
+    fn offenders(&self) -> Vec<Offender> {
+        self.offenders.clone()
+    }
+
+    fn session_index(&self) -> SessionIndex {
+        self.session_index
+    }
+
+    fn validator_set_count(&self) -> u32 {
+        self.validator_set_count
+    }
+
+    fn time_slot(&self) -> Self::TimeSlot {
+        self.session_index
+    }
+
+    fn slash_fraction(_offenders: u32, _validator_set_count: u32) -> Perbill {
+        // We don't implement fraction slashes at the moment.
+        Perbill::from_percent(100)
+    }
+}
+
+/// A submitter that sent its transaction, but did not attest in time that it failed on Ethereum. The transaction was
+/// sent, and the other validators record its outcome anyway, so this is not as severe as not sending it at all.
+#[derive(PartialEq, Clone, Debug, Encode, Decode)]
+pub struct OutcomeNotRecordedOffence<Offender> {
+    /// The current session index in which we report the validators that did not record the outcome.
+    pub session_index: SessionIndex,
+    /// The size of the validator set in current session/era.
+    pub validator_set_count: u32,
+    /// Authorities that did not record the outcome of their transaction.
+    pub offenders: Vec<Offender>,
+}
+
+impl<Offender: Clone> Offence<Offender> for OutcomeNotRecordedOffence<Offender> {
+    const ID: Kind = *b"ethtx:no-outcome";
+    type TimeSlot = SessionIndex;
+
+    fn offenders(&self) -> Vec<Offender> {
+        self.offenders.clone()
+    }
+
+    fn session_index(&self) -> SessionIndex {
+        self.session_index
+    }
+
+    fn validator_set_count(&self) -> u32 {
+        self.validator_set_count
+    }
+
+    fn time_slot(&self) -> Self::TimeSlot {
+        self.session_index
+    }
+
+    fn slash_fraction(_offenders: u32, _validator_set_count: u32) -> Perbill {
+        Perbill::from_percent(OUTCOME_NOT_RECORDED_SLASH_PERCENTAGE)
+    }
+}
+
+pub fn create_offenders_identification<T: crate::Config>(offenders_accounts: &Vec<T::AccountId>) -> Vec<IdentificationTuple<T>> {
+    let offenders = offenders_accounts
+        .into_iter()
+        .filter_map(|id| <T as SessionConfig>::ValidatorIdOf::convert(id.clone()))
+        .filter_map(|id|
+            T::FullIdentificationOf::convert(id.clone())
+            .map(|full_id| (id, full_id))
+        )
+        .collect::<Vec<IdentificationTuple<T>>>();
+    return offenders;
+}
+
+pub fn create_and_report_transaction_offence<T: crate::Config>(
+    reporter: &T::AccountId,
+    offenders_accounts: &Vec<T::AccountId>,
+    offence_type: EthereumTransactionOffenceType) {
+
+    let offenders = create_offenders_identification::<T>(offenders_accounts);
+
+    if offenders.len() > 0 {
+        let session_index = <pallet_session::Module<T>>::current_index();
+        let validator_set_count = <pallet_session::Module<T>>::validators().len() as u32;
+
+        let reported = match offence_type {
+            EthereumTransactionOffenceType::TransactionNotSent => report_offence::<T, _>(reporter, EthereumTransactionOffence {
+                session_index,
+                validator_set_count,
+                offenders: offenders.clone(),
+                offence_type: offence_type.clone()
+            }),
+            EthereumTransactionOffenceType::OutcomeNotRecorded => report_offence::<T, _>(reporter, OutcomeNotRecordedOffence {
+                session_index,
+                validator_set_count,
+                offenders: offenders.clone(),
+            }),
+        };
+
+        if reported {
+            <crate::Module<T>>::deposit_event(Event::<T>::OffenceReported(offence_type, offenders));
+        }
+    }
+}
+
+/// Returns false if the offence was already known
+fn report_offence<T: crate::Config, O: Offence<IdentificationTuple<T>>>(reporter: &T::AccountId, offence: O) -> bool
+    where T::ReportTransactionOffence: ReportOffence<T::AccountId, IdentificationTuple<T>, O>
+{
+    if T::ReportTransactionOffence::is_known_offence(&offence.offenders(), &offence.time_slot()) {
+        return false;
+    }
+
+    let reporters = vec![reporter.clone()];
+    if let Err(e) = T::ReportTransactionOffence::report_offence(reporters, offence) {
+        debug::native::info!(
+            target: "pallet-ethereum-transactions",
+            "ℹ️ Error while reporting offence: {:?}. Stored in deferred",
+            e
+        );
+    }
+
+    return true;
+}
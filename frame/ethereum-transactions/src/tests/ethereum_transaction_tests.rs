@@ -1,9 +1,10 @@
 #![cfg(test)]
 #![allow(unused_must_use)]
 
-use ethabi::{Param, Token};
+use ethabi::{Function, Param, ParamType, Token};
 use frame_support::assert_err;
-use sp_core::{U256, ecdsa};
+use sp_avn_common::EthTransaction;
+use sp_core::{H160, U256, ecdsa};
 
 use crate::ethereum_transaction::*;
 use crate::tests_eth_transaction_type::*;
@@ -261,6 +262,51 @@ fn full_ethereum_description_appends_signatures() {
     assert_eq!(number_of_terms - number_of_original_terms, 1);
 }
 
+#[test]
+fn batch_transaction_wraps_each_call_for_the_multicall_contract() {
+    let from = [5u8; 32];
+    let multicall_contract = H160::from([9; 20]);
+    let transactions = vec![
+        EthTransaction::new(from, H160::from([1; 20]), vec![1, 2]),
+        EthTransaction::new(from, H160::from([2; 20]), vec![3]),
+    ];
+
+    let batch = EthAbiHelper::generate_batch_transaction_abi(from, multicall_contract, &transactions).unwrap();
+
+    assert_eq!(batch.from, from);
+    assert_eq!(batch.to, multicall_contract);
+    let function_call = Function {
+        name: String::from("tryBatchCall"),
+        inputs: vec![
+            Param { name: String::from("_targets"), kind: ParamType::Array(Box::new(ParamType::Address)) },
+            Param { name: String::from("_calls"), kind: ParamType::Array(Box::new(ParamType::Bytes)) },
+        ],
+        outputs: Vec::<Param>::new(),
+        constant: false,
+    };
+    let expected_data = function_call.encode_input(&vec![
+        Token::Array(vec![Token::Address(H160::from([1; 20])), Token::Address(H160::from([2; 20]))]),
+        Token::Array(vec![Token::Bytes(vec![1, 2]), Token::Bytes(vec![3])]),
+    ]).unwrap();
+    assert_eq!(batch.data, expected_data);
+}
+
+#[test]
+fn batch_transaction_fails_for_transactions_of_another_sender() {
+    let from = [5u8; 32];
+    let transactions = vec![
+        EthTransaction::new(from, H160::from([1; 20]), vec![1, 2]),
+        EthTransaction::new([6u8; 32], H160::from([2; 20]), vec![3]),
+    ];
+
+    assert!(EthAbiHelper::generate_batch_transaction_abi(from, H160::from([9; 20]), &transactions).is_err());
+}
+
+#[test]
+fn batch_transaction_fails_when_empty() {
+    assert!(EthAbiHelper::generate_batch_transaction_abi([5u8; 32], H160::from([9; 20]), &vec![]).is_err());
+}
+
 // EthSignature tests
 
 #[test]
@@ -0,0 +1,285 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const SUBMITTER: AccountId = 1;
+const MULTICALL_CONTRACT_ADDRESS: [u8; 20] = [9; 20];
+
+fn enable_batching() {
+    MULTICALL_CONTRACT.with(|contract| *contract.borrow_mut() = H160::from(MULTICALL_CONTRACT_ADDRESS));
+}
+
+fn add_dispatched_transactions(submitter: AccountId, count: u8) -> Vec<TransactionId> {
+    return (0..count).map(|_| add_dispatched_transaction(submitter)).collect();
+}
+
+fn set_eth_tx_hash_for_batch(submitter: AccountId, tx_ids: Vec<TransactionId>) -> DispatchResult {
+    return EthereumTransactions::set_eth_tx_hash_for_batch(
+        RawOrigin::None.into(),
+        submitter,
+        tx_ids,
+        eth_tx_hash(),
+        TestSignature(0, vec![])
+    );
+}
+
+fn eth_transaction(tx_id: TransactionId) -> EthTransaction {
+    let transaction = EthereumTransactions::get_transaction(tx_id);
+    return transaction.to_abi(EthereumTransactions::get_publish_root_contract()).unwrap();
+}
+
+mod set_eth_tx_hash_for_batch {
+    use super::*;
+
+    #[test]
+    fn records_the_hash_on_each_transaction_of_the_batch() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 3);
+            System::set_block_number(2);
+
+            assert_ok!(set_eth_tx_hash_for_batch(SUBMITTER, tx_ids.clone()));
+
+            for tx_id in tx_ids.iter() {
+                assert_eq!(EthereumTransactions::get_transaction(tx_id).get_eth_tx_hash(), Some(eth_tx_hash()));
+                assert_eq!(EthereumTransactions::get_eth_tx_hash_recorded_at(tx_id), 2);
+                assert_eq!(
+                    EthereumTransactions::get_batch_call_index(tx_id),
+                    tx_ids.iter().position(|id| id == tx_id).map(|index| index as u32)
+                );
+                assert!(event_emitted(crate::Event::<TestRuntime>::EthereumTransactionHashAdded(*tx_id, eth_tx_hash())));
+            }
+            assert!(event_emitted(crate::Event::<TestRuntime>::BatchSent(tx_ids, eth_tx_hash())));
+        });
+    }
+
+    #[test]
+    fn forgets_the_position_in_the_batch_once_the_outcome_is_recorded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            assert_ok!(set_eth_tx_hash_for_batch(SUBMITTER, tx_ids.clone()));
+
+            record_outcome(tx_ids[1], EthTransactionOutcome::Reverted);
+
+            assert_eq!(EthereumTransactions::get_batch_call_index(tx_ids[0]), Some(0));
+            assert_eq!(EthereumTransactions::get_batch_call_index(tx_ids[1]), None);
+        });
+    }
+
+    #[test]
+    fn records_nothing_if_one_of_the_transactions_cannot_be_recorded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let mut tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            tx_ids.push(tx_ids[1] + 1);
+
+            assert_noop!(set_eth_tx_hash_for_batch(SUBMITTER, tx_ids), Error::<TestRuntime>::InvalidTransactionSubmitter);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_batch_is_empty() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            assert_noop!(set_eth_tx_hash_for_batch(SUBMITTER, vec![]), Error::<TestRuntime>::InvalidBatch);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_batch_is_too_big() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, MaxTransactionsPerBatch::get() as u8 + 1);
+
+            assert_noop!(set_eth_tx_hash_for_batch(SUBMITTER, tx_ids), Error::<TestRuntime>::InvalidBatch);
+        });
+    }
+
+    #[test]
+    fn fails_when_a_transaction_is_repeated() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 1);
+
+            assert_noop!(
+                set_eth_tx_hash_for_batch(SUBMITTER, vec![tx_ids[0], tx_ids[0]]),
+                Error::<TestRuntime>::InvalidBatch
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_submitter_is_not_a_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+
+            assert_noop!(set_eth_tx_hash_for_batch(4, tx_ids), Error::<TestRuntime>::InvalidKey);
+        });
+    }
+}
+
+mod send_transaction_candidates {
+    use super::*;
+
+    #[test]
+    fn sends_ready_transactions_in_one_batch_when_batching_is_enabled() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            enable_batching();
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            let batch = EthAbiHelper::generate_batch_transaction_abi(
+                <TestRuntime as Config>::AccountToBytesConvert::into_bytes(&SUBMITTER),
+                H160::from(MULTICALL_CONTRACT_ADDRESS),
+                &tx_ids.iter().map(|tx_id| eth_transaction(*tx_id)).collect(),
+            ).unwrap();
+            mock_send_response(&mut offchain_state.write(), batch);
+
+            let submitter = validator(SUBMITTER);
+            EthereumTransactions::send_transaction_candidates(&submitter, 1);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let signature = submitter.key.sign(
+                &(SET_ETH_TX_HASH_FOR_BATCH, (&SUBMITTER, &tx_ids, eth_tx_hash())).encode()
+            ).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(
+                    crate::Call::set_eth_tx_hash_for_batch(SUBMITTER, tx_ids, eth_tx_hash(), signature)
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn sends_one_transaction_at_a_time_when_batching_is_disabled() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            mock_send_response(&mut offchain_state.write(), eth_transaction(tx_ids[0]));
+
+            let submitter = validator(SUBMITTER);
+            EthereumTransactions::send_transaction_candidates(&submitter, 1);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let signature = submitter.key.sign(
+                &(SET_ETH_TX_HASH_FOR_DISPATCHED_TX, (&SUBMITTER, &tx_ids[0], eth_tx_hash())).encode()
+            ).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(
+                    crate::Call::set_eth_tx_hash_for_dispatched_tx(SUBMITTER, tx_ids[0], eth_tx_hash(), signature)
+                )
+            );
+        });
+    }
+}
+
+mod send_batch_of_transactions {
+    use super::*;
+
+    #[test]
+    fn releases_the_sending_locks_when_the_batch_cannot_be_encoded() {
+        let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            enable_batching();
+            let block_number = 1;
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            for tx_id in tx_ids.iter() {
+                assert_ok!(OcwLock::set_lock_with_expiry(
+                    block_number,
+                    OcwOperationExpiration::Custom(ETHEREUM_SEND_BLOCKS_EXPIRY),
+                    EthereumTransactions::generate_sending_lock_name(*tx_id)
+                ));
+            }
+
+            // A transaction of another sender cannot be part of the batch
+            let mut transactions: Vec<(TransactionId, EthTransaction)> =
+                tx_ids.iter().map(|tx_id| (*tx_id, eth_transaction(*tx_id))).collect();
+            transactions[1].1.from = [7; 32];
+
+            EthereumTransactions::send_batch_of_transactions(&validator(SUBMITTER), block_number, transactions);
+
+            assert!(pool_state.read().transactions.is_empty());
+            for tx_id in tx_ids.iter() {
+                assert!(!EthereumTransactions::is_transaction_locked_for_sending(tx_id));
+            }
+        });
+    }
+}
+
+mod check_outcome_of_sent_transactions {
+    use super::*;
+
+    const ETH_BLOCK_NUMBER: u64 = 100;
+
+    fn mined(succeeded: bool) -> EthTransactionStatus {
+        return EthTransactionStatus::Mined {
+            tx_hash: eth_tx_hash(),
+            block_number: ETH_BLOCK_NUMBER,
+            succeeded,
+            num_confirmations: MinEthBlockConfirmation::get(),
+        };
+    }
+
+    #[test]
+    fn attests_the_result_of_the_call_of_each_transaction_in_the_batch() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_ids = add_dispatched_transactions(SUBMITTER, 2);
+            assert_ok!(set_eth_tx_hash_for_batch(SUBMITTER, tx_ids.clone()));
+            mock_batch_call_status_response(&mut offchain_state.write(), 0, mined(true));
+            mock_batch_call_status_response(&mut offchain_state.write(), 1, mined(false));
+
+            let attester = validator(2);
+            EthereumTransactions::check_outcome_of_sent_transactions(&attester, 1);
+
+            let calls: Vec<mock::Call> = pool_state.write().transactions
+                .drain(..)
+                .map(|tx| Extrinsic::decode(&mut &*tx).unwrap().call)
+                .collect();
+            let expected_calls: Vec<mock::Call> = vec![
+                (tx_ids[0], EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER)),
+                (tx_ids[1], EthTransactionOutcome::Reverted),
+            ]
+            .into_iter()
+            .map(|(tx_id, outcome)| {
+                let signature = attester.key.sign(&(SET_TRANSACTION_OUTCOME, (&2, &tx_id, eth_tx_hash(), outcome)).encode()).unwrap();
+                mock::Call::EthereumTransactions(crate::Call::set_transaction_outcome(2, tx_id, eth_tx_hash(), outcome, signature))
+            })
+            .collect();
+            assert_eq!(calls, expected_calls);
+        });
+    }
+}
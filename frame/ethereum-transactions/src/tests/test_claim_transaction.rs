@@ -0,0 +1,207 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const SUBMITTER: AccountId = 1;
+const DISPATCHED_AT: BlockNumber = 0;
+
+fn timeout() -> BlockNumber {
+    return DispatchedTransactionTimeout::get();
+}
+
+fn claimant_at(tx_id: TransactionId, block_number: BlockNumber) -> Option<AccountId> {
+    let (submitter, dispatched_data) = EthereumTransactions::get_dispatched_data(tx_id).unwrap();
+    return EthereumTransactions::get_claimant(tx_id, &submitter, &dispatched_data, block_number);
+}
+
+fn claim(tx_id: TransactionId, claimant: AccountId) -> DispatchResult {
+    return EthereumTransactions::claim_transaction(
+        RawOrigin::None.into(),
+        tx_id,
+        validator(claimant),
+        TestSignature(0, vec![])
+    );
+}
+
+mod get_claimant {
+    use super::*;
+
+    #[test]
+    fn is_nobody_until_the_submitter_timed_out() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+
+            assert_eq!(claimant_at(tx_id, DISPATCHED_AT + timeout() - 1), None);
+        });
+    }
+
+    #[test]
+    fn follows_the_validators_after_the_submitter_one_period_each() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            let deadline = DISPATCHED_AT + timeout();
+
+            assert_eq!(claimant_at(tx_id, deadline), Some(2));
+            assert_eq!(claimant_at(tx_id, deadline + timeout() - 1), Some(2));
+            assert_eq!(claimant_at(tx_id, deadline + timeout()), Some(3));
+            assert_eq!(claimant_at(tx_id, deadline + 2 * timeout()), Some(2));
+        });
+    }
+
+    #[test]
+    fn skips_the_validators_that_already_failed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            <FailedSubmitters<TestRuntime>>::insert(tx_id, vec![2]);
+
+            assert_eq!(claimant_at(tx_id, DISPATCHED_AT + timeout()), Some(3));
+        });
+    }
+
+    #[test]
+    fn is_nobody_once_the_transaction_was_sent() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let drop_block = ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get();
+
+            assert_eq!(claimant_at(tx_id, DISPATCHED_AT + timeout()), None);
+            assert_eq!(claimant_at(tx_id, drop_block + timeout()), None);
+        });
+    }
+}
+
+mod claim_transaction {
+    use super::*;
+
+    #[test]
+    fn hands_the_transaction_to_the_claimant_and_reports_the_submitter() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            System::set_block_number(DISPATCHED_AT + timeout());
+
+            assert_ok!(claim(tx_id, 2));
+
+            let transaction = EthereumTransactions::get_transaction(tx_id);
+            assert_eq!(transaction.from, Some(<TestRuntime as Config>::AccountToBytesConvert::into_bytes(&2)));
+            assert_eq!(transaction.signatures.count(), 0);
+            assert!(EthereumTransactions::get_dispatched_avn_tx_ids(SUBMITTER).is_empty());
+            assert_eq!(EthereumTransactions::get_failed_submitters(tx_id), vec![SUBMITTER]);
+            assert_eq!(EthereumTransactions::get_transactions_awaiting_confirmations(), vec![tx_id]);
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionClaimed(tx_id, SUBMITTER, 2)));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+            assert!(event_emitted(crate::Event::<TestRuntime>::OffenceReported(
+                EthereumTransactionOffenceType::TransactionNotSent,
+                vec![(SUBMITTER, SUBMITTER)]
+            )));
+
+            let offences = OFFENCES.with(|l| l.replace(vec![]));
+            assert_eq!(offences.len(), 1);
+            assert_eq!(offences[0].0, vec![2]);
+            assert_eq!(offences[0].1.offence_type, EthereumTransactionOffenceType::TransactionNotSent);
+            assert_eq!(offences[0].1.offenders, vec![(SUBMITTER, SUBMITTER)]);
+        });
+    }
+
+    #[test]
+    fn fails_before_the_submitter_timed_out() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            System::set_block_number(DISPATCHED_AT + timeout() - 1);
+
+            assert_noop!(claim(tx_id, 2), Error::<TestRuntime>::ClaimNotAllowed);
+        });
+    }
+
+    #[test]
+    fn fails_when_it_is_not_the_turn_of_the_claimant() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            System::set_block_number(DISPATCHED_AT + timeout());
+
+            assert_noop!(claim(tx_id, 3), Error::<TestRuntime>::ClaimNotAllowed);
+            assert_noop!(claim(tx_id, SUBMITTER), Error::<TestRuntime>::ClaimNotAllowed);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_transaction_was_sent() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            System::set_block_number(ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get() + timeout());
+
+            assert_noop!(claim(tx_id, 2), Error::<TestRuntime>::ClaimNotAllowed);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_transaction_is_not_dispatched() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            EthereumTransactions::remove_submitter_from_dispatched_avn_tx_ids(SUBMITTER);
+            System::set_block_number(DISPATCHED_AT + timeout());
+
+            assert_noop!(claim(tx_id, 2), Error::<TestRuntime>::MissingDispatchedAvnTx);
+        });
+    }
+}
+
+mod claim_timed_out_transactions {
+    use super::*;
+
+    #[test]
+    fn claims_the_transactions_this_validator_can_claim() {
+        let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_id = add_dispatched_transaction(SUBMITTER);
+            let claimant = validator(2);
+
+            EthereumTransactions::claim_timed_out_transactions(&claimant, DISPATCHED_AT + timeout());
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let signature = claimant.key.sign(&(CLAIM_TRANSACTION, tx_id).encode()).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(crate::Call::claim_transaction(tx_id, claimant, signature))
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_claim_when_it_is_the_turn_of_another_validator() {
+        let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            add_dispatched_transaction(SUBMITTER);
+
+            EthereumTransactions::claim_timed_out_transactions(&validator(3), DISPATCHED_AT + timeout());
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
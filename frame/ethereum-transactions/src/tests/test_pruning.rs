@@ -0,0 +1,139 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::ethereum_transaction::*;
+use crate::*;
+use frame_support::{assert_ok, traits::OnInitialize};
+use system::RawOrigin;
+
+const SUBMITTER: AccountId = 1;
+const COMPLETED_AT: BlockNumber = 5;
+
+fn retention_period() -> BlockNumber {
+    return TransactionRetentionPeriod::get();
+}
+
+fn complete_transaction() -> TransactionId {
+    let tx_id = add_sent_transaction(SUBMITTER);
+    record_outcome(tx_id, EthTransactionOutcome::Succeeded(100));
+
+    return tx_id;
+}
+
+mod schedule_pruning {
+    use super::*;
+
+    #[test]
+    fn queues_a_transaction_that_succeeded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(COMPLETED_AT);
+
+            let tx_id = complete_transaction();
+
+            assert_eq!(EthereumTransactions::get_pruning_queue_tail(), 1);
+            assert_eq!(EthereumTransactions::get_pruning_queue_entry(0), (tx_id, COMPLETED_AT));
+            assert!(!<DispatchedAvnTxIds<TestRuntime>>::contains_key(SUBMITTER));
+        });
+    }
+
+    #[test]
+    fn queues_a_transaction_that_was_discarded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(COMPLETED_AT);
+            let transaction_type = EthTransactionType::PublishRoot(PublishRootData::new([1; 32]));
+            let tx_id = EthereumTransactions::reserve_transaction_id(&transaction_type).unwrap();
+
+            assert_ok!(EthereumTransactions::unreserve_transaction(RawOrigin::Root.into(), transaction_type));
+
+            assert_eq!(EthereumTransactions::get_pruning_queue_entry(0), (tx_id, COMPLETED_AT));
+        });
+    }
+
+    #[test]
+    fn does_not_queue_a_transaction_handed_to_another_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+
+            assert_eq!(EthereumTransactions::get_pruning_queue_tail(), 0);
+        });
+    }
+}
+
+mod prune_completed_transactions {
+    use super::*;
+
+    #[test]
+    fn keeps_transactions_until_the_retention_period_is_over() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(COMPLETED_AT);
+            let tx_id = complete_transaction();
+
+            EthereumTransactions::on_initialize(COMPLETED_AT + retention_period() - 1);
+
+            assert!(<Repository>::contains_key(tx_id));
+            assert_eq!(EthereumTransactions::get_pruning_queue_head(), 0);
+        });
+    }
+
+    #[test]
+    fn removes_completed_and_discarded_transactions() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(COMPLETED_AT);
+            let completed_tx_id = complete_transaction();
+            let transaction_type = EthTransactionType::PublishRoot(PublishRootData::new([2; 32]));
+            let discarded_tx_id = EthereumTransactions::reserve_transaction_id(&transaction_type).unwrap();
+            assert_ok!(EthereumTransactions::unreserve_transaction(RawOrigin::Root.into(), transaction_type));
+            <DroppedEthTxHashes<TestRuntime>>::insert(completed_tx_id, vec![(2, eth_tx_hash())]);
+
+            EthereumTransactions::on_initialize(COMPLETED_AT + retention_period());
+
+            assert!(!<Repository>::contains_key(completed_tx_id));
+            assert_eq!(EthereumTransactions::get_transaction_outcome(completed_tx_id), None);
+            assert!(!<DroppedEthTxHashes<TestRuntime>>::contains_key(completed_tx_id));
+            assert!(!<ReservedTransactions>::contains_key(EthTransactionType::Discarded(discarded_tx_id)));
+            assert_eq!(EthereumTransactions::get_pruning_queue_head(), 2);
+            assert!(!<PruningQueue<TestRuntime>>::contains_key(0));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionsPruned(vec![completed_tx_id, discarded_tx_id])));
+        });
+    }
+
+    #[test]
+    fn prunes_at_most_the_maximum_number_of_transactions_per_block() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            System::set_block_number(COMPLETED_AT);
+            let tx_ids: Vec<TransactionId> = (1..=3).map(|_| complete_transaction()).collect();
+            let pruning_block = COMPLETED_AT + retention_period();
+
+            EthereumTransactions::on_initialize(pruning_block);
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionsPruned(tx_ids[0..2].to_vec())));
+            assert!(<Repository>::contains_key(tx_ids[2]));
+
+            EthereumTransactions::on_initialize(pruning_block + 1);
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionsPruned(vec![tx_ids[2]])));
+            assert_eq!(EthereumTransactions::get_pruning_queue_head(), EthereumTransactions::get_pruning_queue_tail());
+        });
+    }
+
+    #[test]
+    fn does_nothing_when_the_queue_is_empty() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let weight = EthereumTransactions::on_initialize(COMPLETED_AT + retention_period());
+
+            assert_eq!(weight, <TestRuntime as system::Config>::DbWeight::get().reads(2));
+            assert_eq!(System::events().len(), 0);
+        });
+    }
+}
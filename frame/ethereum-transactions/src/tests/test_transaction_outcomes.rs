@@ -0,0 +1,740 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+
+#![cfg(test)]
+
+use crate::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::testing::TestSignature;
+use system::RawOrigin;
+
+const SUBMITTER: AccountId = 1;
+const ETH_BLOCK_NUMBER: u64 = 100;
+
+fn submitter_deadline() -> BlockNumber {
+    return ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get() + DispatchedTransactionTimeout::get();
+}
+
+/// With 4 validators the other 3 reach the quorum without the submitter
+fn with_four_validators() -> ExtBuilder {
+    VALIDATORS.with(|l| *l.borrow_mut() = Some(vec![1, 2, 3, 4]));
+    return ExtBuilder::build_default().with_genesis_config().with_validators();
+}
+
+fn mined(succeeded: bool, num_confirmations: u64) -> EthTransactionStatus {
+    return EthTransactionStatus::Mined { tx_hash: eth_tx_hash(), block_number: ETH_BLOCK_NUMBER, succeeded, num_confirmations };
+}
+
+fn add_replacements(submitter: AccountId, tx_id: TransactionId, replacement_eth_tx_hashes: Vec<H256>) -> DispatchResult {
+    return EthereumTransactions::add_replacement_eth_tx_hashes(
+        RawOrigin::None.into(),
+        submitter,
+        tx_id,
+        eth_tx_hash(),
+        replacement_eth_tx_hashes,
+        TestSignature(0, vec![])
+    );
+}
+
+mod set_transaction_outcome {
+    use super::*;
+
+    #[test]
+    fn records_a_success_once_a_quorum_of_validators_attested_it() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let outcome = EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER);
+
+            assert_ok!(attest_outcome(2, tx_id, outcome));
+            assert_ok!(attest_outcome(3, tx_id, outcome));
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), None);
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionOutcomeAttested(tx_id, outcome, 2)));
+
+            assert_ok!(attest_outcome(SUBMITTER, tx_id, outcome));
+
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), Some(outcome));
+            assert!(EthereumTransactions::get_dispatched_avn_tx_ids(SUBMITTER).is_empty());
+            assert!(!<EthTxHashRecordedAt<TestRuntime>>::contains_key(tx_id));
+            assert!(!<OutcomeAttestations<TestRuntime>>::contains_key(tx_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionSucceeded(tx_id, eth_tx_hash(), ETH_BLOCK_NUMBER)));
+        });
+    }
+
+    #[test]
+    fn does_not_record_an_outcome_attested_by_the_submitter_alone() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let outcome = EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER);
+
+            assert_ok!(attest_outcome(SUBMITTER, tx_id, outcome));
+
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), None);
+            assert_eq!(EthereumTransactions::get_outcome_attestations(tx_id), vec![(SUBMITTER, outcome)]);
+            assert_eq!(EthereumTransactions::get_dispatched_avn_tx_ids(SUBMITTER).len(), 1);
+            assert!(!event_emitted(crate::Event::<TestRuntime>::TransactionSucceeded(tx_id, eth_tx_hash(), ETH_BLOCK_NUMBER)));
+        });
+    }
+
+    #[test]
+    fn does_not_record_an_outcome_until_a_quorum_agrees_on_it() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_ok!(attest_outcome(SUBMITTER, tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER)));
+            assert_ok!(attest_outcome(2, tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER)));
+            assert_ok!(attest_outcome(3, tx_id, EthTransactionOutcome::Reverted));
+
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), None);
+            assert_eq!(EthereumTransactions::get_outcome_attestations(tx_id).len(), 3);
+        });
+    }
+
+    #[test]
+    fn replaces_the_previous_attestation_of_a_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            assert_ok!(attest_outcome(3, tx_id, EthTransactionOutcome::Reverted));
+
+            assert_ok!(attest_outcome(3, tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER)));
+
+            assert_eq!(
+                EthereumTransactions::get_outcome_attestations(tx_id),
+                vec![(3, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER))]
+            );
+        });
+    }
+
+    #[test]
+    fn hands_a_failed_transaction_to_the_next_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+
+            let transaction = EthereumTransactions::get_transaction(tx_id);
+            assert_eq!(transaction.from, Some(<TestRuntime as Config>::AccountToBytesConvert::into_bytes(&2)));
+            assert_eq!(transaction.get_eth_tx_hash(), None);
+            assert_eq!(transaction.signatures.count(), 0);
+            assert_eq!(transaction.quorum, mock::AVN::calculate_two_third_quorum());
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), None);
+            assert_eq!(EthereumTransactions::get_failed_submitters(tx_id), vec![SUBMITTER]);
+            assert_eq!(EthereumTransactions::get_transactions_awaiting_confirmations(), vec![tx_id]);
+            assert!(EthereumTransactions::get_dispatched_avn_tx_ids(SUBMITTER).is_empty());
+            assert!(!<OutcomeAttestations<TestRuntime>>::contains_key(tx_id));
+            assert!(!<DroppedEthTxHashes<TestRuntime>>::contains_key(tx_id));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionFailed(tx_id, EthTransactionOutcome::Reverted, SUBMITTER)));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+        });
+    }
+
+    #[test]
+    fn records_the_hash_of_a_dropped_transaction_so_its_submitter_stops_replacing_it() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            System::set_block_number(ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get());
+
+            record_outcome(tx_id, EthTransactionOutcome::Dropped);
+
+            assert_eq!(EthereumTransactions::get_dropped_eth_tx_hashes(tx_id), vec![(SUBMITTER, eth_tx_hash())]);
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+        });
+    }
+
+    #[test]
+    fn removes_the_replacements_once_the_outcome_is_recorded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            assert_ok!(add_replacements(SUBMITTER, tx_id, vec![replacement_eth_tx_hash(0)]));
+
+            record_outcome(tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER));
+
+            assert!(!<ReplacementEthTxHashes>::contains_key(tx_id));
+        });
+    }
+
+    #[test]
+    fn skips_the_validators_that_already_failed_and_wraps_around() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(3);
+            <FailedSubmitters<TestRuntime>>::insert(tx_id, vec![1]);
+
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+        });
+    }
+
+    #[test]
+    fn abandons_the_transaction_when_every_validator_failed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            <FailedSubmitters<TestRuntime>>::insert(tx_id, vec![2, 3]);
+
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+
+            assert_eq!(EthereumTransactions::get_transaction_outcome(tx_id), Some(EthTransactionOutcome::Reverted));
+            assert!(EthereumTransactions::get_transactions_awaiting_confirmations().is_empty());
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionAbandoned(tx_id)));
+        });
+    }
+
+    #[test]
+    fn accepts_a_dropped_transaction_only_after_the_drop_delay() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let drop_block = ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get();
+
+            System::set_block_number(drop_block - 1);
+            assert_noop!(
+                attest_outcome(2, tx_id, EthTransactionOutcome::Dropped),
+                Error::<TestRuntime>::TransactionNotDroppedYet
+            );
+
+            System::set_block_number(drop_block);
+            assert_ok!(attest_outcome(2, tx_id, EthTransactionOutcome::Dropped));
+        });
+    }
+
+    #[test]
+    fn reports_a_submitter_that_did_not_attest_the_failure_in_time() {
+        let mut ext = with_four_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            System::set_block_number(submitter_deadline());
+
+            for validator in vec![2, 3, 4] {
+                assert_ok!(attest_outcome(validator, tx_id, EthTransactionOutcome::Dropped));
+            }
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::OffenceReported(
+                EthereumTransactionOffenceType::OutcomeNotRecorded,
+                vec![(SUBMITTER, SUBMITTER)]
+            )));
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+
+            let offences = OFFENCES.with(|l| l.replace(vec![]));
+            assert_eq!(offences.len(), 1);
+            assert_eq!(offences[0].0, vec![4]);
+            assert_eq!(offences[0].1.offence_type, EthereumTransactionOffenceType::OutcomeNotRecorded);
+            assert_eq!(offences[0].1.offenders, vec![(SUBMITTER, SUBMITTER)]);
+        });
+    }
+
+    #[test]
+    fn does_not_report_a_submitter_before_its_deadline() {
+        let mut ext = with_four_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            System::set_block_number(submitter_deadline() - 1);
+
+            for validator in vec![2, 3, 4] {
+                assert_ok!(attest_outcome(validator, tx_id, EthTransactionOutcome::Dropped));
+            }
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+            assert!(OFFENCES.with(|l| l.borrow().is_empty()));
+        });
+    }
+
+    #[test]
+    fn does_not_report_a_submitter_that_attested_the_failure() {
+        let mut ext = with_four_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            System::set_block_number(submitter_deadline());
+
+            for validator in vec![SUBMITTER, 2, 3] {
+                assert_ok!(attest_outcome(validator, tx_id, EthTransactionOutcome::Dropped));
+            }
+
+            assert!(event_emitted(crate::Event::<TestRuntime>::TransactionReassigned(tx_id, 2)));
+            assert!(OFFENCES.with(|l| l.borrow().is_empty()));
+        });
+    }
+
+    #[test]
+    fn fails_when_the_transaction_has_no_ethereum_hash() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            assert_ok!(<EthereumTransactions as Store>::Repository::mutate(tx_id, |tx| tx.set_eth_tx_hash::<TestRuntime>(H256::zero())));
+
+            assert_noop!(
+                attest_outcome(2, tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER)),
+                Error::<TestRuntime>::MissingEthTxHash
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_ethereum_hash_is_not_the_recorded_one() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_noop!(
+                EthereumTransactions::set_transaction_outcome(
+                    RawOrigin::None.into(),
+                    2,
+                    tx_id,
+                    H256::from([6; 32]),
+                    EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER),
+                    TestSignature(0, vec![])
+                ),
+                Error::<TestRuntime>::EthTxHashMismatch
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_validator_already_attested_the_outcome() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            assert_ok!(attest_outcome(2, tx_id, EthTransactionOutcome::Reverted));
+
+            assert_noop!(
+                attest_outcome(2, tx_id, EthTransactionOutcome::Reverted),
+                Error::<TestRuntime>::DuplicateAttestation
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_attester_is_not_a_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_noop!(
+                attest_outcome(4, tx_id, EthTransactionOutcome::Reverted),
+                Error::<TestRuntime>::InvalidKey
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_outcome_was_already_recorded() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            record_outcome(tx_id, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER));
+
+            assert_noop!(
+                attest_outcome(2, tx_id, EthTransactionOutcome::Reverted),
+                Error::<TestRuntime>::MissingDispatchedAvnTx
+            );
+        });
+    }
+}
+
+mod add_replacement_eth_tx_hashes {
+    use super::*;
+
+    #[test]
+    fn records_the_replacements_and_restarts_the_drop_delay() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let replaced_at = ETH_TX_HASH_RECORDED_AT + 10;
+            System::set_block_number(replaced_at);
+
+            assert_ok!(add_replacements(SUBMITTER, tx_id, vec![replacement_eth_tx_hash(0)]));
+            assert_ok!(add_replacements(SUBMITTER, tx_id, vec![replacement_eth_tx_hash(1)]));
+
+            assert_eq!(
+                EthereumTransactions::get_replacement_eth_tx_hashes(tx_id),
+                vec![replacement_eth_tx_hash(0), replacement_eth_tx_hash(1)]
+            );
+            assert_eq!(EthereumTransactions::get_eth_tx_hash_recorded_at(tx_id), replaced_at);
+            assert!(event_emitted(
+                crate::Event::<TestRuntime>::ReplacementEthTxHashesAdded(tx_id, vec![replacement_eth_tx_hash(1)])
+            ));
+
+            System::set_block_number(ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get());
+            assert_noop!(
+                attest_outcome(2, tx_id, EthTransactionOutcome::Dropped),
+                Error::<TestRuntime>::TransactionNotDroppedYet
+            );
+        });
+    }
+
+    #[test]
+    fn discards_the_attestations_that_the_transaction_was_dropped() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            <OutcomeAttestations<TestRuntime>>::insert(
+                tx_id,
+                vec![(2, EthTransactionOutcome::Dropped), (3, EthTransactionOutcome::Reverted)]
+            );
+
+            assert_ok!(add_replacements(SUBMITTER, tx_id, vec![replacement_eth_tx_hash(0)]));
+
+            assert_eq!(EthereumTransactions::get_outcome_attestations(tx_id), vec![(3, EthTransactionOutcome::Reverted)]);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_sender_is_not_the_submitter() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_noop!(
+                add_replacements(2, tx_id, vec![replacement_eth_tx_hash(0)]),
+                Error::<TestRuntime>::InvalidTransactionSubmitter
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_ethereum_hash_is_not_the_recorded_one() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_noop!(
+                EthereumTransactions::add_replacement_eth_tx_hashes(
+                    RawOrigin::None.into(),
+                    SUBMITTER,
+                    tx_id,
+                    H256::from([1; 32]),
+                    vec![replacement_eth_tx_hash(0)],
+                    TestSignature(0, vec![])
+                ),
+                Error::<TestRuntime>::EthTxHashMismatch
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_a_hash_is_already_known() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            assert_ok!(add_replacements(SUBMITTER, tx_id, vec![replacement_eth_tx_hash(0)]));
+
+            for replacements in vec![
+                vec![eth_tx_hash()],
+                vec![replacement_eth_tx_hash(0)],
+                vec![replacement_eth_tx_hash(1), replacement_eth_tx_hash(1)],
+            ] {
+                assert_noop!(
+                    add_replacements(SUBMITTER, tx_id, replacements),
+                    Error::<TestRuntime>::InvalidReplacementEthTxHashes
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn fails_when_there_are_too_many_replacements() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            let replacements: Vec<H256> = (0..=MAX_REPLACEMENT_ETH_TX_HASHES as u8).map(replacement_eth_tx_hash).collect();
+
+            assert_noop!(add_replacements(SUBMITTER, tx_id, vec![]), Error::<TestRuntime>::InvalidReplacementEthTxHashes);
+            assert_noop!(add_replacements(SUBMITTER, tx_id, replacements), Error::<TestRuntime>::InvalidReplacementEthTxHashes);
+        });
+    }
+}
+
+mod add_confirmation {
+    use super::*;
+
+    fn add_confirmation(tx_id: TransactionId, confirmer: AccountId) -> DispatchResult {
+        return EthereumTransactions::add_confirmation(
+            RawOrigin::None.into(),
+            tx_id,
+            ecdsa::Signature::from_raw([4; 65]),
+            validator(confirmer),
+            TestSignature(0, vec![])
+        );
+    }
+
+    #[test]
+    fn fails_when_the_transaction_is_not_awaiting_confirmations() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+
+            assert_noop!(add_confirmation(tx_id, 2), Error::<TestRuntime>::TransactionNotAwaitingConfirmations);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_validator_already_confirmed() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+            <Confirmers<TestRuntime>>::insert(tx_id, vec![3]);
+
+            assert_noop!(add_confirmation(tx_id, 3), Error::<TestRuntime>::DuplicateConfirmation);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_confirmation_is_not_signed_by_the_validator() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().with_validators().as_externality();
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            record_outcome(tx_id, EthTransactionOutcome::Reverted);
+
+            assert_noop!(add_confirmation(tx_id, 3), avn_error::<TestRuntime>::InvalidECDSASignature);
+        });
+    }
+}
+
+mod get_outcome {
+    use super::*;
+
+    fn outcome_at(tx_status: EthTransactionStatus, block_number: BlockNumber) -> Option<EthTransactionOutcome> {
+        return EthereumTransactions::get_outcome(tx_status, ETH_TX_HASH_RECORDED_AT, block_number);
+    }
+
+    #[test]
+    fn waits_until_the_transaction_has_enough_confirmations() {
+        let min_confirmations = MinEthBlockConfirmation::get();
+
+        assert_eq!(outcome_at(mined(true, min_confirmations - 1), ETH_TX_HASH_RECORDED_AT), None);
+        assert_eq!(outcome_at(mined(false, min_confirmations - 1), ETH_TX_HASH_RECORDED_AT), None);
+        assert_eq!(
+            outcome_at(mined(true, min_confirmations), ETH_TX_HASH_RECORDED_AT),
+            Some(EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER))
+        );
+        assert_eq!(outcome_at(mined(false, min_confirmations), ETH_TX_HASH_RECORDED_AT), Some(EthTransactionOutcome::Reverted));
+    }
+
+    #[test]
+    fn considers_a_transaction_dropped_once_the_drop_delay_has_passed() {
+        let drop_block = ETH_TX_HASH_RECORDED_AT + EthTransactionDropDelay::get();
+
+        assert_eq!(outcome_at(EthTransactionStatus::NotMined, drop_block - 1), None);
+        assert_eq!(outcome_at(EthTransactionStatus::NotMined, drop_block), Some(EthTransactionOutcome::Dropped));
+    }
+}
+
+mod check_outcome_of_sent_transactions {
+    use super::*;
+
+    #[test]
+    fn attests_the_outcome_reported_by_the_external_service() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            // Any validator attests the outcome, not just the submitter
+            let attester = validator(2);
+            mock_tx_status_response(&mut offchain_state.write(), mined(true, MinEthBlockConfirmation::get()));
+
+            EthereumTransactions::check_outcome_of_sent_transactions(&attester, ETH_TX_HASH_RECORDED_AT);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let outcome = EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER);
+            let signature = attester.key.sign(&(SET_TRANSACTION_OUTCOME, (&2, &tx_id, eth_tx_hash(), outcome)).encode()).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(crate::Call::set_transaction_outcome(2, tx_id, eth_tx_hash(), outcome, signature))
+            );
+        });
+    }
+
+    #[test]
+    fn attests_the_outcome_of_a_mined_replacement() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            <ReplacementEthTxHashes>::insert(tx_id, vec![replacement_eth_tx_hash(0)]);
+            mock_tx_status_response(&mut offchain_state.write(), EthTransactionStatus::NotMined);
+            mock_tx_status_response_for(
+                &mut offchain_state.write(),
+                format!("http://127.0.0.1:2020/eth/tx_status/0x{}", hex::encode(replacement_eth_tx_hash(0))),
+                mined(false, MinEthBlockConfirmation::get())
+            );
+            let attester = validator(2);
+
+            EthereumTransactions::check_outcome_of_sent_transactions(&attester, ETH_TX_HASH_RECORDED_AT);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let outcome = EthTransactionOutcome::Reverted;
+            let signature = attester.key.sign(&(SET_TRANSACTION_OUTCOME, (&2, &tx_id, eth_tx_hash(), outcome)).encode()).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(crate::Call::set_transaction_outcome(2, tx_id, eth_tx_hash(), outcome, signature))
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_attest_an_outcome_the_validator_already_attested() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            <OutcomeAttestations<TestRuntime>>::insert(tx_id, vec![(2, EthTransactionOutcome::Succeeded(ETH_BLOCK_NUMBER))]);
+            mock_tx_status_response(&mut offchain_state.write(), mined(true, MinEthBlockConfirmation::get()));
+
+            EthereumTransactions::check_outcome_of_sent_transactions(&validator(2), ETH_TX_HASH_RECORDED_AT);
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn does_not_attest_while_the_outcome_is_unknown() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            add_sent_transaction(SUBMITTER);
+            mock_tx_status_response(&mut offchain_state.write(), EthTransactionStatus::NotMined);
+
+            EthereumTransactions::check_outcome_of_sent_transactions(&validator(SUBMITTER), ETH_TX_HASH_RECORDED_AT);
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
+
+mod record_replacements_of_sent_transactions {
+    use super::*;
+
+    #[test]
+    fn records_the_replacements_sent_by_the_external_service() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            let tx_id = add_sent_transaction(SUBMITTER);
+            <ReplacementEthTxHashes>::insert(tx_id, vec![replacement_eth_tx_hash(0)]);
+            mock_tx_hashes_response(
+                &mut offchain_state.write(),
+                vec![eth_tx_hash(), replacement_eth_tx_hash(0), replacement_eth_tx_hash(1)]
+            );
+            let submitter = validator(SUBMITTER);
+
+            EthereumTransactions::record_replacements_of_sent_transactions(&submitter, ETH_TX_HASH_RECORDED_AT);
+
+            let tx = pool_state.write().transactions.pop().unwrap();
+            assert!(pool_state.read().transactions.is_empty());
+            let tx = Extrinsic::decode(&mut &*tx).unwrap();
+            let new_replacements = vec![replacement_eth_tx_hash(1)];
+            let signature = submitter.key.sign(
+                &(ADD_REPLACEMENT_ETH_TX_HASHES, (&SUBMITTER, &tx_id, eth_tx_hash(), &new_replacements)).encode()
+            ).unwrap();
+            assert_eq!(
+                tx.call,
+                mock::Call::EthereumTransactions(
+                    crate::Call::add_replacement_eth_tx_hashes(SUBMITTER, tx_id, eth_tx_hash(), new_replacements, signature)
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn does_nothing_when_the_transaction_was_not_replaced() {
+        let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            add_sent_transaction(SUBMITTER);
+            mock_tx_hashes_response(&mut offchain_state.write(), vec![eth_tx_hash()]);
+
+            EthereumTransactions::record_replacements_of_sent_transactions(&validator(SUBMITTER), ETH_TX_HASH_RECORDED_AT);
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+
+    #[test]
+    fn only_checks_the_transactions_the_validator_sent() {
+        let (mut ext, pool_state, _) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            add_sent_transaction(SUBMITTER);
+
+            // No request to the external service is expected
+            EthereumTransactions::record_replacements_of_sent_transactions(&validator(2), ETH_TX_HASH_RECORDED_AT);
+
+            assert!(pool_state.read().transactions.is_empty());
+        });
+    }
+}
+
+mod stop_monitoring_dropped_transactions {
+    use super::*;
+
+    #[test]
+    fn asks_the_external_service_to_stop_replacing_a_dropped_transaction() {
+        let (mut ext, _, offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            <DroppedEthTxHashes<TestRuntime>>::insert(1, vec![(SUBMITTER, eth_tx_hash())]);
+            mock_stop_monitoring_response(&mut offchain_state.write());
+
+            EthereumTransactions::stop_monitoring_dropped_transactions(&validator(SUBMITTER), ETH_TX_HASH_RECORDED_AT);
+        });
+    }
+
+    #[test]
+    fn ignores_the_transactions_dropped_by_other_validators() {
+        let (mut ext, _, _) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .with_validators()
+            .for_offchain_worker()
+            .as_externality_with_state();
+
+        ext.execute_with(|| {
+            <DroppedEthTxHashes<TestRuntime>>::insert(1, vec![(SUBMITTER, eth_tx_hash())]);
+
+            // No request to the external service is expected
+            EthereumTransactions::stop_monitoring_dropped_transactions(&validator(2), ETH_TX_HASH_RECORDED_AT);
+        });
+    }
+}
@@ -301,6 +301,31 @@ mod reserve_transaction_id {
                 verify_value_incremented_by_1(old_unique_identifier, new_unique_identifier)
             });
         }
+
+        #[test]
+        fn fails_if_contract_call_cannot_be_encoded() {
+            let mut ext = ExtBuilder::build_default()
+                .with_genesis_config()
+                .with_validators()
+                .as_externality();
+            ext.execute_with(|| {
+
+                let contract_call = EthTransactionType::ContractCall(
+                    ContractCallData::new(EthContractRole::ValidatorManager, "", vec![])
+                );
+                let old_unique_identifier = EthereumTransactions::get_current_unique_transaction_identifier();
+
+                assert_err!(
+                    EthereumTransactions::reserve_transaction_id(&contract_call),
+                    Error::<TestRuntime>::InvalidContractCall
+                );
+
+                let new_unique_identifier =
+                    EthereumTransactions::get_current_unique_transaction_identifier();
+
+                assert_eq!(old_unique_identifier, new_unique_identifier);
+            });
+        }
     }
 }
 
@@ -1172,6 +1197,30 @@ fn eth_get_contract_address_works_with_valid_input() {
     });
 }
 
+#[test]
+fn eth_get_contract_address_follows_the_role_of_a_contract_call() {
+    let mut ext = ExtBuilder::build_default()
+        .with_genesis_config()
+        .as_externality();
+
+    ext.execute_with(|| {
+        let set_royalties = |contract_role| EthTransactionType::ContractCall(ContractCallData::new(
+            contract_role,
+            "setRoyalties",
+            vec![EthCallParam::new("_royalties", EthCallParamValue::Bytes(vec![1]))],
+        ));
+
+        test_get_contract_address_for_eth_txn(
+            &set_royalties(EthContractRole::PublishRoot),
+            get_publish_root_default_contract()
+        );
+        test_get_contract_address_for_eth_txn(
+            &set_royalties(EthContractRole::ValidatorManager),
+            CUSTOM_VALIDATOR_MANAGER_CONTRACT
+        );
+    });
+}
+
 #[test]
 fn eth_get_contract_address_invalid_input() {
     let mut ext = ExtBuilder::build_default()
@@ -2,6 +2,7 @@
 
 use ethabi::{Function, Token, Param, ParamType};
 use crate::ethereum_transaction::*;
+use codec::Encode;
 
 pub const ROOT_HASH: [u8; 32] = [3; 32];
 pub const T2_PUBLIC_KEY: [u8; 32] = [4; 32];
@@ -145,3 +146,96 @@ mod deregister_validator_data {
         assert_eq!(deregister_validator_data.to_abi(), expected_eth_transaction_desc);
     }
 }
+
+// ContractCallData tests
+mod contract_call_data {
+    use super::*;
+    use sp_core::{H160, U256};
+
+    const RECIPIENT: [u8; 20] = [7; 20];
+
+    fn generate_release_lowered_data() -> ContractCallData {
+        ContractCallData::new(
+            EthContractRole::ValidatorManager,
+            "releaseLowered",
+            vec![
+                EthCallParam::new("_recipient", EthCallParamValue::Address(H160::from(RECIPIENT))),
+                EthCallParam::new("_amount", EthCallParamValue::Uint256(U256::from(100))),
+                EthCallParam::new("_proof", EthCallParamValue::Bytes(vec![1, 2, 3])),
+            ],
+        )
+    }
+
+    #[test]
+    fn to_abi_succeeds() {
+        let expected_eth_transaction_desc = EthTransactionDescription {
+            function_call: Function {
+                name: String::from("releaseLowered"),
+                inputs: vec![
+                    Param { name: String::from("_recipient"), kind: ParamType::Address },
+                    Param { name: String::from("_amount"), kind: ParamType::Uint(256) },
+                    Param { name: String::from("_proof"), kind: ParamType::Bytes },
+                ],
+                outputs: Vec::<Param>::new(),
+                constant: false,
+            },
+            call_values: vec![
+                Token::Address(H160::from(RECIPIENT)),
+                Token::Uint(EthAbiHelper::u256_to_big_endian(&U256::from(100)).into()),
+                Token::Bytes(vec![1, 2, 3]),
+            ],
+        };
+
+        assert_eq!(generate_release_lowered_data().to_abi().unwrap(), expected_eth_transaction_desc);
+    }
+
+    #[test]
+    fn to_abi_matches_the_dedicated_transaction_types() {
+        let publish_root_call = ContractCallData::new(
+            EthContractRole::PublishRoot,
+            "publishRoot",
+            vec![EthCallParam::new("_rootHash", EthCallParamValue::Bytes32(ROOT_HASH))],
+        );
+
+        assert_eq!(publish_root_call.to_abi().unwrap(), generate_publish_root_eth_txn_desc(ROOT_HASH));
+    }
+
+    #[test]
+    fn to_abi_fails_when_function_name_is_empty() {
+        let mut contract_call = generate_release_lowered_data();
+        contract_call.function_name = vec![];
+
+        assert!(contract_call.to_abi().is_err());
+    }
+
+    #[test]
+    fn to_abi_fails_when_a_name_is_not_utf8() {
+        let mut contract_call = generate_release_lowered_data();
+        contract_call.params[0].name = vec![0xff, 0xfe];
+
+        assert!(contract_call.to_abi().is_err());
+    }
+
+    #[test]
+    fn contract_role_is_the_target_of_the_call() {
+        let contract_call = generate_release_lowered_data();
+
+        assert_eq!(
+            EthTransactionType::ContractCall(contract_call).get_contract_role(),
+            Some(EthContractRole::ValidatorManager)
+        );
+        assert_eq!(
+            EthTransactionType::PublishRoot(generate_publish_root_data(ROOT_HASH)).get_contract_role(),
+            Some(EthContractRole::PublishRoot)
+        );
+        assert_eq!(EthTransactionType::Invalid.get_contract_role(), None);
+    }
+
+    #[test]
+    fn is_encoded_after_the_existing_transaction_types() {
+        // Stored transactions of the existing types must still decode
+        assert_eq!(EthTransactionType::Invalid.encode(), vec![4]);
+        assert_eq!(EthTransactionType::Discarded(1).encode()[0], 5);
+        assert_eq!(EthTransactionType::ContractCall(generate_release_lowered_data()).encode()[0], 6);
+    }
+}
@@ -60,6 +60,7 @@ mod cancel_single_nft_listing {
                 event_id: EthEventId {
                     signature: ValidEvents::NftCancelListing.signature(),
                     transaction_hash: H256::from([1u8; 32]),
+                    log_index: 0,
                 },
             }
         }
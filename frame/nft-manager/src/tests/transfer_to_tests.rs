@@ -61,6 +61,7 @@ mod transfer_eth_nft {
                 event_id: EthEventId {
                     signature: ValidEvents::NftTransferTo.signature(),
                     transaction_hash: H256::from([1u8; 32]),
+                    log_index: 0,
                 },
             }
         }
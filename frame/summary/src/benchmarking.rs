@@ -528,6 +528,31 @@ benchmarks! {
         ).into());
     }
 
+    skip_slot {
+        let number_of_validators = MAX_VALIDATOR_ACCOUNT_IDS;
+        let validators = setup_validators::<T>(number_of_validators);
+        let (sender, _, _, signature, _) = setup_publish_root_voting::<T>(validators.clone());
+
+        CurrentSlotsValidator::<T>::put(sender.account_id.clone());
+
+        // The slot is still active
+        let current_block_number = System::<T>::block_number();
+        NextSlotAtBlock::<T>::put(current_block_number + SchedulePeriod::<T>::get());
+
+        // Create an offence: last published summary slot number < current slot number
+        let old_slot_number: T::BlockNumber = 2u32.into();
+        CurrentSlot::<T>::put(old_slot_number);
+
+        let last_summary_slot: T::BlockNumber = 1u32.into();
+        SlotOfLastPublishedSummary::<T>::put(last_summary_slot);
+    }: skip_slot(RawOrigin::None, sender.clone(), signature)
+    verify {
+        assert_eq!(CurrentSlot::<T>::get(), old_slot_number + 1u32.into());
+        assert_eq!(NextSlotAtBlock::<T>::get(), current_block_number + SchedulePeriod::<T>::get());
+        assert_eq!(false, CurrentSlotsValidator::<T>::get() == sender.account_id.clone());
+        assert_last_event::<T>(RawEvent::SlotSkipped(sender.account_id, old_slot_number).into());
+    }
+
     add_challenge {
         let number_of_validators = 4;
         let validators = setup_validators::<T>(number_of_validators);
@@ -619,6 +644,7 @@ mod tests {
             assert_ok!(test_benchmark_end_voting_period_with_approved_invalid_votes::<TestRuntime>());
             assert_ok!(test_benchmark_advance_slot_with_offence::<TestRuntime>());
             assert_ok!(test_benchmark_advance_slot_without_offence::<TestRuntime>());
+            assert_ok!(test_benchmark_skip_slot::<TestRuntime>());
 
             // TODO: SYS-1976 Fix 'InvalidECDSASignature'
             // assert_ok!(test_benchmark_approve_root_with_end_voting::<TestRuntime>());
@@ -18,6 +18,7 @@ pub trait WeightInfo {
     fn advance_slot_with_offence() -> Weight;
     fn advance_slot_without_offence() -> Weight;
     fn add_challenge() -> Weight;
+    fn skip_slot() -> Weight;
 }
 
 /// Weights for pallet_summary
@@ -103,6 +104,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(DbWeight::get().reads(14 as Weight))
 			.saturating_add(DbWeight::get().writes(7 as Weight))
 	}
+	fn skip_slot() -> Weight {
+		(375_563_000 as Weight)
+			.saturating_add(DbWeight::get().reads(14 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -180,4 +186,9 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(15 as Weight))
 			.saturating_add(DbWeight::get().writes(7 as Weight))
 	}
+	fn skip_slot() -> Weight {
+		(375_563_000 as Weight)
+			.saturating_add(DbWeight::get().reads(14 as Weight))
+			.saturating_add(DbWeight::get().writes(7 as Weight))
+	}
 }
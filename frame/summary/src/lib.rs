@@ -58,6 +58,7 @@ use crate::offence::{SummaryOffence, SummaryOffenceType, create_and_report_summa
 const NAME: &'static [u8; 7] = b"summary";
 const UPDATE_BLOCK_NUMBER_CONTEXT: &'static [u8] = b"update_last_processed_block_number";
 const ADVANCE_SLOT_CONTEXT: &'static [u8] = b"advance_slot";
+const SKIP_SLOT_CONTEXT: &'static [u8] = b"skip_slot";
 
 // Error codes returned by validate unsigned methods
 const ERROR_CODE_VALIDATOR_IS_NOT_PRIMARY: u8 = 10;
@@ -151,6 +152,8 @@ decl_event!(
             /*slot where a block was last published*/ BlockNumber,
             /*block number for end of the void slot*/ BlockNumber,
             ),
+        /// The slot validator cannot fund the publication of a summary, so it handed its slot over to the next validator
+        SlotSkipped(/*slot validator*/ AccountId, /*skipped slot*/ BlockNumber),
     }
 );
 
@@ -188,6 +191,7 @@ decl_error! {
         VotingPeriodIsTooLong,
         VotingPeriodIsLessThanFinalityReportLatency,
         VotingPeriodIsEqualOrLongerThanSchedulePeriod,
+        SlotHasEnded,
     }
 }
 
@@ -470,6 +474,39 @@ decl_module! {
             Ok(())
         }
 
+        /// # <weight>
+        ///  DbReads: 3 * `CurrentSlot`, 2 * `NextSlotAtBlock`, 3 * `CurrentSlotsValidator`,
+        ///           `SlotOfLastPublishedSummary`, `SchedulePeriod`: O(1)
+        ///  DbWrites: `CurrentSlot`, `CurrentSlotsValidator`, `NextSlotAtBlock`: O(1)
+        ///  avn pallet calculate primary validator operations:
+        ///     - DbReads: Validators: O(1)
+        ///  Emit events: `SlotAdvanced`, `SlotSkipped`: O(1)
+        /// Total Complexity: O(1)
+        /// # </weight>
+        #[weight = <T as Config>::WeightInfo::skip_slot()]
+        fn skip_slot(
+            origin,
+            validator: Validator<<T as avn::Config>::AuthorityId, T::AccountId>,
+            _signature: <T::AuthorityId as RuntimeAppPublic>::Signature) -> DispatchResult
+        {
+            ensure_none(origin)?;
+            ensure!(validator.account_id == Self::slot_validator(), Error::<T>::WrongValidator);
+
+            let current_block_number = <frame_system::Module<T>>::block_number();
+            ensure!(current_block_number < Self::block_number_for_next_slot(), Error::<T>::SlotHasEnded);
+
+            // A validator skips its slot when its wallet cannot fund the summary, which is not an offence, so it is not reported.
+            // The next validator gets a full slot to create the summary instead of waiting for this one to end.
+            let skipped_slot = Self::current_slot();
+            let next_slot_start_block = safe_add_block_numbers::<T::BlockNumber>(current_block_number, Self::schedule_period())
+                .map_err(|_| Error::<T>::Overflow)?;
+
+            Self::start_next_slot(validator.account_id.clone(), next_slot_start_block)?;
+
+            Self::deposit_event(Event::<T>::SlotSkipped(validator.account_id, skipped_slot));
+            Ok(())
+        }
+
         /// # <weight>
         ///  DbReads: `NextBlockToProcess`, `NextSlotAtBlock`, `CurrentSlot`, `CurrentSlotsValidator`,
         ///           `SlotOfLastPublishedSummary`: O(1)
@@ -619,6 +656,15 @@ impl<T: Config> Module<T> {
         // this code is always called inside an unsigned transaction, so in consensus. We can raise offences here.
         Self::register_offence_if_no_summary_created_in_slot(&validator);
 
+        let next_slot_start_block = safe_add_block_numbers::<T::BlockNumber>(
+            Self::block_number_for_next_slot(),
+            Self::schedule_period())
+        .map_err(|_| Error::<T>::Overflow)?;
+
+        return Self::start_next_slot(validator.account_id, next_slot_start_block);
+    }
+
+    fn start_next_slot(advanced_by: T::AccountId, next_slot_start_block: T::BlockNumber) -> DispatchResult {
         let new_slot_number = safe_add_block_numbers::<T::BlockNumber>(
             Self::current_slot(),
             1u32.into())
@@ -626,17 +672,12 @@ impl<T: Config> Module<T> {
 
         let new_validator_account_id = AVN::<T>::calculate_primary_validator(new_slot_number)?;
 
-        let next_slot_start_block = safe_add_block_numbers::<T::BlockNumber>(
-            Self::block_number_for_next_slot(),
-            Self::schedule_period())
-        .map_err(|_| Error::<T>::Overflow)?;
-
         <CurrentSlot<T>>::put(new_slot_number);
         <CurrentSlotsValidator<T>>::put(new_validator_account_id.clone());
         <NextSlotAtBlock<T>>::put(next_slot_start_block);
 
         Self::deposit_event(Event::<T>::SlotAdvanced(
-            validator.account_id,
+            advanced_by,
             new_slot_number,
             new_validator_account_id,
             next_slot_start_block));
@@ -741,8 +782,18 @@ impl<T: Config> Module<T> {
         let root_lock_name = Self::create_root_lock_name(last_block_in_range);
         let expiration = Self::lock_till_request_expires();
 
-        if Self::can_process_summary(block_number, last_block_in_range, this_validator) &&
-            OcwLock::set_lock_with_expiry(
+        if !Self::can_process_summary(block_number, last_block_in_range, this_validator) {
+            return;
+        }
+
+        if !Self::ethereum_wallet_can_publish_summary() {
+            if let Err(e) = Self::dispatch_skip_slot(this_validator) {
+                debug::native::warn!("💔️ Error skipping summary slot {:?}: {:?}", Self::current_slot(), e);
+            }
+            return;
+        }
+
+        if OcwLock::set_lock_with_expiry(
                 block_number,
                 expiration,
                 root_lock_name.clone()).is_ok()
@@ -865,6 +916,42 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    // called from OCW - no storage changes allowed here
+    // The slot validator pays for publishing the summary on Ethereum. If its wallet cannot pay, it hands the slot over
+    // instead of creating a summary that will never be published.
+    fn ethereum_wallet_can_publish_summary() -> bool {
+        return match AVN::<T>::get_ethereum_wallet_status() {
+            Ok(wallet) if !wallet.can_pay_for_transaction() => {
+                debug::native::warn!(
+                    "⚠️  Skipping summary slot {:?}: Ethereum wallet {:?} cannot pay {:?} wei for a transaction from its balance of {:?} wei and keep its reserve of {:?} wei",
+                    Self::current_slot(),
+                    wallet.address,
+                    wallet.transaction_cost,
+                    wallet.balance,
+                    wallet.reserve);
+                false
+            },
+            Ok(_) => true,
+            Err(e) => {
+                // Don't skip the slot if we can't tell. The publication will fail later if the wallet is really underfunded.
+                debug::native::warn!("💔️ Error getting the Ethereum wallet status: {:?}", e);
+                true
+            }
+        };
+    }
+
+    fn dispatch_skip_slot(validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>) -> DispatchResult {
+        let signature = validator.key
+            .sign(&(SKIP_SLOT_CONTEXT, Self::current_slot()).encode())
+            .ok_or(Error::<T>::ErrorSigning)?;
+
+        SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+            Call::skip_slot(validator.clone(), signature).into()
+        ).map_err(|_| Error::<T>::ErrorSubmittingTransaction)?;
+
+        Ok(())
+    }
+
     fn dispatch_advance_slot(validator: &Validator<<T as avn::Config>::AuthorityId, T::AccountId>) -> DispatchResult {
         let signature = validator.key
             .sign(&(ADVANCE_SLOT_CONTEXT, Self::current_slot()).encode())
@@ -1036,6 +1123,29 @@ impl<T: Config> Module<T> {
         return InvalidTransaction::Call.into();
     }
 
+    fn skip_slot_validate_unsigned(_source: TransactionSource, call: &Call<T>) -> TransactionValidity {
+        if let Call::skip_slot(validator, signature) = call {
+            if validator.account_id != Self::slot_validator() {
+                return InvalidTransaction::Custom(ERROR_CODE_VALIDATOR_IS_NOT_PRIMARY).into();
+            }
+
+            let current_slot = Self::current_slot();
+            let signed_data = &(SKIP_SLOT_CONTEXT, current_slot);
+            if !AVN::<T>::signature_is_valid(signed_data, &validator, signature) {
+                return InvalidTransaction::BadProof.into();
+            };
+
+            return ValidTransaction::with_tag_prefix("Summary")
+                .priority(TransactionPriority::max_value())
+                .and_provides(vec![(SKIP_SLOT_CONTEXT, current_slot).encode()])
+                .longevity(64_u64)
+                .propagate(true)
+                .build();
+        }
+
+        return InvalidTransaction::Call.into();
+    }
+
     fn empty_root() -> H256 {
         return H256::from_slice(&[0; 32]);
     }
@@ -1092,6 +1202,9 @@ impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
         } else if let Call::advance_slot(_validator, _signature) = call {
             return Self::advance_slot_validate_unsigned(source, call);
 
+        } else if let Call::skip_slot(_validator, _signature) = call {
+            return Self::skip_slot_validate_unsigned(source, call);
+
         } else {
             return InvalidTransaction::Call.into();
         }
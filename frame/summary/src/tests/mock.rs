@@ -11,11 +11,13 @@ use pallet_avn::{self as avn, vote::VotingSessionData, EthereumPublicKeyChecker,
 use pallet_session as session;
 use sp_core::{
     offchain::testing::{OffchainState, PendingRequest},
+    H160,
     H256,
+    U256,
     ecdsa,
 };
 use sp_application_crypto::TryFrom;
-use sp_avn_common::{safe_add_block_numbers, safe_sub_block_numbers};
+use sp_avn_common::{safe_add_block_numbers, safe_sub_block_numbers, EthWalletStatus};
 use sp_runtime::{
     testing::{Header, TestSignature, TestXt, UintAuthorityId},
     traits::{BlakeTwo256, ConvertInto, IdentityLookup},
@@ -602,6 +604,28 @@ pub fn mock_response_of_get_roothash(
     });
 }
 
+pub const ETHEREUM_WALLET_RESERVE: u128 = 50_000_000_000_000_000;
+pub const ETHEREUM_TRANSACTION_COST: u128 = 10_000_000_000_000_000;
+
+pub fn mock_response_of_get_ethereum_wallet(state: &mut OffchainState, can_pay_for_transaction: bool) {
+    let reserve = U256::from(ETHEREUM_WALLET_RESERVE);
+    let transaction_cost = U256::from(ETHEREUM_TRANSACTION_COST);
+    let balance = match can_pay_for_transaction {
+        true => reserve + transaction_cost,
+        // There are funds above the reserve, but not enough to pay for a transaction
+        false => reserve + transaction_cost - 1,
+    };
+    let wallet_status = EthWalletStatus::new(H160::from([1; 20]), balance, reserve, transaction_cost);
+
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: "http://127.0.0.1:2020/eth/wallet".into(),
+        response: Some(hex::encode(wallet_status.encode()).into_bytes()),
+        sent: true,
+        ..Default::default()
+    });
+}
+
 pub fn mock_response_of_get_ecdsa_signature(
     state: &mut OffchainState,
     data_to_sign: String,
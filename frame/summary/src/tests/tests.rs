@@ -138,6 +138,7 @@ mod process_summary_if_required {
             ext.execute_with(|| {
                 let context = setup_context();
 
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -177,6 +178,7 @@ mod process_summary_if_required {
 
                 // Fails at the default current block
                 let fake_failure_response = b"0".to_vec();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -194,6 +196,7 @@ mod process_summary_if_required {
 
                 // Retries and succeeds at the next block
                 let fake_successful_response = context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -221,6 +224,7 @@ mod process_summary_if_required {
             ext.execute_with(|| {
                 let setup = setup_success_preconditions();
 
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     setup.url_param.clone(),
@@ -255,6 +259,7 @@ mod process_summary_if_required {
 
                 let early_block_number = setup.target_block + setup.min_block_age;
 
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     setup.url_param.clone(),
@@ -311,6 +316,7 @@ mod process_summary_if_required {
 
                 let last_valid_block_number = setup.block_number_for_next_slot - 1;
 
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     setup.url_param.clone(),
@@ -408,6 +414,37 @@ mod process_summary_if_required {
         }
     }
 
+    mod skips_the_slot_when {
+        use super::*;
+
+        #[test]
+        fn ethereum_wallet_cannot_pay_for_a_transaction() {
+            let (mut ext, pool_state, offchain_state) = ExtBuilder::build_default()
+                .with_validators()
+                .for_offchain_worker()
+                .as_externality_with_state();
+
+            ext.execute_with(|| {
+                let setup = setup_success_preconditions();
+
+                // The root hash is not requested, so only the wallet is mocked
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), false);
+
+                assert!(pool_state.read().transactions.is_empty());
+
+                Summary::process_summary_if_required(setup.current_block, &setup.slot_validator);
+
+                let submitted_call = get_unsigned_record_summary_calculation_call_from_chain(&pool_state);
+                let signature = setup.slot_validator.key
+                    .sign(&(SKIP_SLOT_CONTEXT, Summary::current_slot()).encode())
+                    .expect("Signature is signed");
+
+                assert_eq!(submitted_call, Call::skip_slot(setup.slot_validator.clone(), signature));
+                assert!(pool_state.read().transactions.is_empty());
+            });
+        }
+    }
+
     mod stops_to_call_record_summary_calculation_when {
         use super::*;
 
@@ -430,6 +467,7 @@ mod process_summary_if_required {
                 let fake_successful_response = context.root_hash_vec.clone();
 
                 // Fails at the default current block #10
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -443,6 +481,7 @@ mod process_summary_if_required {
 
                 // Advance to block #11
                 let current_block_number = advance_block_numbers(1);
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -458,6 +497,7 @@ mod process_summary_if_required {
                 // By providing a successful compute root hash response, the validator should not be able to reach it
                 // and call the transaction as the slot has expired
                 let current_block_number = advance_block_numbers(1);
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     context.url_param.clone(),
@@ -1453,6 +1493,7 @@ mod if_process_summary_is_called_a_second_time {
 
                 // Mock a successful compute root hash response for the first time process summary
                 let fake_successful_response_1 = first_process_summary_context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     first_process_summary_context.url_param.clone(),
@@ -1488,6 +1529,7 @@ mod if_process_summary_is_called_a_second_time {
 
                 // Mock successful compute root hash response for the second summary process
                 let fake_successful_response_2 = second_process_summary_context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     second_process_summary_context.url_param.clone(),
@@ -1532,6 +1574,7 @@ mod if_process_summary_is_called_a_second_time {
 
                 // Mock successful compute root hash response for the first time process summary
                 let fake_successful_response_1 = first_process_summary_context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     first_process_summary_context.url_param.clone(),
@@ -1565,6 +1608,7 @@ mod if_process_summary_is_called_a_second_time {
                 // Mock successful compute root hash response to process summary for the second time [from:3;to:4]
                 // This is a new root hash value that is different from the first time
                 let fake_successful_response_2 = second_process_summary_context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     second_process_summary_context.url_param.clone(),
@@ -1609,6 +1653,7 @@ mod if_process_summary_is_called_a_second_time {
 
                 // Mock successful compute root hash response for the first time process summary
                 let fake_successful_response_1 = first_process_summary_context.root_hash_vec.clone();
+                mock_response_of_get_ethereum_wallet(&mut offchain_state.write(), true);
                 mock_response_of_get_roothash(
                     &mut offchain_state.write(),
                     first_process_summary_context.url_param.clone(),
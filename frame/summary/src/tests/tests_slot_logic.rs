@@ -510,6 +510,195 @@ mod advance_slot {
     }
 }
 
+mod skip_slot {
+    use super::*;
+
+    struct LocalContext {
+        pub slot_validator: MockValidator,
+        pub other_validator: MockValidator,
+        pub slot_number: BlockNumber,
+        pub block_number_for_next_slot: BlockNumber,
+    }
+
+    fn setup_success_preconditions() -> LocalContext {
+        let context = advance_slot::setup_success_preconditions();
+
+        // The slot is still active
+        System::set_block_number(context.block_number_for_next_slot - 1);
+
+        return LocalContext {
+            slot_validator: context.slot_validator,
+            other_validator: context.other_validator,
+            slot_number: context.slot_number,
+            block_number_for_next_slot: context.block_number_for_next_slot,
+        };
+    }
+
+    fn create_signature(slot_number: BlockNumber, validator: &MockValidator) -> TestSignature {
+        return validator.key
+            .sign(&(SKIP_SLOT_CONTEXT, slot_number).encode())
+            .expect("Signature is signed");
+    }
+
+    fn call_skip_slot(validator: &MockValidator) -> DispatchResult {
+        let signature = create_signature(Summary::current_slot(), validator);
+        return Summary::skip_slot(RawOrigin::None.into(), validator.clone(), signature);
+    }
+
+    mod succeeds {
+        use super::*;
+
+        #[test]
+        fn and_hands_the_slot_to_the_next_validator() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                let current_block = System::block_number();
+                let next_slot_start_block = current_block + Summary::schedule_period();
+
+                assert_ok!(call_skip_slot(&context.slot_validator));
+
+                assert_eq!(Summary::current_slot(), context.slot_number + 1);
+                assert_eq!(Summary::slot_validator(), context.other_validator.account_id);
+                assert_eq!(Summary::block_number_for_next_slot(), next_slot_start_block);
+                assert!(Summary::emitted_event(&mock::Event::summary(
+                    Event::<TestRuntime>::SlotAdvanced(
+                        context.slot_validator.account_id,
+                        context.slot_number + 1,
+                        context.other_validator.account_id,
+                        next_slot_start_block
+                    )
+                )));
+                assert!(Summary::emitted_event(&mock::Event::summary(
+                    Event::<TestRuntime>::SlotSkipped(context.slot_validator.account_id, context.slot_number)
+                )));
+            });
+        }
+
+        #[test]
+        fn and_does_not_report_the_slot_validator_when_no_summary_was_created() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                Summary::set_previous_summary_slot(0);
+
+                assert_ok!(call_skip_slot(&context.slot_validator));
+
+                assert_eq!(false, Summary::reported_offence_of_type(SummaryOffenceType::NoSummaryCreated));
+                assert_eq!(false, Summary::emitted_event_for_offence_of_type(SummaryOffenceType::NoSummaryCreated));
+            });
+        }
+
+        #[test]
+        fn and_does_not_report_the_slot_validator_when_a_summary_was_created() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                Summary::set_previous_summary_slot(context.slot_number);
+
+                assert_ok!(call_skip_slot(&context.slot_validator));
+
+                assert_eq!(false, Summary::reported_offence_of_type(SummaryOffenceType::NoSummaryCreated));
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_signed() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                let signature = create_signature(Summary::current_slot(), &context.slot_validator);
+
+                assert_noop!(
+                    Summary::skip_slot(Origin::signed(Default::default()), context.slot_validator.clone(), signature),
+                    BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn called_by_other_validator() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+
+                assert_noop!(call_skip_slot(&context.other_validator), Error::<TestRuntime>::WrongValidator);
+            });
+        }
+
+        #[test]
+        fn slot_has_ended() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                System::set_block_number(context.block_number_for_next_slot);
+
+                assert_noop!(call_skip_slot(&context.slot_validator), Error::<TestRuntime>::SlotHasEnded);
+            });
+        }
+
+        #[test]
+        fn slot_is_already_skipped() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                assert_ok!(call_skip_slot(&context.slot_validator));
+
+                assert_noop!(call_skip_slot(&context.slot_validator), Error::<TestRuntime>::WrongValidator);
+            });
+        }
+    }
+
+    mod signature {
+        use super::*;
+        use frame_support::unsigned::ValidateUnsigned;
+
+        #[test]
+        fn is_accepted_by_validate_unsigned() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                let signature = create_signature(Summary::current_slot(), &context.slot_validator);
+
+                assert_ok!(Summary::validate_unsigned(
+                    TransactionSource::Local,
+                    &crate::Call::skip_slot(context.slot_validator.clone(), signature)
+                ));
+            });
+        }
+
+        #[test]
+        fn is_rejected_with_a_wrong_context() {
+            let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+            ext.execute_with(|| {
+                let context = setup_success_preconditions();
+                let signature = advance_slot::create_signature(Summary::current_slot(), &context.slot_validator);
+
+                assert_noop!(
+                    Summary::validate_unsigned(
+                        TransactionSource::Local,
+                        &crate::Call::skip_slot(context.slot_validator.clone(), signature)
+                    ),
+                    InvalidTransaction::BadProof
+                );
+            });
+        }
+    }
+}
+
 mod signature_in {
     use super::*;
     use frame_support::unsigned::ValidateUnsigned;
@@ -9,7 +9,8 @@ use codec::{Encode, Decode};
 use sp_std::{prelude::*};
 use sp_avn_common::{
     event_types::Validator,
-    offchain_worker_storage_lock:: {self as OcwLock}
+    offchain_worker_storage_lock:: {self as OcwLock},
+    ocw_metrics::{self, OCW_ROOTS_VOTED_COUNTER_KEY},
 };
 
 use sp_core::ecdsa;
@@ -156,6 +157,7 @@ pub fn cast_votes_if_required<T: Config>(
             }
         }
 
+        ocw_metrics::increment_counter(OCW_ROOTS_VOTED_COUNTER_KEY);
         return;
     }
 }
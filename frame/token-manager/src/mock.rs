@@ -305,6 +305,7 @@ impl MockData {
                 event_id: EthEventId {
                     signature: ValidEvents::Lifted.signature(),
                     transaction_hash: H256::random(),
+                    log_index: 0,
                 },
                 event_data: Self::get_event_data(amount_to_lift, &lift_avt_token_event_topics),
             },
@@ -312,6 +313,7 @@ impl MockData {
                 event_id: EthEventId {
                     signature: ValidEvents::Lifted.signature(),
                     transaction_hash: H256::random(),
+                    log_index: 0,
                 },
                 event_data: Self::get_event_data(amount_to_lift, &lift_non_avt_token_event_topics),
             },
@@ -319,6 +321,7 @@ impl MockData {
                 event_id: EthEventId {
                     signature: ValidEvents::Lifted.signature(),
                     transaction_hash: H256::random(),
+                    log_index: 0,
                 },
                 event_data: EventData::EmptyEvent,
             },
@@ -676,6 +676,7 @@ impl MockData {
         let event_id = EthEventId {
             signature: ValidEvents::AddedValidator.signature(),
             transaction_hash: H256::random(),
+            log_index: 0,
         };
         let data = Some(LogDataHelper::get_validator_data(REGISTERING_VALIDATOR_TIER1_ID));
         let topics = MockData::get_validator_token_topics();
@@ -1,5 +1,5 @@
 
-use sp_std::{vec::Vec, convert::TryInto};
+use sp_std::{vec, vec::Vec, convert::TryInto};
 use sp_core::{H160, H256, H512, U256};
 use codec::{Encode, Decode};
 use sp_runtime::{traits::Member, DispatchResult};
@@ -48,6 +48,15 @@ pub enum Error {
     NftEndBatchListingEventShouldOnlyContainTopics,
     NftEndBatchListingEventWrongTopicCount,
     NftEndBatchListingEventBadTopicLength,
+
+    DecodedEventInvalidDescriptor,
+    DecodedEventWrongTopicCount,
+    DecodedEventBadTopicLength,
+    DecodedEventMissingData,
+    DecodedEventShouldOnlyContainTopics,
+    DecodedEventBadDataLength,
+    DecodedEventValueOverflow,
+    DecodedEventBadDynamicDataOffset,
 }
 
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
@@ -106,6 +115,17 @@ impl ValidEvents {
         }
     }
 
+    pub fn all() -> Vec<ValidEvents> {
+        return vec![
+            ValidEvents::AddedValidator,
+            ValidEvents::Lifted,
+            ValidEvents::NftMint,
+            ValidEvents::NftTransferTo,
+            ValidEvents::NftCancelListing,
+            ValidEvents::NftEndBatchListing,
+        ];
+    }
+
     pub fn is_nft_event(&self) -> bool {
         match *self {
             ValidEvents::NftMint |
@@ -484,11 +504,14 @@ pub enum EventData {
     LogNftTransferTo(NftTransferToData),
     LogNftCancelListing(NftCancelListingData),
     LogNftEndBatchListing(NftEndBatchListingData),
+    LogDecoded(DecodedEventData),
 }
 
 impl EventData {
+    /// Decoded logs can only be checked against the descriptor they were decoded with, which is the descriptor
+    /// registered for the signature of the event. The other event types are checked without it.
     #[allow(unreachable_patterns)]
-    pub fn is_valid(&self) -> bool {
+    pub fn is_valid(&self, descriptor: Option<&EthEventDescriptor>) -> bool {
         return match self {
             EventData::LogAddedValidator(d) => d.is_valid(),
             EventData::LogLifted(d) => d.is_valid(),
@@ -496,6 +519,7 @@ impl EventData {
             EventData::LogNftTransferTo(d) => d.is_valid(),
             EventData::LogNftCancelListing(d) => d.is_valid(),
             EventData::LogNftEndBatchListing(d) => d.is_valid(),
+            EventData::LogDecoded(d) => descriptor.map_or(false, |descriptor| d.is_valid(descriptor)),
             EventData::EmptyEvent => true,
             _ => false,
         };
@@ -506,8 +530,220 @@ impl Default for EventData {
     fn default() -> Self { EventData::EmptyEvent }
 }
 
+// ================================= Event descriptors ====================================
+
+/// Solidity types the generic log decoder understands
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EthAbiParamType {
+    Address,
+    /// uintN, where N is the number of bits (a multiple of 8, from 8 to 256)
+    Uint(u16),
+    Bool,
+    /// bytesN, where N is the number of bytes (from 1 to 32)
+    FixedBytes(u8),
+    /// Dynamic bytes. When indexed, the topic only holds the Keccak-256 hash of the value.
+    Bytes,
+    /// Dynamic string. When indexed, the topic only holds the Keccak-256 hash of the value.
+    String,
+}
+
+impl EthAbiParamType {
+    pub fn is_valid(&self) -> bool {
+        return match *self {
+            EthAbiParamType::Uint(bits) => bits >= 8 && bits <= 256 && bits % 8 == 0,
+            EthAbiParamType::FixedBytes(size) => size >= 1 && size as usize <= WORD_LENGTH,
+            _ => true,
+        };
+    }
+
+    fn is_dynamic(&self) -> bool {
+        return *self == EthAbiParamType::Bytes || *self == EthAbiParamType::String;
+    }
+
+    /// True if `token` is a value `decode_log` can produce for a param of this type
+    fn matches(&self, token: &EthAbiToken, indexed: bool) -> bool {
+        return match (self, token) {
+            (EthAbiParamType::Address, EthAbiToken::Address(_)) => true,
+            (EthAbiParamType::Uint(bits), EthAbiToken::Uint(value)) => value.bits() <= *bits as usize,
+            (EthAbiParamType::Bool, EthAbiToken::Bool(_)) => true,
+            (EthAbiParamType::FixedBytes(size), EthAbiToken::FixedBytes(value)) => value.len() == *size as usize,
+            // Indexed dynamic values are replaced by their hash
+            (EthAbiParamType::Bytes, EthAbiToken::FixedBytes(hash)) |
+            (EthAbiParamType::String, EthAbiToken::FixedBytes(hash)) => indexed && hash.len() == WORD_LENGTH,
+            (EthAbiParamType::Bytes, EthAbiToken::Bytes(_)) |
+            (EthAbiParamType::String, EthAbiToken::String(_)) => !indexed,
+            _ => false,
+        };
+    }
+
+    fn decode_word(&self, word: &[u8]) -> Result<EthAbiToken, Error> {
+        match *self {
+            EthAbiParamType::Address => {
+                ensure_zero_bytes(&word[0..DISCARDED_ZERO_BYTES])?;
+                return Ok(EthAbiToken::Address(H160::from_slice(&word[DISCARDED_ZERO_BYTES..WORD_LENGTH])));
+            },
+            EthAbiParamType::Uint(bits) => {
+                ensure_zero_bytes(&word[0..WORD_LENGTH - bits as usize / 8])?;
+                return Ok(EthAbiToken::Uint(<U256 as From<&[u8]>>::from(word)));
+            },
+            EthAbiParamType::Bool => {
+                ensure_zero_bytes(&word[0..WORD_LENGTH - BYTE_LENGTH])?;
+                return match word[WORD_LENGTH - BYTE_LENGTH] {
+                    0 => Ok(EthAbiToken::Bool(false)),
+                    1 => Ok(EthAbiToken::Bool(true)),
+                    _ => Err(Error::DecodedEventValueOverflow),
+                };
+            },
+            EthAbiParamType::FixedBytes(size) => {
+                // bytesN values are left aligned
+                ensure_zero_bytes(&word[size as usize..WORD_LENGTH])?;
+                return Ok(EthAbiToken::FixedBytes(word[0..size as usize].to_vec()));
+            },
+            // Indexed dynamic values are replaced by their hash
+            EthAbiParamType::Bytes | EthAbiParamType::String => return Ok(EthAbiToken::FixedBytes(word.to_vec())),
+        }
+    }
+}
+
+fn ensure_zero_bytes(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.iter().any(|byte| byte > &0) {
+        return Err(Error::DecodedEventValueOverflow);
+    }
+
+    return Ok(());
+}
+
+fn read_word_as_usize(data: &[u8], position: usize) -> Result<usize, Error> {
+    let word_end = position.checked_add(WORD_LENGTH).ok_or(Error::DecodedEventBadDynamicDataOffset)?;
+    let word = data.get(position..word_end).ok_or(Error::DecodedEventBadDynamicDataOffset)?;
+    if word[0..TWENTY_FOUR_BYTES].iter().any(|byte| byte > &0) {
+        return Err(Error::DecodedEventBadDynamicDataOffset);
+    }
+
+    let value = u64::from_be_bytes(word[TWENTY_FOUR_BYTES..WORD_LENGTH].try_into().expect("Slice is the correct size"));
+    return value.try_into().map_err(|_| Error::DecodedEventBadDynamicDataOffset);
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EthAbiToken {
+    Address(H160),
+    Uint(U256),
+    Bool(bool),
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(Vec<u8>),
+}
+
+/// Describes a T1 event so its logs can be decoded without a dedicated parser
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EthEventDescriptor {
+    /// Keccak-256 of the event declaration (eg: `LogLifted(address,address,bytes32,uint256)`), as in ethereum's Topic0
+    pub signature: H256,
+    /// Types of the indexed params, in the order they appear in the topics
+    pub indexed_params: Vec<EthAbiParamType>,
+    /// Types of the non indexed params, in the order they appear in the data
+    pub non_indexed_params: Vec<EthAbiParamType>,
+    /// The only contract allowed to emit this event
+    pub contract_address: H160,
+}
+
+impl EthEventDescriptor {
+    // Topic0 is the event signature, which leaves room for 3 indexed params
+    const MAX_INDEXED_PARAMS: usize = 3;
+
+    pub fn is_valid(&self) -> bool {
+        return !self.signature.is_zero() &&
+               !self.contract_address.is_zero() &&
+               self.indexed_params.len() <= Self::MAX_INDEXED_PARAMS &&
+               self.indexed_params.iter().chain(self.non_indexed_params.iter()).all(|param| param.is_valid());
+    }
+
+    pub fn decode_log(&self, data: Option<Vec<u8>>, topics: Vec<Vec<u8>>) -> Result<DecodedEventData, Error> {
+        // Structure of input bytes:
+        // all topics are 32 bytes long
+        // topics[0] --> event signature (can be ignored)
+        // topics[1..] --> one topic per indexed param
+        // data --> one 32 bytes word per non indexed param. For dynamic types, the word is the offset of the value in data,
+        //          where a 32 bytes length is followed by the value itself
+
+        if !self.is_valid() {
+            return Err(Error::DecodedEventInvalidDescriptor);
+        }
+
+        if topics.len() != self.indexed_params.len() + 1 {
+            return Err(Error::DecodedEventWrongTopicCount);
+        }
+
+        if topics.iter().skip(1).any(|topic| topic.len() != WORD_LENGTH) {
+            return Err(Error::DecodedEventBadTopicLength);
+        }
+
+        let mut tokens = self.indexed_params.iter()
+            .zip(topics.iter().skip(1))
+            .map(|(param, topic)| param.decode_word(topic))
+            .collect::<Result<Vec<EthAbiToken>, Error>>()?;
+
+        if self.non_indexed_params.is_empty() {
+            if data.is_some() {
+                return Err(Error::DecodedEventShouldOnlyContainTopics);
+            }
+
+            return Ok(DecodedEventData { tokens });
+        }
+
+        let data = data.ok_or(Error::DecodedEventMissingData)?;
+        if data.len() < self.non_indexed_params.len() * WORD_LENGTH || data.len() % WORD_LENGTH != 0 {
+            return Err(Error::DecodedEventBadDataLength);
+        }
+
+        for (index, param) in self.non_indexed_params.iter().enumerate() {
+            let word_position = index * WORD_LENGTH;
+            let word = &data[word_position..word_position + WORD_LENGTH];
+
+            if param.is_dynamic() {
+                let offset = read_word_as_usize(&data, word_position)?;
+                let length = read_word_as_usize(&data, offset)?;
+                let value_start = offset.checked_add(WORD_LENGTH).ok_or(Error::DecodedEventBadDynamicDataOffset)?;
+                let value_end = value_start.checked_add(length).ok_or(Error::DecodedEventBadDynamicDataOffset)?;
+                let value = data.get(value_start..value_end).ok_or(Error::DecodedEventBadDynamicDataOffset)?.to_vec();
+
+                tokens.push(match param {
+                    EthAbiParamType::String => EthAbiToken::String(value),
+                    _ => EthAbiToken::Bytes(value),
+                });
+            } else {
+                tokens.push(param.decode_word(word)?);
+            }
+        }
+
+        return Ok(DecodedEventData { tokens });
+    }
+}
+
+/// Params of an event decoded with its `EthEventDescriptor`
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct DecodedEventData {
+    /// The indexed params, followed by the non indexed params
+    pub tokens: Vec<EthAbiToken>,
+}
+
+impl DecodedEventData {
+    /// True if there is one token per param of the descriptor, in the same order and of the same type
+    pub fn is_valid(&self, descriptor: &EthEventDescriptor) -> bool {
+        let params = descriptor.indexed_params.iter().map(|param| (param, true))
+            .chain(descriptor.non_indexed_params.iter().map(|param| (param, false)));
+
+        return self.tokens.len() == descriptor.indexed_params.len() + descriptor.non_indexed_params.len() &&
+               params.zip(self.tokens.iter()).all(|((param, indexed), token)| param.matches(token, indexed));
+    }
+}
+
 // ================================= Checking and Validating Events ====================================
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CheckResult {
     /// Event exists on tier 1
     Ok,
@@ -526,6 +762,36 @@ impl Default for CheckResult {
     fn default() -> Self { CheckResult::Unknown }
 }
 
+/// Where an ethereum event is on its way to being processed
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EthEventStatus<BlockNumber> {
+    /// The event has not been added
+    Unknown,
+    /// The event is waiting to be checked, behind this number of unchecked events
+    Unchecked(u32),
+    /// The event has been checked and can be challenged. It can be processed after this block.
+    PendingChallenge(CheckResult, BlockNumber),
+    /// The event has been processed: true if it was accepted, false if it was rejected for good
+    Processed(bool),
+    /// A challenge proved the check of the event wrong, or its ethereum block was reorged out. It can be added again.
+    Rejected,
+}
+
+impl<BlockNumber> Default for EthEventStatus<BlockNumber> {
+    fn default() -> Self { EthEventStatus::Unknown }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EthEventStatusInfo<AccountId, BlockNumber> {
+    pub status: EthEventStatus<BlockNumber>,
+    /// The validator whose check result is pending challenge
+    pub checked_by: Option<AccountId>,
+    /// The validators who challenged the check result
+    pub challenges: Vec<AccountId>,
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 // Note: strictly speaking, different contracts can have events with the same signature, which would suggests that the
@@ -536,11 +802,14 @@ impl Default for CheckResult {
 pub struct EthEventId {
     pub signature: H256, // this is the Event Signature, as in ethereum's Topic0. It is not a cryptographic signature
     pub transaction_hash: H256,
+    // Position of this event, starting at 0, among the events with the same signature that our contracts emitted in the
+    // transaction. This lets a transaction emit the same event more than once (eg: a batch of lifts).
+    pub log_index: u64,
 }
 
 impl EthEventId {
     pub fn hashed<R, F: FnOnce(&[u8]) -> R>(&self, hasher: F) -> R {
-        return (self.signature, self.transaction_hash).using_encoded(hasher);
+        return (self.signature, self.transaction_hash, self.log_index).using_encoded(hasher);
     }
 }
 
@@ -559,6 +828,9 @@ pub struct EthEventCheckResult<BlockNumber: Member, AccountId: Member> {
     pub ready_for_processing_after_block: BlockNumber,
     // Minimum number of votes to successfully challenge this result
     pub min_challenge_votes: u32,
+    /// Ethereum block that included the event, when the check found it. Used to detect reorgs during the challenge period.
+    pub eth_block_number: u64,
+    pub eth_block_hash: H256,
 }
 
 impl<BlockNumber: Member, AccountId: Member> EthEventCheckResult<BlockNumber, AccountId> {
@@ -581,6 +853,8 @@ impl<BlockNumber: Member, AccountId: Member> EthEventCheckResult<BlockNumber, Ac
             ready_for_processing_after_block: ready_after_block,
             checked_at_block: checked_at_block,
             min_challenge_votes: min_challenge_votes,
+            eth_block_number: 0,
+            eth_block_hash: H256::zero(),
         };
     }
 }
@@ -594,6 +868,22 @@ pub enum ChallengeReason {
     IncorrectEventData,
     /// Default value
     Unknown,
+    /// The Ethereum block that included the event has been reorged out
+    BlockNoLongerCanonical,
+    /// The result of the check is not correct. Carries the result the challenger found.
+    IncorrectResultWithEvidence(CheckResult),
+    /// The event data is not correct. Carries the event data the challenger found.
+    IncorrectEventDataWithEvidence(EventData),
+}
+
+impl ChallengeReason {
+    pub fn has_evidence(&self) -> bool {
+        match *self {
+            ChallengeReason::IncorrectResultWithEvidence(_) |
+            ChallengeReason::IncorrectEventDataWithEvidence(_) => true,
+            _ => false
+        }
+    }
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
@@ -650,6 +940,46 @@ impl ProcessedEventHandler for Tuple {
     }
 }
 
+// ======================================== Log scanning ==============================================
+
+/// Request sent to the external service to find the logs emitted by our tier1 contracts in a range of Ethereum blocks
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct EthLogsQuery {
+    pub addresses: Vec<H160>,
+    /// Event signatures (topic0) we are interested in
+    pub signatures: Vec<H256>,
+    pub from_block: u64,
+    /// Maximum number of blocks to scan in a single request
+    pub max_block_range: u64,
+    /// Blocks with fewer confirmations than this are not scanned
+    pub min_confirmations: u64,
+}
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct DiscoveredEthLog {
+    pub signature: H256,
+    pub transaction_hash: H256,
+    pub contract_address: H160,
+    pub block_number: u64,
+    /// Position of the log in its block, as reported by Ethereum
+    pub block_log_index: u64,
+}
+
+/// Logs found in the blocks `[from_block, to_block]`.
+/// `to_block` is lower than `from_block` if there are no blocks with enough confirmations to scan yet.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, Eq)]
+pub struct EthLogsScanResult {
+    pub from_block: u64,
+    pub to_block: u64,
+    pub logs: Vec<DiscoveredEthLog>,
+}
+
+impl EthLogsScanResult {
+    pub fn has_scanned_blocks(&self) -> bool {
+        return self.to_block >= self.from_block;
+    }
+}
+
 // ======================================== Tests =====================================================
 
 #[cfg(test)]
@@ -659,3 +989,7 @@ mod test_event_types;
 #[cfg(test)]
 #[path = "tests/nft_event_tests.rs"]
 mod nft_event_tests;
+
+#[cfg(test)]
+#[path = "tests/event_descriptor_tests.rs"]
+mod event_descriptor_tests;
@@ -0,0 +1,103 @@
+//! Errors returned by the external service (avn-service) to the offchain workers.
+//!
+//! A failed request returns a non 200 status code and a json envelope:
+//! `{"version": 1, "error": {"code": "RECEIPT_NOT_FOUND", "message": "..."}}`
+//! The codes are stable: new codes can be added but existing ones must not be renamed or change meaning.
+
+/// Version of the error envelope returned by the external service
+pub const EXTERNAL_SERVICE_ERROR_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExternalServiceErrorCode {
+    /// The request is malformed
+    InvalidRequest,
+    /// The request did not present the node's secret
+    Unauthorised,
+    /// The request is not allowed by the node's policy
+    Forbidden,
+    /// None of the configured Ethereum nodes could serve the request
+    EthereumNodeUnavailable,
+    /// The Ethereum nodes did not agree on the result
+    QuorumNotReached,
+    /// Ethereum does not have a receipt for the transaction
+    ReceiptNotFound,
+    /// Gas estimation failed, usually because the transaction would revert
+    GasEstimationFailed,
+    /// The transaction nonce has already been used
+    NonceTooLow,
+    /// Sending the transaction would drop the Ethereum wallet balance below the configured reserve
+    InsufficientFunds,
+    /// The data could not be signed
+    SigningFailed,
+    /// Any other failure in the external service
+    Internal,
+}
+
+impl ExternalServiceErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        return match self {
+            ExternalServiceErrorCode::InvalidRequest => "INVALID_REQUEST",
+            ExternalServiceErrorCode::Unauthorised => "UNAUTHORISED",
+            ExternalServiceErrorCode::Forbidden => "FORBIDDEN",
+            ExternalServiceErrorCode::EthereumNodeUnavailable => "ETHEREUM_NODE_UNAVAILABLE",
+            ExternalServiceErrorCode::QuorumNotReached => "QUORUM_NOT_REACHED",
+            ExternalServiceErrorCode::ReceiptNotFound => "RECEIPT_NOT_FOUND",
+            ExternalServiceErrorCode::GasEstimationFailed => "GAS_ESTIMATION_FAILED",
+            ExternalServiceErrorCode::NonceTooLow => "NONCE_TOO_LOW",
+            ExternalServiceErrorCode::InsufficientFunds => "INSUFFICIENT_FUNDS",
+            ExternalServiceErrorCode::SigningFailed => "SIGNING_FAILED",
+            ExternalServiceErrorCode::Internal => "INTERNAL",
+        };
+    }
+
+    pub fn from_str(code: &str) -> Option<Self> {
+        return match code {
+            "INVALID_REQUEST" => Some(ExternalServiceErrorCode::InvalidRequest),
+            "UNAUTHORISED" => Some(ExternalServiceErrorCode::Unauthorised),
+            "FORBIDDEN" => Some(ExternalServiceErrorCode::Forbidden),
+            "ETHEREUM_NODE_UNAVAILABLE" => Some(ExternalServiceErrorCode::EthereumNodeUnavailable),
+            "QUORUM_NOT_REACHED" => Some(ExternalServiceErrorCode::QuorumNotReached),
+            "RECEIPT_NOT_FOUND" => Some(ExternalServiceErrorCode::ReceiptNotFound),
+            "GAS_ESTIMATION_FAILED" => Some(ExternalServiceErrorCode::GasEstimationFailed),
+            "NONCE_TOO_LOW" => Some(ExternalServiceErrorCode::NonceTooLow),
+            "INSUFFICIENT_FUNDS" => Some(ExternalServiceErrorCode::InsufficientFunds),
+            "SIGNING_FAILED" => Some(ExternalServiceErrorCode::SigningFailed),
+            "INTERNAL" => Some(ExternalServiceErrorCode::Internal),
+            _ => None,
+        };
+    }
+
+    /// The http status code the external service responds with
+    pub fn status_code(&self) -> u16 {
+        return match self {
+            ExternalServiceErrorCode::InvalidRequest => 400,
+            ExternalServiceErrorCode::Unauthorised => 401,
+            ExternalServiceErrorCode::Forbidden => 403,
+            ExternalServiceErrorCode::ReceiptNotFound => 404,
+            ExternalServiceErrorCode::InsufficientFunds => 402,
+            ExternalServiceErrorCode::NonceTooLow => 409,
+            ExternalServiceErrorCode::GasEstimationFailed => 422,
+            ExternalServiceErrorCode::EthereumNodeUnavailable => 503,
+            ExternalServiceErrorCode::QuorumNotReached => 503,
+            ExternalServiceErrorCode::SigningFailed => 500,
+            ExternalServiceErrorCode::Internal => 500,
+        };
+    }
+
+    /// True if the same request can succeed if it is retried later
+    pub fn is_transient(&self) -> bool {
+        return match self {
+            ExternalServiceErrorCode::EthereumNodeUnavailable |
+            ExternalServiceErrorCode::QuorumNotReached |
+            ExternalServiceErrorCode::NonceTooLow |
+            ExternalServiceErrorCode::Internal => true,
+            _ => false,
+        };
+    }
+}
+
+// ======================================== Tests =====================================================
+
+#[cfg(test)]
+#[path = "tests/test_external_service_errors.rs"]
+mod test_external_service_errors;
@@ -7,12 +7,14 @@ use alloc::{string::String};
 
 use codec::{Encode, Decode, Codec};
 use sp_std::{vec::Vec, boxed::Box};
-use sp_core::{H160, crypto::KeyTypeId, ecdsa};
+use sp_core::{H160, H256, U256, crypto::KeyTypeId, ecdsa};
 use sp_runtime::traits::{AtLeast32Bit, Member, Dispatchable};
 use sp_io::{EcdsaVerifyError, hashing::keccak_256, crypto::secp256k1_ecdsa_recover_compressed};
 
 pub mod event_types;
 pub mod offchain_worker_storage_lock;
+pub mod external_service_errors;
+pub mod ocw_metrics;
 #[path = "tests/helpers.rs"]
 pub mod avn_tests_helpers;
 
@@ -30,6 +32,11 @@ pub const ETHEREUM_PREFIX: &'static [u8] = b"\x19Ethereum Signed Message:\n32";
 pub const EXTERNAL_SERVICE_PORT_NUMBER_KEY: &'static [u8; 15] = b"avn_port_number";
 /// Default port number the external service runs on.
 pub const DEFAULT_EXTERNAL_SERVICE_PORT_NUMBER: &str = "2020";
+/// Local storage key to access the secret the external service expects on every request.
+/// The secret is generated by the node every time it starts.
+pub const EXTERNAL_SERVICE_AUTH_SECRET_KEY: &'static [u8; 15] = b"avn_auth_secret";
+/// Http header used to present the external service's secret
+pub const EXTERNAL_SERVICE_AUTH_HEADER: &str = "X-Avn-Auth";
 
 #[derive(Debug)]
 pub enum ECDSAVerificationError {
@@ -64,6 +71,51 @@ impl EthTransaction {
     }
 }
 
+/// Balance of the Ethereum (`ethk`) wallet of this node, as returned by the external service.
+/// Transactions that would drop the balance below `reserve` are refused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct EthWalletStatus {
+    pub address: H160,
+    /// Balance in wei
+    pub balance: U256,
+    /// Minimum balance, in wei, the wallet must keep
+    pub reserve: U256,
+    /// Maximum cost, in wei, of a transaction to a T1 contract at the current Ethereum fees
+    pub transaction_cost: U256,
+}
+
+impl EthWalletStatus {
+    pub fn new(address: H160, balance: U256, reserve: U256, transaction_cost: U256) -> Self {
+        return EthWalletStatus { address, balance, reserve, transaction_cost };
+    }
+
+    /// True if the wallet can pay for a transaction at the current fees and keep its reserve
+    pub fn can_pay_for_transaction(&self) -> bool {
+        return self.can_pay(self.transaction_cost);
+    }
+
+    /// True if a transaction costing up to `cost` wei can be paid without dropping the balance below the reserve
+    pub fn can_pay(&self, cost: U256) -> bool {
+        return self.balance >= self.reserve.saturating_add(cost);
+    }
+}
+
+/// What the external service knows about a transaction hash on Ethereum.
+/// It only describes that hash: the replacements of a transaction (eg to bump its gas price) are queried separately.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EthTransactionStatus {
+    /// There is no receipt for the transaction yet
+    NotMined,
+    Mined {
+        /// Hash of the transaction that was mined
+        tx_hash: H256,
+        block_number: u64,
+        /// False if the transaction reverted
+        succeeded: bool,
+        num_confirmations: u64,
+    },
+}
+
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Default, Debug)]
 pub struct Proof<Signature, AccountId> {
     pub signer: AccountId,
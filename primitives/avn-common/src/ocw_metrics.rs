@@ -0,0 +1,17 @@
+//! Counters kept by the offchain workers in the persistent local storage.
+//! The node reads them and exports them as Prometheus metrics.
+use sp_runtime::offchain::storage::StorageValueRef;
+
+/// Local storage key of the number of Ethereum events checked or validated by this node
+pub const OCW_EVENTS_CHECKED_COUNTER_KEY: &'static [u8] = b"avn_metrics::ocw_events_checked";
+/// Local storage key of the number of votes on summary roots cast by this node
+pub const OCW_ROOTS_VOTED_COUNTER_KEY: &'static [u8] = b"avn_metrics::ocw_roots_voted";
+
+/// Adds one to the counter. A count can be lost if another worker updates the counter at the same time,
+/// which is acceptable for metrics.
+pub fn increment_counter(key: &[u8]) {
+    let counter = StorageValueRef::persistent(key);
+    let _ = counter.mutate(|count: Option<Option<u64>>| -> Result<u64, ()> {
+        Ok(count.flatten().unwrap_or(0).saturating_add(1))
+    });
+}
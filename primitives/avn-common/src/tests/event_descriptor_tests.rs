@@ -0,0 +1,347 @@
+// Copyright 2021 Aventus Network Services (UK) Ltd.
+#[cfg(test)]
+
+use super::*; // event_types
+use sp_std::vec::Vec;
+use sp_core::{H160, H256, U256};
+use sha3::{Digest, Keccak256};
+
+fn keccak(value: &[u8]) -> H256 {
+    let mut hasher = Keccak256::new();
+    hasher.input(value);
+    return H256::from_slice(&hasher.result()[..]);
+}
+
+fn word_from_u64(value: u64) -> Vec<u8> {
+    let mut word = vec![0; 24];
+    word.extend_from_slice(&value.to_be_bytes());
+    return word;
+}
+
+fn word_from_address(address: H160) -> Vec<u8> {
+    let mut word = vec![0; 12];
+    word.extend_from_slice(address.as_bytes());
+    return word;
+}
+
+// event LogLifted(address indexed tokenContract, address indexed lifter, bytes32 indexed liftee, uint amount);
+fn lifted_descriptor() -> EthEventDescriptor {
+    return EthEventDescriptor {
+        signature: keccak(b"LogLifted(address,address,bytes32,uint256)"),
+        indexed_params: vec![EthAbiParamType::Address, EthAbiParamType::Address, EthAbiParamType::FixedBytes(32)],
+        non_indexed_params: vec![EthAbiParamType::Uint(256)],
+        contract_address: H160::from([9; 20]),
+    };
+}
+
+fn lifted_topics() -> Vec<Vec<u8>> {
+    return vec![
+        lifted_descriptor().signature.as_bytes().to_vec(),
+        word_from_address(H160::from([1; 20])),
+        word_from_address(H160::from([2; 20])),
+        vec![3; 32],
+    ];
+}
+
+// event LogTagged(bool indexed active, string tag, uint64 count);
+fn tagged_descriptor() -> EthEventDescriptor {
+    return EthEventDescriptor {
+        signature: keccak(b"LogTagged(bool,string,uint64)"),
+        indexed_params: vec![EthAbiParamType::Bool],
+        non_indexed_params: vec![EthAbiParamType::String, EthAbiParamType::Uint(64)],
+        contract_address: H160::from([9; 20]),
+    };
+}
+
+fn tagged_data(tag: &[u8], count: u64) -> Vec<u8> {
+    let mut data = word_from_u64(2 * 32); // the string starts after the 2 head words
+    data.append(&mut word_from_u64(count));
+    data.append(&mut word_from_u64(tag.len() as u64));
+    data.extend_from_slice(tag);
+    data.append(&mut vec![0; 32 - tag.len() % 32]);
+    return data;
+}
+
+mod descriptor_is_valid {
+    use super::*;
+
+    #[test]
+    fn when_all_fields_are_correct() {
+        assert!(lifted_descriptor().is_valid());
+        assert!(tagged_descriptor().is_valid());
+    }
+
+    #[test]
+    fn fails_when_signature_is_zero() {
+        let descriptor = EthEventDescriptor { signature: H256::zero(), ..lifted_descriptor() };
+        assert!(!descriptor.is_valid());
+    }
+
+    #[test]
+    fn fails_when_contract_address_is_zero() {
+        let descriptor = EthEventDescriptor { contract_address: H160::zero(), ..lifted_descriptor() };
+        assert!(!descriptor.is_valid());
+    }
+
+    #[test]
+    fn fails_when_there_are_more_than_3_indexed_params() {
+        let descriptor = EthEventDescriptor { indexed_params: vec![EthAbiParamType::Bool; 4], ..lifted_descriptor() };
+        assert!(!descriptor.is_valid());
+    }
+
+    #[test]
+    fn fails_when_a_param_size_is_not_supported() {
+        let bad_uint = EthEventDescriptor { non_indexed_params: vec![EthAbiParamType::Uint(12)], ..lifted_descriptor() };
+        let bad_bytes = EthEventDescriptor { non_indexed_params: vec![EthAbiParamType::FixedBytes(33)], ..lifted_descriptor() };
+
+        assert!(!bad_uint.is_valid());
+        assert!(!bad_bytes.is_valid());
+    }
+}
+
+mod can_successfully_be_decoded {
+    use super::*;
+
+    #[test]
+    fn when_event_has_static_params() {
+        let data = word_from_u64(100_000_000);
+
+        let result = lifted_descriptor().decode_log(Some(data.clone()), lifted_topics());
+
+        assert_eq!(result, Ok(DecodedEventData {
+            tokens: vec![
+                EthAbiToken::Address(H160::from([1; 20])),
+                EthAbiToken::Address(H160::from([2; 20])),
+                EthAbiToken::FixedBytes(vec![3; 32]),
+                EthAbiToken::Uint(U256::from(100_000_000u64)),
+            ]
+        }));
+
+        // The generic decoder and the dedicated parser agree
+        let lifted_data = LiftedData::parse_bytes(Some(data), lifted_topics()).unwrap();
+        assert_eq!(lifted_data.amount, 100_000_000u128);
+    }
+
+    #[test]
+    fn when_event_has_dynamic_params() {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(1)];
+
+        let result = tagged_descriptor().decode_log(Some(tagged_data(b"b1dc0452-8b2f-78ec-7e80-167002d11678", 7)), topics);
+
+        assert_eq!(result, Ok(DecodedEventData {
+            tokens: vec![
+                EthAbiToken::Bool(true),
+                EthAbiToken::String(b"b1dc0452-8b2f-78ec-7e80-167002d11678".to_vec()),
+                EthAbiToken::Uint(U256::from(7u64)),
+            ]
+        }));
+    }
+
+    #[test]
+    fn when_an_indexed_param_is_dynamic() {
+        let descriptor = EthEventDescriptor {
+            indexed_params: vec![EthAbiParamType::String],
+            non_indexed_params: vec![],
+            ..tagged_descriptor()
+        };
+        let tag_hash = keccak(b"tag");
+
+        let result = descriptor.decode_log(None, vec![descriptor.signature.as_bytes().to_vec(), tag_hash.as_bytes().to_vec()]);
+
+        assert_eq!(result, Ok(DecodedEventData { tokens: vec![EthAbiToken::FixedBytes(tag_hash.as_bytes().to_vec())] }));
+    }
+}
+
+mod fails_decoding_when {
+    use super::*;
+
+    #[test]
+    fn descriptor_is_invalid() {
+        let descriptor = EthEventDescriptor { contract_address: H160::zero(), ..lifted_descriptor() };
+
+        let result = descriptor.decode_log(Some(word_from_u64(1)), lifted_topics());
+
+        assert_eq!(result, Err(Error::DecodedEventInvalidDescriptor));
+    }
+
+    #[test]
+    fn event_has_the_wrong_number_of_topics() {
+        let mut topics = lifted_topics();
+        topics.pop();
+
+        let result = lifted_descriptor().decode_log(Some(word_from_u64(1)), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventWrongTopicCount));
+    }
+
+    #[test]
+    fn a_topic_is_not_a_word() {
+        let mut topics = lifted_topics();
+        topics[3] = vec![3; 16];
+
+        let result = lifted_descriptor().decode_log(Some(word_from_u64(1)), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventBadTopicLength));
+    }
+
+    #[test]
+    fn data_is_missing() {
+        let result = lifted_descriptor().decode_log(None, lifted_topics());
+
+        assert_eq!(result, Err(Error::DecodedEventMissingData));
+    }
+
+    #[test]
+    fn data_is_present_but_no_param_is_in_data() {
+        let descriptor = EthEventDescriptor { non_indexed_params: vec![], ..lifted_descriptor() };
+
+        let result = descriptor.decode_log(Some(word_from_u64(1)), lifted_topics());
+
+        assert_eq!(result, Err(Error::DecodedEventShouldOnlyContainTopics));
+    }
+
+    #[test]
+    fn data_is_too_short() {
+        let result = lifted_descriptor().decode_log(Some(vec![1; 16]), lifted_topics());
+
+        assert_eq!(result, Err(Error::DecodedEventBadDataLength));
+    }
+
+    #[test]
+    fn an_address_is_not_padded_with_zeros() {
+        let mut topics = lifted_topics();
+        topics[1] = vec![1; 32];
+
+        let result = lifted_descriptor().decode_log(Some(word_from_u64(1)), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventValueOverflow));
+    }
+
+    #[test]
+    fn a_uint_does_not_fit_in_its_size() {
+        let descriptor = EthEventDescriptor { non_indexed_params: vec![EthAbiParamType::Uint(64)], ..lifted_descriptor() };
+
+        let result = descriptor.decode_log(Some(vec![1; 32]), lifted_topics());
+
+        assert_eq!(result, Err(Error::DecodedEventValueOverflow));
+    }
+
+    #[test]
+    fn a_bool_is_not_0_or_1() {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(2)];
+
+        let result = tagged_descriptor().decode_log(Some(tagged_data(b"tag", 7)), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventValueOverflow));
+    }
+
+    #[test]
+    fn a_dynamic_value_is_out_of_bounds() {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(1)];
+        let mut data = tagged_data(b"tag", 7);
+        data[0..32].copy_from_slice(&word_from_u64(1024));
+
+        let result = tagged_descriptor().decode_log(Some(data), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventBadDynamicDataOffset));
+    }
+
+    #[test]
+    fn a_dynamic_value_offset_overflows() {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(1)];
+        let mut data = tagged_data(b"tag", 7);
+        data[24..32].copy_from_slice(&[0xff; 8]);
+
+        let result = tagged_descriptor().decode_log(Some(data), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventBadDynamicDataOffset));
+    }
+
+    #[test]
+    fn a_dynamic_value_offset_is_all_ones() {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(1)];
+        let mut data = tagged_data(b"tag", 7);
+        data[0..32].copy_from_slice(&[0xff; 32]);
+
+        let result = tagged_descriptor().decode_log(Some(data), topics);
+
+        assert_eq!(result, Err(Error::DecodedEventBadDynamicDataOffset));
+    }
+}
+
+mod decoded_event_data_is_valid {
+    use super::*;
+
+    fn tagged_event_data() -> DecodedEventData {
+        let topics = vec![tagged_descriptor().signature.as_bytes().to_vec(), word_from_u64(1)];
+        return tagged_descriptor().decode_log(Some(tagged_data(b"tag", 7)), topics).unwrap();
+    }
+
+    #[test]
+    fn when_it_is_decoded_with_the_descriptor() {
+        let lifted_data = lifted_descriptor().decode_log(Some(word_from_u64(1)), lifted_topics()).unwrap();
+
+        assert!(lifted_data.is_valid(&lifted_descriptor()));
+        assert!(tagged_event_data().is_valid(&tagged_descriptor()));
+        assert!(EventData::LogDecoded(tagged_event_data()).is_valid(Some(&tagged_descriptor())));
+    }
+
+    #[test]
+    fn when_an_indexed_param_is_dynamic() {
+        let descriptor = EthEventDescriptor { indexed_params: vec![EthAbiParamType::String], ..tagged_descriptor() };
+        let mut event_data = tagged_event_data();
+        event_data.tokens[0] = EthAbiToken::FixedBytes(keccak(b"tag").as_bytes().to_vec());
+
+        assert!(event_data.is_valid(&descriptor));
+    }
+
+    #[test]
+    fn not_when_it_is_decoded_with_another_descriptor() {
+        assert!(!tagged_event_data().is_valid(&lifted_descriptor()));
+    }
+
+    #[test]
+    fn not_when_a_param_is_missing() {
+        let mut event_data = tagged_event_data();
+        event_data.tokens.pop();
+
+        assert!(!event_data.is_valid(&tagged_descriptor()));
+    }
+
+    #[test]
+    fn not_when_there_is_an_extra_token() {
+        let mut event_data = tagged_event_data();
+        event_data.tokens.push(EthAbiToken::Bool(false));
+
+        assert!(!event_data.is_valid(&tagged_descriptor()));
+    }
+
+    #[test]
+    fn not_when_a_token_has_the_wrong_type() {
+        let mut event_data = tagged_event_data();
+        event_data.tokens[1] = EthAbiToken::Bytes(b"tag".to_vec());
+
+        assert!(!event_data.is_valid(&tagged_descriptor()));
+    }
+
+    #[test]
+    fn not_when_a_uint_does_not_fit_in_its_size() {
+        let mut event_data = tagged_event_data();
+        event_data.tokens[2] = EthAbiToken::Uint(U256::from(u64::MAX) + 1);
+
+        assert!(!event_data.is_valid(&tagged_descriptor()));
+    }
+
+    #[test]
+    fn not_when_fixed_bytes_have_the_wrong_size() {
+        let mut event_data = lifted_descriptor().decode_log(Some(word_from_u64(1)), lifted_topics()).unwrap();
+        event_data.tokens[2] = EthAbiToken::FixedBytes(vec![3; 31]);
+
+        assert!(!event_data.is_valid(&lifted_descriptor()));
+    }
+
+    #[test]
+    fn not_when_there_is_no_descriptor() {
+        assert!(!EventData::LogDecoded(tagged_event_data()).is_valid(None));
+    }
+}
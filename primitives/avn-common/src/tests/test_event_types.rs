@@ -398,6 +398,7 @@ fn test_hashed() {
     let event_id = EthEventId {
         signature: H256::zero(),
         transaction_hash: H256::zero(),
+        log_index: 0,
     };
 
     let actual = event_id.hashed(BlakeTwo256::hash);
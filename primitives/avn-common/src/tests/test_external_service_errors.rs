@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use super::*;
+
+const ALL_CODES: [ExternalServiceErrorCode; 11] = [
+    ExternalServiceErrorCode::InvalidRequest,
+    ExternalServiceErrorCode::Unauthorised,
+    ExternalServiceErrorCode::Forbidden,
+    ExternalServiceErrorCode::EthereumNodeUnavailable,
+    ExternalServiceErrorCode::QuorumNotReached,
+    ExternalServiceErrorCode::ReceiptNotFound,
+    ExternalServiceErrorCode::GasEstimationFailed,
+    ExternalServiceErrorCode::NonceTooLow,
+    ExternalServiceErrorCode::InsufficientFunds,
+    ExternalServiceErrorCode::SigningFailed,
+    ExternalServiceErrorCode::Internal,
+];
+
+#[test]
+fn error_codes_can_be_parsed_back() {
+    for code in ALL_CODES.iter() {
+        assert_eq!(ExternalServiceErrorCode::from_str(code.as_str()), Some(*code));
+    }
+}
+
+#[test]
+fn error_codes_are_stable() {
+    assert_eq!(ExternalServiceErrorCode::ReceiptNotFound.as_str(), "RECEIPT_NOT_FOUND");
+    assert_eq!(ExternalServiceErrorCode::EthereumNodeUnavailable.as_str(), "ETHEREUM_NODE_UNAVAILABLE");
+    assert_eq!(ExternalServiceErrorCode::NonceTooLow.as_str(), "NONCE_TOO_LOW");
+    assert_eq!(ExternalServiceErrorCode::GasEstimationFailed.as_str(), "GAS_ESTIMATION_FAILED");
+    assert_eq!(ExternalServiceErrorCode::InsufficientFunds.as_str(), "INSUFFICIENT_FUNDS");
+}
+
+#[test]
+fn unknown_error_codes_are_not_parsed() {
+    assert_eq!(ExternalServiceErrorCode::from_str("NOT_A_CODE"), None);
+    assert_eq!(ExternalServiceErrorCode::from_str("receipt_not_found"), None);
+}
+
+#[test]
+fn error_codes_are_never_returned_with_a_success_status_code() {
+    for code in ALL_CODES.iter() {
+        assert!(code.status_code() >= 400);
+    }
+}